@@ -7,14 +7,16 @@ use std::{
 
 use dces::prelude::*;
 
-use super::WindowAdapter;
+use super::{WindowAdapter, WindowId, WindowManager};
 
 use crate::{
+    command::Commands,
     event::*,
+    executor::LocalExecutor,
     layout::*,
     localization::Localization,
     render_object::*,
-    shell::{ShellRequest, WindowRequest},
+    shell::{CursorIcon, ShellRequest, WindowRequest},
     utils::Point,
     widget_base::*,
 };
@@ -27,35 +29,72 @@ pub struct ContextProvider {
     pub handler_map: Rc<RefCell<EventHandlerMap>>,
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
     pub event_queue: Rc<RefCell<EventQueue>>,
+    pub dispatch_sender: mpsc::Sender<Dispatch>,
+    pub dispatch_receiver: Rc<mpsc::Receiver<Dispatch>>,
+    pub executor: Rc<RefCell<LocalExecutor>>,
     pub mouse_position: Rc<Cell<Point>>,
+    pub cursor_icon: Rc<Cell<CursorIcon>>,
+    pub drag_session: Rc<RefCell<Option<DragSession>>>,
+    pub hovered_widgets: Rc<RefCell<Vec<Entity>>>,
+    pub mouse_capture: Rc<Cell<Option<Entity>>>,
+    pub mouse_pressed: Rc<Cell<bool>>,
+    pub gestures: Rc<RefCell<GestureRecognizer>>,
     pub window_sender: mpsc::Sender<WindowRequest>,
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     pub application_name: String,
     pub first_run: Rc<Cell<bool>>,
+    pub window_id: WindowId,
+    pub window_manager: WindowManager,
+    pub commands: Commands,
+    pub application_commands: Commands,
     pub raw_window_handle: Option<raw_window_handle::RawWindowHandle>,
     // todo thread save
     pub localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
 }
 
 impl ContextProvider {
-    /// Creates a new context provider.
+    /// Creates a new context provider and registers its window on the window manager.
     pub fn new(
         window_sender: mpsc::Sender<WindowRequest>,
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
         localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+        window_manager: WindowManager,
+        owner: Option<WindowId>,
+        application_commands: Commands,
     ) -> Self {
+        let (dispatch_sender, dispatch_receiver) = mpsc::channel();
+
+        let window_id = window_manager.register(
+            owner,
+            window_sender.clone(),
+            Dispatcher::new(dispatch_sender.clone(), window_sender.clone()),
+        );
+
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
             layouts: Rc::new(RefCell::new(BTreeMap::new())),
             handler_map: Rc::new(RefCell::new(EventHandlerMap::new())),
             states: Rc::new(RefCell::new(BTreeMap::new())),
             event_queue: Rc::new(RefCell::new(EventQueue::new())),
+            dispatch_sender,
+            dispatch_receiver: Rc::new(dispatch_receiver),
+            executor: Rc::new(RefCell::new(LocalExecutor::new(window_sender.clone()))),
             mouse_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            cursor_icon: Rc::new(Cell::new(CursorIcon::default())),
+            drag_session: Rc::new(RefCell::new(None)),
+            hovered_widgets: Rc::new(RefCell::new(vec![])),
+            mouse_capture: Rc::new(Cell::new(None)),
+            mouse_pressed: Rc::new(Cell::new(false)),
+            gestures: Rc::new(RefCell::new(GestureRecognizer::new())),
             window_sender,
             shell_sender,
             application_name: application_name.into(),
             first_run: Rc::new(Cell::new(true)),
+            window_id,
+            window_manager,
+            commands: Commands::new(),
+            application_commands,
             raw_window_handle: None,
             localization,
         }
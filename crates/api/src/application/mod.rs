@@ -5,6 +5,7 @@ use std::sync::mpsc;
 use dces::prelude::Entity;
 
 use crate::{
+    command::{Command, Commands},
     localization::Localization,
     shell::{Shell, ShellRequest},
     theming::Theme,
@@ -14,10 +15,61 @@ use crate::{
 pub use self::context_provider::*;
 pub use self::overlay::*;
 pub use self::window_adapter::*;
+pub use self::window_manager::*;
 
 mod context_provider;
 mod overlay;
 mod window_adapter;
+mod window_manager;
+
+type LastWindowClosedHandler = dyn FnMut(&mut ApplicationContext) -> bool;
+
+/// Gives the last window closed handler of the `Application` access to the application e.g. to
+/// show a new window.
+pub struct ApplicationContext {
+    request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
+    name: Box<str>,
+    theme: Theme,
+    localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    window_manager: WindowManager,
+    commands: Commands,
+}
+
+impl ApplicationContext {
+    /// Creates and shows a new window.
+    pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+    ) -> WindowId {
+        let (adapter, settings, receiver) = create_window(
+            self.name.clone(),
+            self.theme.clone(),
+            self.request_sender.clone(),
+            create_fn,
+            self.localization.clone(),
+            self.window_manager.clone(),
+            None,
+            self.commands.clone(),
+        );
+        let id = adapter.window_id();
+
+        self.request_sender
+            .send(ShellRequest::CreateWindow(adapter, settings, receiver))
+            .expect("ApplicationContext.show_window: Could not send shell request.");
+
+        id
+    }
+
+    /// Gets the window manager of the application.
+    pub fn window_manager(&self) -> &WindowManager {
+        &self.window_manager
+    }
+
+    /// Gets the commands of the application.
+    pub fn commands(&self) -> &Commands {
+        &self.commands
+    }
+}
 
 /// The `Application` represents the entry point of an OrbTk based application.
 pub struct Application {
@@ -27,6 +79,9 @@ pub struct Application {
     name: Box<str>,
     theme: Theme,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    window_manager: WindowManager,
+    commands: Commands,
+    last_window_closed: Option<Box<LastWindowClosedHandler>>,
 }
 
 impl Default for Application {
@@ -68,6 +123,9 @@ impl Application {
             #[cfg(feature = "light")]
             theme: crate::theme::light_theme(),
             localization: None,
+            window_manager: WindowManager::new(),
+            commands: Commands::new(),
+            last_window_closed: None,
         }
     }
 
@@ -79,6 +137,9 @@ impl Application {
             self.request_sender.clone(),
             create_fn,
             self.localization.clone(),
+            self.window_manager.clone(),
+            None,
+            self.commands.clone(),
         );
 
         self.shell
@@ -89,8 +150,71 @@ impl Application {
         self
     }
 
+    /// Sets a handler that decides what happens after the last window is closed. If it returns
+    /// `true` the application quits, otherwise it keeps running e.g. with a new window shown by
+    /// the handler. By default the application quits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Application::new()
+    ///     .window(|ctx| MainView::new().build(ctx))
+    ///     .on_last_window_closed(|ctx| {
+    ///         // asks for confirmation before the application is closed
+    ///         ctx.show_window(|ctx| QuitView::new().build(ctx));
+    ///         false
+    ///     })
+    ///     .run();
+    /// ```
+    pub fn on_last_window_closed<F: FnMut(&mut ApplicationContext) -> bool + 'static>(
+        mut self,
+        handler: F,
+    ) -> Self {
+        self.last_window_closed = Some(Box::new(handler));
+        self
+    }
+
+    /// Registers an application command that could be executed in all windows e.g. by its
+    /// shortcut.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id or the shortcut of the command is already used by another application
+    /// command.
+    pub fn command(self, command: Command) -> Self {
+        if let Err(error) = self.commands.register(command) {
+            panic!("Application.command(): {}.", error);
+        }
+
+        self
+    }
+
+    /// Gets the commands of the application that are shared by all windows.
+    pub fn commands(&self) -> &Commands {
+        &self.commands
+    }
+
+    /// Gets the window manager that keeps track of the open windows of the application.
+    pub fn window_manager(&self) -> &WindowManager {
+        &self.window_manager
+    }
+
     /// Starts the application and run it until quit is requested.
     pub fn run(mut self) {
+        if let Some(mut last_window_closed) = self.last_window_closed.take() {
+            let mut ctx = ApplicationContext {
+                request_sender: self.request_sender.clone(),
+                name: self.name.clone(),
+                theme: self.theme.clone(),
+                localization: self.localization.clone(),
+                window_manager: self.window_manager.clone(),
+                commands: self.commands.clone(),
+            };
+
+            self.shell
+                .on_last_window_closed(move || last_window_closed(&mut ctx));
+        }
+
         self.shell.run();
     }
 }
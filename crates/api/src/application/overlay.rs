@@ -13,6 +13,7 @@ use crate::{
     layout::{AbsoluteLayout, Layout},
     proc_macros::WidgetCtx,
     properties::*,
+    shell::CursorIcon,
     theming::Selector,
     utils::*,
     widget,
@@ -4,12 +4,13 @@ use dces::prelude::*;
 
 use crate::{
     application::*,
+    command::Commands,
     event::*,
     localization::Localization,
     render,
-    services::{Clipboard, Settings},
+    services::{Clipboard, FileDialogs, Settings},
     shell,
-    shell::{ShellRequest, WindowRequest, WindowSettings},
+    shell::{Instant, ShellRequest, WindowRequest, WindowSettings},
     systems::*,
     tree::Tree,
     utils::{Constraint, Point, Rectangle},
@@ -21,7 +22,8 @@ pub struct WindowAdapter {
     world: World<Tree, StringComponentStore, render::RenderContext2D>,
     ctx: ContextProvider,
     registry: Rc<RefCell<Registry>>,
-    old_clipboard_value: Option<String>,
+    // the finger that emulates the mouse
+    primary_touch: Option<u64>,
 }
 
 impl WindowAdapter {
@@ -35,12 +37,23 @@ impl WindowAdapter {
             world,
             ctx,
             registry,
-            old_clipboard_value: None,
+            primary_touch: None,
         }
     }
 }
 
+impl Drop for WindowAdapter {
+    fn drop(&mut self) {
+        self.ctx.window_manager.remove(self.ctx.window_id);
+    }
+}
+
 impl WindowAdapter {
+    /// Gets the id of the window.
+    pub fn window_id(&self) -> WindowId {
+        self.ctx.window_id
+    }
+
     fn root(&mut self) -> Entity {
         self.world
             .entity_component_manager()
@@ -51,24 +64,11 @@ impl WindowAdapter {
 }
 
 impl shell::WindowAdapter for WindowAdapter {
-    fn clipboard_update(&mut self, value: &mut Option<String>) {
-        // internal clipboard value is new => update system clipboard value.
-        if self.registry.borrow().get::<Clipboard>("clipboard").get() != self.old_clipboard_value {
-            *value = self.registry.borrow().get::<Clipboard>("clipboard").get();
-
-            self.old_clipboard_value = value.clone();
-
-            return;
-        }
-
-        //  system clipboard value is newer => update internal clipboard
-        if let Some(value) = value.clone() {
-            self.registry
-                .borrow_mut()
-                .get_mut::<Clipboard>("clipboard")
-                .set(value.clone());
-            self.old_clipboard_value = Some(value);
-        }
+    fn clipboard_changed(&mut self, kind: shell::ClipboardKind, content: shell::ClipboardContent) {
+        self.registry
+            .borrow_mut()
+            .get_mut::<Clipboard>("clipboard")
+            .system_changed(kind, content);
     }
 
     fn resize(&mut self, width: f64, height: f64) {
@@ -83,6 +83,18 @@ impl shell::WindowAdapter for WindowAdapter {
             );
     }
 
+    fn scale_factor_changed(&mut self, scale_factor: f64) {
+        let root = self.root();
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::ScaleFactorChanged(scale_factor),
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
     fn mouse(&mut self, x: f64, y: f64) {
         let root = self.root();
         self.ctx.mouse_position.set(Point::new(x, y));
@@ -133,6 +145,72 @@ impl shell::WindowAdapter for WindowAdapter {
         }
     }
 
+    fn touch_event(&mut self, event: shell::TouchEvent) {
+        let root = self.root();
+        let id = event.id;
+        let position = event.position;
+
+        let gestures = {
+            let mut gestures = self.ctx.gestures.borrow_mut();
+            let now = Instant::now();
+
+            match event.phase {
+                shell::TouchPhase::Down => gestures.touch_down(id, position, now),
+                shell::TouchPhase::Move => gestures.touch_move(id, position, now),
+                shell::TouchPhase::Up => gestures.touch_up(id, position, now),
+                shell::TouchPhase::Cancel => gestures.touch_cancel(id),
+            }
+        };
+
+        {
+            let mut event_queue = self.ctx.event_queue.borrow_mut();
+
+            match event.phase {
+                shell::TouchPhase::Down => {
+                    event_queue.register_event(TouchDownEvent { id, position }, root)
+                }
+                shell::TouchPhase::Move => {
+                    event_queue.register_event(TouchMoveEvent { id, position }, root)
+                }
+                shell::TouchPhase::Up => {
+                    event_queue.register_event(TouchUpEvent { id, position }, root)
+                }
+                shell::TouchPhase::Cancel => {
+                    event_queue.register_event(TouchCancelEvent { id, position }, root)
+                }
+            }
+        }
+
+        // the first finger emulates the left mouse button for widgets without touch handling
+        match event.phase {
+            shell::TouchPhase::Down if self.primary_touch.is_none() => {
+                self.primary_touch = Some(id);
+                self.mouse(position.x(), position.y());
+                self.mouse_event(shell::MouseEvent {
+                    position,
+                    button: shell::MouseButton::Left,
+                    state: shell::ButtonState::Down,
+                });
+            }
+            shell::TouchPhase::Move if self.primary_touch == Some(id) => {
+                self.mouse(position.x(), position.y());
+            }
+            shell::TouchPhase::Up | shell::TouchPhase::Cancel if self.primary_touch == Some(id) => {
+                self.primary_touch = None;
+                self.mouse_event(shell::MouseEvent {
+                    position,
+                    button: shell::MouseButton::Left,
+                    state: shell::ButtonState::Up,
+                });
+            }
+            _ => {}
+        }
+
+        for gesture in gestures {
+            gesture.register(&mut *self.ctx.event_queue.borrow_mut(), root);
+        }
+    }
+
     fn mouse_position(&self) -> Point {
         self.ctx.mouse_position.get()
     }
@@ -214,6 +292,9 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    window_manager: WindowManager,
+    owner: Option<WindowId>,
+    application_commands: Commands,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
     let app_name = app_name.into();
     let mut world: World<Tree, StringComponentStore, render::RenderContext2D> =
@@ -233,11 +314,23 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             .register("settings", Settings::new(app_name.clone()));
     };
 
+    let mut clipboard = Clipboard::new();
+    clipboard.connect(sender.clone());
+    registry.borrow_mut().register("clipboard", clipboard);
+
     registry
         .borrow_mut()
-        .register("clipboard", Clipboard::new());
-
-    let context_provider = ContextProvider::new(sender, request_sender, app_name, localization);
+        .register("file_dialog", FileDialogs::new());
+
+    let context_provider = ContextProvider::new(
+        sender,
+        request_sender,
+        app_name,
+        localization,
+        window_manager,
+        owner,
+        application_commands,
+    );
 
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
@@ -0,0 +1,281 @@
+use std::{any::Any, cell::RefCell, collections::BTreeMap, rc::Rc, sync::mpsc};
+
+use dces::entity::Entity;
+
+use crate::{shell::WindowRequest, widget_base::Dispatcher};
+
+/// Identifies a window of an application.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WindowId(u32);
+
+impl From<u32> for WindowId {
+    fn from(id: u32) -> Self {
+        WindowId(id)
+    }
+}
+
+impl WindowId {
+    /// Gets the id as number.
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+struct WindowEntry {
+    owner: Option<WindowId>,
+    window_sender: mpsc::Sender<WindowRequest>,
+    dispatcher: Dispatcher,
+    subscribers: Vec<Entity>,
+}
+
+#[derive(Default)]
+struct Windows {
+    next_id: u32,
+    entries: BTreeMap<WindowId, WindowEntry>,
+}
+
+/// The `WindowManager` keeps track of the open windows of an application. It is shared by all
+/// windows and could be used to close, show or hide other windows, to set owner / child
+/// relationships and to send messages from one window to another.
+///
+/// A message sent to a window is delivered to the states of the widgets that subscribed to the
+/// messages of the window. It is handled by `State::message`.
+///
+/// # Example
+///
+/// ```rust
+/// impl State for PreviewState {
+///     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+///         let window_id = ctx.window_id();
+///         ctx.window_manager().subscribe(window_id, ctx.entity);
+///     }
+///
+///     fn message(&mut self, message: Message, _: &mut Registry, ctx: &mut Context) {
+///         if let Ok(DocumentChanged(text)) = message.downcast::<DocumentChanged>() {
+///             self.show(text, ctx);
+///         }
+///     }
+/// }
+///
+/// // in the editor window
+/// ctx.window_manager().send(preview_window, DocumentChanged(text));
+/// ```
+#[derive(Clone, Default)]
+pub struct WindowManager {
+    windows: Rc<RefCell<Windows>>,
+}
+
+impl WindowManager {
+    /// Creates a new window manager.
+    pub fn new() -> Self {
+        WindowManager::default()
+    }
+
+    /// Registers a new window and returns its id.
+    pub fn register(
+        &self,
+        owner: Option<WindowId>,
+        window_sender: mpsc::Sender<WindowRequest>,
+        dispatcher: Dispatcher,
+    ) -> WindowId {
+        let mut windows = self.windows.borrow_mut();
+        let id = WindowId(windows.next_id);
+        windows.next_id += 1;
+
+        windows.entries.insert(
+            id,
+            WindowEntry {
+                owner,
+                window_sender,
+                dispatcher,
+                subscribers: vec![],
+            },
+        );
+
+        id
+    }
+
+    /// Removes a closed window. The children of the window are closed.
+    pub fn remove(&self, id: WindowId) {
+        if self.windows.borrow_mut().entries.remove(&id).is_none() {
+            return;
+        }
+
+        for child in self.children(id) {
+            self.set_owner(child, None);
+            self.close(child);
+        }
+    }
+
+    /// Gets the ids of all open windows.
+    pub fn ids(&self) -> Vec<WindowId> {
+        self.windows.borrow().entries.keys().copied().collect()
+    }
+
+    /// Gets the number of open windows.
+    pub fn len(&self) -> usize {
+        self.windows.borrow().entries.len()
+    }
+
+    /// Check if there is no open window.
+    pub fn is_empty(&self) -> bool {
+        self.windows.borrow().entries.is_empty()
+    }
+
+    /// Check if the window with the given id is open.
+    pub fn contains(&self, id: WindowId) -> bool {
+        self.windows.borrow().entries.contains_key(&id)
+    }
+
+    /// Gets the owner of the given window.
+    pub fn owner(&self, id: WindowId) -> Option<WindowId> {
+        self.windows
+            .borrow()
+            .entries
+            .get(&id)
+            .and_then(|entry| entry.owner)
+    }
+
+    /// Sets the owner of a window e.g. of a tool window. A window is closed together with its
+    /// owner.
+    pub fn set_owner(&self, id: WindowId, owner: Option<WindowId>) {
+        if let Some(entry) = self.windows.borrow_mut().entries.get_mut(&id) {
+            entry.owner = owner;
+        }
+    }
+
+    /// Gets the windows that are owned by the given window.
+    pub fn children(&self, id: WindowId) -> Vec<WindowId> {
+        self.windows
+            .borrow()
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.owner == Some(id))
+            .map(|(child, _)| *child)
+            .collect()
+    }
+
+    /// Sends a request to the given window. Returns `false` if the window is not open.
+    pub fn request(&self, id: WindowId, request: WindowRequest) -> bool {
+        self.windows
+            .borrow()
+            .entries
+            .get(&id)
+            .map_or(false, |entry| entry.window_sender.send(request).is_ok())
+    }
+
+    /// Requests to close the given window.
+    pub fn close(&self, id: WindowId) -> bool {
+        self.request(id, WindowRequest::Close)
+    }
+
+    /// Requests to bring the given window to the front and focus it.
+    pub fn focus(&self, id: WindowId) -> bool {
+        self.request(id, WindowRequest::Focus)
+    }
+
+    /// Subscribes the state of the given widget to the messages of the given window.
+    pub fn subscribe(&self, id: WindowId, entity: Entity) {
+        if let Some(entry) = self.windows.borrow_mut().entries.get_mut(&id) {
+            if !entry.subscribers.contains(&entity) {
+                entry.subscribers.push(entity);
+            }
+        }
+    }
+
+    /// Removes the subscription of the given widget.
+    pub fn unsubscribe(&self, id: WindowId, entity: Entity) {
+        if let Some(entry) = self.windows.borrow_mut().entries.get_mut(&id) {
+            entry.subscribers.retain(|subscriber| *subscriber != entity);
+        }
+    }
+
+    /// Sends a message to the subscribers of the given window. Returns `false` if the window is
+    /// not open.
+    pub fn send<M: Any + Send + Clone>(&self, id: WindowId, message: M) -> bool {
+        let windows = self.windows.borrow();
+
+        if let Some(entry) = windows.entries.get(&id) {
+            for subscriber in &entry.subscribers {
+                entry.dispatcher.send(*subscriber, message.clone());
+            }
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Sends a message to the subscribers of all open windows.
+    pub fn broadcast<M: Any + Send + Clone>(&self, message: M) {
+        for id in self.ids() {
+            self.send(id, message.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register(
+        manager: &WindowManager,
+        owner: Option<WindowId>,
+    ) -> (
+        WindowId,
+        mpsc::Receiver<WindowRequest>,
+        mpsc::Receiver<crate::widget_base::Dispatch>,
+    ) {
+        let (window_sender, window_receiver) = mpsc::channel();
+        let (sender, receiver) = mpsc::channel();
+        let id = manager.register(
+            owner,
+            window_sender.clone(),
+            Dispatcher::new(sender, window_sender),
+        );
+
+        (id, window_receiver, receiver)
+    }
+
+    #[test]
+    fn test_owner() {
+        let manager = WindowManager::new();
+        let (main, _, _) = register(&manager, None);
+        let (tool, tool_requests, _) = register(&manager, Some(main));
+
+        assert_eq!(manager.ids(), vec![main, tool]);
+        assert_eq!(manager.owner(tool), Some(main));
+        assert_eq!(manager.children(main), vec![tool]);
+
+        // closing the owner closes its children
+        manager.remove(main);
+        assert!(!manager.contains(main));
+        assert_eq!(tool_requests.try_recv(), Ok(WindowRequest::Close));
+        assert_eq!(manager.owner(tool), None);
+
+        manager.remove(tool);
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn test_send() {
+        let manager = WindowManager::new();
+        let (main, _, _) = register(&manager, None);
+        let (preview, _, dispatches) = register(&manager, None);
+
+        // no subscribers
+        assert!(manager.send(preview, 1_u32));
+        assert!(dispatches.try_recv().is_err());
+
+        manager.subscribe(preview, Entity::from(4));
+        manager.subscribe(preview, Entity::from(4));
+        manager.broadcast(2_u32);
+
+        assert_eq!(dispatches.try_recv().unwrap().target(), Entity::from(4));
+        assert!(dispatches.try_recv().is_err());
+
+        manager.remove(preview);
+        assert!(!manager.send(preview, 3_u32));
+        assert!(manager.send(main, 3_u32));
+    }
+}
@@ -0,0 +1,394 @@
+//! This module contains commands that could be executed by keyboard shortcuts, buttons and menu
+//! items.
+
+use std::{cell::RefCell, error, fmt, rc::Rc};
+
+use dces::entity::Entity;
+
+use crate::{properties::*, shell::Key, widget_base::Dispatcher};
+
+/// A `Command` describes an action of the application like `save` or `refresh`. It could be
+/// executed by its keyboard shortcut independent of the focused widget and by the buttons and menu
+/// items that are bound to it. A disabled command could not be executed and its bound widgets are
+/// disabled.
+///
+/// # Example
+///
+/// ```rust
+/// Application::new()
+///     .command(Command::new("save").label("Save").shortcut("Ctrl+S"))
+///     .command(Command::new("palette").label("Command palette").shortcut("Ctrl+Shift+P"))
+///     .window(|ctx| {
+///         Window::new()
+///             .command(Command::new("refresh").label("Refresh").shortcut("F5"))
+///             .child(MainView::new().build(ctx))
+///             .build(ctx)
+///     })
+///     .run();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Command {
+    /// The unique id of the command.
+    pub id: String,
+
+    /// The label that is displayed by bound buttons and menu items without text.
+    pub label: String,
+
+    /// The keyboard shortcut that executes the command. Could be empty.
+    pub shortcut: Shortcut,
+
+    /// Only an enabled command could be executed.
+    pub enabled: bool,
+}
+
+impl Command {
+    /// Creates a new enabled command with the given id.
+    pub fn new(id: impl Into<String>) -> Self {
+        Command {
+            id: id.into(),
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the label.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Sets the keyboard shortcut e.g. `Ctrl+S`.
+    pub fn shortcut(mut self, shortcut: impl Into<Shortcut>) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+
+    /// Sets the enabled state.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// Describes why a command could not be registered.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CommandError {
+    /// A command with the same id is already registered.
+    DuplicateId(String),
+
+    /// The shortcut is already used by the registered command `conflict`.
+    ShortcutConflict {
+        command: String,
+        shortcut: Shortcut,
+        conflict: String,
+    },
+
+    /// There is no command with the given id.
+    NotFound(String),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::DuplicateId(id) => write!(f, "command {} is already registered", id),
+            CommandError::ShortcutConflict {
+                command,
+                shortcut,
+                conflict,
+            } => write!(
+                f,
+                "shortcut {} of command {} is already used by command {}",
+                shortcut, command, conflict
+            ),
+            CommandError::NotFound(id) => write!(f, "command {} is not registered", id),
+        }
+    }
+}
+
+impl error::Error for CommandError {}
+
+/// This message is sent to the widgets that are bound to a command if the command has changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandChanged(pub String);
+
+struct Subscriber {
+    command: String,
+    entity: Entity,
+    dispatcher: Dispatcher,
+}
+
+#[derive(Default)]
+struct CommandTable {
+    commands: Vec<Command>,
+    subscribers: Vec<Subscriber>,
+}
+
+impl CommandTable {
+    fn find_conflict(&self, id: &str, shortcut: &Shortcut) -> Option<&Command> {
+        if shortcut.is_empty() {
+            return None;
+        }
+
+        self.commands
+            .iter()
+            .find(|command| command.id != id && command.shortcut == *shortcut)
+    }
+
+    fn notify(&mut self, id: &str) {
+        // drops the subscribers of closed windows
+        self.subscribers.retain(|subscriber| {
+            subscriber.command != id
+                || subscriber
+                    .dispatcher
+                    .send(subscriber.entity, CommandChanged(id.to_string()))
+        });
+    }
+}
+
+/// `Commands` is a shared list of commands. The application and each window have their own list,
+/// the commands of a window hide the application commands with the same id or shortcut.
+/// Shortcut conflicts inside of a list are detected on registration.
+#[derive(Clone, Default)]
+pub struct Commands {
+    table: Rc<RefCell<CommandTable>>,
+}
+
+impl Commands {
+    /// Creates a new empty list of commands.
+    pub fn new() -> Self {
+        Commands::default()
+    }
+
+    /// Registers a new command. Fails if the id or the shortcut is already used.
+    pub fn register(&self, command: Command) -> Result<(), CommandError> {
+        let mut table = self.table.borrow_mut();
+
+        if table.commands.iter().any(|c| c.id == command.id) {
+            return Err(CommandError::DuplicateId(command.id));
+        }
+
+        if let Some(conflict) = table.find_conflict(&command.id, &command.shortcut) {
+            return Err(CommandError::ShortcutConflict {
+                command: command.id,
+                shortcut: command.shortcut,
+                conflict: conflict.id.clone(),
+            });
+        }
+
+        let id = command.id.clone();
+        table.commands.push(command);
+        table.notify(&id);
+
+        Ok(())
+    }
+
+    /// Removes the command with the given id.
+    pub fn unregister(&self, id: &str) -> Option<Command> {
+        let mut table = self.table.borrow_mut();
+        let index = table.commands.iter().position(|c| c.id == id)?;
+        let command = table.commands.remove(index);
+        table.notify(id);
+
+        Some(command)
+    }
+
+    /// Gets a copy of the command with the given id.
+    pub fn get(&self, id: &str) -> Option<Command> {
+        self.table
+            .borrow()
+            .commands
+            .iter()
+            .find(|c| c.id == id)
+            .cloned()
+    }
+
+    /// Check if a command with the given id is registered.
+    pub fn contains(&self, id: &str) -> bool {
+        self.table.borrow().commands.iter().any(|c| c.id == id)
+    }
+
+    /// Gets the ids of all registered commands.
+    pub fn ids(&self) -> Vec<String> {
+        self.table
+            .borrow()
+            .commands
+            .iter()
+            .map(|c| c.id.clone())
+            .collect()
+    }
+
+    /// Check if the command with the given id is registered and enabled.
+    pub fn is_enabled(&self, id: &str) -> bool {
+        matches!(self.get(id), Some(command) if command.enabled)
+    }
+
+    /// Enables or disables the command with the given id. The bound widgets are updated.
+    pub fn set_enabled(&self, id: &str, enabled: bool) {
+        let mut table = self.table.borrow_mut();
+
+        match table.commands.iter_mut().find(|c| c.id == id) {
+            Some(command) if command.enabled != enabled => command.enabled = enabled,
+            _ => return,
+        }
+
+        table.notify(id);
+    }
+
+    /// Changes the shortcut of the command with the given id. Fails if the shortcut is already
+    /// used by another command.
+    pub fn set_shortcut(
+        &self,
+        id: &str,
+        shortcut: impl Into<Shortcut>,
+    ) -> Result<(), CommandError> {
+        let shortcut = shortcut.into();
+        let mut table = self.table.borrow_mut();
+
+        if let Some(conflict) = table.find_conflict(id, &shortcut) {
+            return Err(CommandError::ShortcutConflict {
+                command: id.to_string(),
+                shortcut,
+                conflict: conflict.id.clone(),
+            });
+        }
+
+        match table.commands.iter_mut().find(|c| c.id == id) {
+            Some(command) => command.shortcut = shortcut,
+            None => return Err(CommandError::NotFound(id.to_string())),
+        }
+
+        table.notify(id);
+
+        Ok(())
+    }
+
+    /// Gets the command whose shortcut matches the given key while the modifiers of the keyboard
+    /// state are pressed.
+    pub fn find(&self, key: Key, keyboard_state: &KeyboardState) -> Option<Command> {
+        self.table
+            .borrow()
+            .commands
+            .iter()
+            .find(|c| c.shortcut.matches(key, keyboard_state))
+            .cloned()
+    }
+
+    /// Subscribes the state of the given widget to the changes of a command. The state receives a
+    /// `CommandChanged` message by the dispatcher.
+    pub fn subscribe(&self, id: impl Into<String>, entity: Entity, dispatcher: Dispatcher) {
+        self.table.borrow_mut().subscribers.push(Subscriber {
+            command: id.into(),
+            entity,
+            dispatcher,
+        });
+    }
+
+    /// Removes all subscriptions of the given widget.
+    pub fn unsubscribe(&self, entity: Entity) {
+        self.table
+            .borrow_mut()
+            .subscribers
+            .retain(|subscriber| subscriber.entity != entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let commands = Commands::new();
+
+        assert!(commands
+            .register(Command::new("save").shortcut("Ctrl+S"))
+            .is_ok());
+        assert!(commands.register(Command::new("open")).is_ok());
+        assert!(commands.register(Command::new("close")).is_ok());
+
+        assert_eq!(
+            commands.register(Command::new("save")),
+            Err(CommandError::DuplicateId(String::from("save")))
+        );
+        assert_eq!(
+            commands.register(Command::new("save_as").shortcut("ctrl+s")),
+            Err(CommandError::ShortcutConflict {
+                command: String::from("save_as"),
+                shortcut: Shortcut::from("Ctrl+S"),
+                conflict: String::from("save"),
+            })
+        );
+        assert!(commands
+            .set_shortcut("open", Shortcut::from("Ctrl+S"))
+            .is_err());
+        assert!(commands.set_shortcut("open", "Ctrl+O").is_ok());
+        assert_eq!(
+            commands.set_shortcut("print", "Ctrl+P"),
+            Err(CommandError::NotFound(String::from("print")))
+        );
+
+        assert_eq!(commands.ids(), vec!["save", "open", "close"]);
+        assert!(commands.unregister("close").is_some());
+        assert!(!commands.contains("close"));
+    }
+
+    #[test]
+    fn test_find() {
+        let commands = Commands::new();
+        commands
+            .register(Command::new("palette").shortcut("Ctrl+Shift+P"))
+            .unwrap();
+        commands
+            .register(Command::new("refresh").shortcut("F5"))
+            .unwrap();
+
+        let mut keyboard_state = KeyboardState::default();
+        assert_eq!(
+            commands.find(Key::F5, &keyboard_state).map(|c| c.id),
+            Some(String::from("refresh"))
+        );
+        assert!(commands.find(Key::P(false), &keyboard_state).is_none());
+
+        keyboard_state.set_key_state(Key::ShiftL, true);
+        if cfg!(target_os = "macos") {
+            keyboard_state.set_key_state(Key::Home, true);
+        } else {
+            keyboard_state.set_key_state(Key::Control, true);
+        }
+
+        assert_eq!(
+            commands.find(Key::P(true), &keyboard_state).map(|c| c.id),
+            Some(String::from("palette"))
+        );
+        assert!(commands.find(Key::F5, &keyboard_state).is_none());
+    }
+
+    #[test]
+    fn test_set_enabled() {
+        let commands = Commands::new();
+        commands.register(Command::new("save")).unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        let (window_sender, _window_receiver) = mpsc::channel();
+        commands.subscribe(
+            "save",
+            Entity::from(2),
+            Dispatcher::new(sender, window_sender),
+        );
+
+        commands.set_enabled("save", false);
+        assert!(!commands.is_enabled("save"));
+        assert_eq!(receiver.try_recv().unwrap().target(), Entity::from(2));
+
+        // nothing changed
+        commands.set_enabled("save", false);
+        assert!(receiver.try_recv().is_err());
+
+        commands.unsubscribe(Entity::from(2));
+        commands.set_enabled("save", true);
+        assert!(commands.is_enabled("save"));
+        assert!(receiver.try_recv().is_err());
+    }
+}
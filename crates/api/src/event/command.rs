@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, proc_macros::*};
+
+use super::{EventBox, EventHandler};
+
+/// This event occurs if a command is executed by its shortcut or a bound widget. It's delivered
+/// to the command handlers of the window until one of them returns `true`.
+#[derive(Clone, Event)]
+pub struct CommandEvent {
+    /// The id of the executed command.
+    pub command: String,
+}
+
+pub type CommandHandlerFn = dyn Fn(&mut StatesContext, &str) -> bool + 'static;
+
+/// Used to handle executed commands. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct CommandEventHandler {
+    handler: Rc<CommandHandlerFn>,
+}
+
+impl EventHandler for CommandEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<CommandEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(states, &event.command))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<CommandEvent>()
+    }
+}
+
+pub trait CommandHandler: Sized + Widget {
+    /// Inserts a handler that is called with the id of each executed command of the window. The
+    /// handler returns `true` if it has handled the command.
+    fn on_command<H: Fn(&mut StatesContext, &str) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(CommandEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,367 @@
+use std::{any::Any, rc::Rc};
+
+use dces::prelude::Entity;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// The mouse has to be moved by this distance while a button is pressed to start a drag.
+pub const DRAG_THRESHOLD: f64 = 4.0;
+
+/// The typed data of an internal drag and drop operation, e.g. the index of a dragged list item.
+///
+/// # Example
+///
+/// ```rust
+/// let payload = DragPayload::new(String::from("orbtk"));
+/// assert_eq!(payload.get::<String>(), Some(&String::from("orbtk")));
+/// assert!(payload.get::<usize>().is_none());
+/// ```
+#[derive(Clone)]
+pub struct DragPayload {
+    data: Rc<dyn Any>,
+}
+
+impl DragPayload {
+    /// Creates a new payload with the given data.
+    pub fn new<T: Any>(data: T) -> Self {
+        DragPayload {
+            data: Rc::new(data),
+        }
+    }
+
+    /// Check if the data is of the given type.
+    pub fn is<T: Any>(&self) -> bool {
+        self.data.is::<T>()
+    }
+
+    /// Gets the data. Returns `None` if the data is of another type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.data.downcast_ref::<T>()
+    }
+}
+
+/// Detects the start of a drag. A drag starts if the mouse is moved more than `DRAG_THRESHOLD`
+/// while the left mouse button is pressed.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DragGesture {
+    start: Option<Point>,
+}
+
+impl DragGesture {
+    /// Is called if the mouse button is pressed at the given position.
+    pub fn press(&mut self, position: Point) {
+        self.start = Some(position);
+    }
+
+    /// Is called if the mouse button is released.
+    pub fn release(&mut self) {
+        self.start = None;
+    }
+
+    /// Is called if the mouse is moved. Returns `true` once if the drag should be started.
+    pub fn moved(&mut self, position: Point) -> bool {
+        if let Some(start) = self.start {
+            if (position.x() - start.x()).abs() > DRAG_THRESHOLD
+                || (position.y() - start.y()).abs() > DRAG_THRESHOLD
+            {
+                self.start = None;
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Describes a running drag and drop operation of a window.
+pub struct DragSession {
+    /// The widget that has started the drag.
+    pub source: Entity,
+
+    /// The dragged data.
+    pub payload: DragPayload,
+
+    /// The widget that is moved with the mouse on the overlay.
+    pub visual: Option<Entity>,
+
+    /// The offset of the visual to the mouse position.
+    pub offset: Point,
+
+    /// The drop target below the mouse.
+    pub target: Option<Entity>,
+
+    /// Indicates if the current target accepts the payload.
+    pub accepted: bool,
+}
+
+/// Occurs if the mouse enters a drop target while dragging.
+#[derive(Event, Clone)]
+pub struct DragEnterEvent {
+    pub payload: DragPayload,
+    pub position: Point,
+}
+
+/// Occurs if the mouse is moved over a drop target while dragging.
+#[derive(Event, Clone)]
+pub struct DragOverEvent {
+    pub payload: DragPayload,
+    pub position: Point,
+}
+
+/// Occurs if the mouse leaves a drop target while dragging or the drag is canceled.
+#[derive(Event, Clone)]
+pub struct DragLeaveEvent {
+    pub payload: DragPayload,
+}
+
+/// Occurs if the payload is dropped on a drop target that has accepted it.
+#[derive(Event, Clone)]
+pub struct DragDropEvent {
+    pub payload: DragPayload,
+    pub position: Point,
+}
+
+/// Occurs on the source of a drag after the drag is finished. `dropped` is `true` if a target
+/// has handled the drop.
+#[derive(Event, Clone)]
+pub struct DragEndEvent {
+    pub payload: DragPayload,
+    pub dropped: bool,
+}
+
+/// Occurs after an item of a widget is moved by drag and drop from the first to the second
+/// index.
+#[derive(Event, Clone)]
+pub struct ItemMovedEvent(pub Entity, pub usize, pub usize);
+
+/// Defines a drag handler function. The return value indicates if the payload is accepted
+/// (enter, over) or if the drop is handled.
+pub type DragHandlerFn = dyn Fn(&mut StatesContext, &DragPayload, Point) -> bool + 'static;
+
+/// Defines the drag leave handler function.
+pub type DragLeaveHandlerFn = dyn Fn(&mut StatesContext, &DragPayload) + 'static;
+
+/// Defines the drag end handler function.
+pub type DragEndHandlerFn = dyn Fn(&mut StatesContext, &DragPayload, bool) + 'static;
+
+/// Defines the item moved handler function.
+pub type ItemMovedHandlerFn = dyn Fn(&mut StatesContext, Entity, usize, usize) + 'static;
+
+#[derive(IntoHandler)]
+pub struct DragEnterEventHandler {
+    pub handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragEnterEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragEnterEvent>() {
+            return (self.handler)(states, &event.payload, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEnterEvent>()
+    }
+}
+
+#[derive(IntoHandler)]
+pub struct DragOverEventHandler {
+    pub handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragOverEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragOverEvent>() {
+            return (self.handler)(states, &event.payload, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragOverEvent>()
+    }
+}
+
+#[derive(IntoHandler)]
+pub struct DragLeaveEventHandler {
+    pub handler: Rc<DragLeaveHandlerFn>,
+}
+
+impl EventHandler for DragLeaveEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragLeaveEvent>() {
+            (self.handler)(states, &event.payload);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragLeaveEvent>()
+    }
+}
+
+#[derive(IntoHandler)]
+pub struct DragDropEventHandler {
+    pub handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragDropEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragDropEvent>() {
+            return (self.handler)(states, &event.payload, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragDropEvent>()
+    }
+}
+
+#[derive(IntoHandler)]
+pub struct DragEndEventHandler {
+    pub handler: Rc<DragEndHandlerFn>,
+}
+
+impl EventHandler for DragEndEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragEndEvent>() {
+            (self.handler)(states, &event.payload, event.dropped);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEndEvent>()
+    }
+}
+
+#[derive(IntoHandler)]
+pub struct ItemMovedEventHandler {
+    pub handler: Rc<ItemMovedHandlerFn>,
+}
+
+impl EventHandler for ItemMovedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ItemMovedEvent>() {
+            (self.handler)(states, event.0, event.1, event.2);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ItemMovedEvent>()
+    }
+}
+
+/// Implement this trait if your widget should be a source or a target of an internal drag and
+/// drop operation. A drag is started by `Context::start_drag`.
+pub trait DragDropHandler: Sized + Widget {
+    /// Inserts a handler that is called if the mouse enters the widget while dragging. Return
+    /// `true` to accept the payload.
+    fn on_drag_enter<H: Fn(&mut StatesContext, &DragPayload, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if the mouse is moved over the widget while dragging.
+    /// Return `true` to accept the payload at the given position.
+    fn on_drag_over<H: Fn(&mut StatesContext, &DragPayload, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragOverEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if the mouse leaves the widget while dragging.
+    fn on_drag_leave<H: Fn(&mut StatesContext, &DragPayload) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if an accepted payload is dropped on the widget. Return
+    /// `true` if the drop is handled.
+    fn on_drag_drop<H: Fn(&mut StatesContext, &DragPayload, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragDropEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called on the source after its drag is finished.
+    fn on_drag_end<H: Fn(&mut StatesContext, &DragPayload, bool) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragEndEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// Implement this trait if the items of your widget could be reordered.
+pub trait ItemMovedHandler: Sized + Widget {
+    /// Inserts a handler that is called after an item is moved from the first to the second
+    /// index.
+    fn on_item_moved<H: Fn(&mut StatesContext, Entity, usize, usize) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ItemMovedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload() {
+        let payload = DragPayload::new(5_usize);
+
+        assert!(payload.is::<usize>());
+        assert!(!payload.is::<String>());
+        assert_eq!(payload.get::<usize>(), Some(&5));
+        assert_eq!(payload.clone().get::<String>(), None);
+    }
+
+    #[test]
+    fn test_gesture() {
+        let mut gesture = DragGesture::default();
+        assert!(!gesture.moved(Point::new(20.0, 20.0)));
+
+        gesture.press(Point::new(10.0, 10.0));
+        assert!(!gesture.moved(Point::new(12.0, 13.0)));
+        assert!(gesture.moved(Point::new(10.0, 15.0)));
+
+        // starts only once
+        assert!(!gesture.moved(Point::new(10.0, 20.0)));
+
+        gesture.press(Point::new(10.0, 10.0));
+        gesture.release();
+        assert!(!gesture.moved(Point::new(30.0, 10.0)));
+    }
+}
@@ -0,0 +1,735 @@
+use std::{rc::Rc, time::Duration};
+
+use dces::prelude::Entity;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    shell::Instant,
+    utils::*,
+};
+
+/// A finger could be moved by this distance and still be recognized as tap or long press.
+pub const TAP_SLOP: f64 = 8.0;
+
+/// A finger has to rest this duration on the screen to be recognized as long press.
+pub const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// Minimal velocity in pixels per second of a finger at the release to be recognized as swipe.
+pub const SWIPE_VELOCITY: f64 = 400.0;
+
+// if the last move is older than this duration the finger was stopped before it was lifted.
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Describes the phase of a continuous gesture like pan or pinch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GesturePhase {
+    Started,
+    Changed,
+    Ended,
+}
+
+/// Describes the direction of a swipe.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// `TapEvent` occurs when a finger touches and leaves the screen without moving.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct TapEvent {
+    /// Indicates position of the tap on the window.
+    pub position: Point,
+}
+
+/// `LongPressEvent` occurs when a finger rests on the screen for `LONG_PRESS_DURATION`.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct LongPressEvent {
+    /// Indicates position of the finger on the window.
+    pub position: Point,
+}
+
+/// `PanEvent` occurs when a single finger is moved on the screen.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct PanEvent {
+    pub phase: GesturePhase,
+
+    /// The position where the finger has touched the screen. It is used to find the target.
+    pub origin: Point,
+
+    /// Indicates the current position of the finger on the window.
+    pub position: Point,
+
+    /// The distance the finger has moved since the last pan event.
+    pub delta: Point,
+
+    /// The velocity of the finger in pixels per second.
+    pub velocity: Point,
+}
+
+/// `PinchEvent` occurs when two fingers are moved on the screen.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct PinchEvent {
+    pub phase: GesturePhase,
+
+    /// The center between the fingers at the start of the pinch. It is used to find the target.
+    pub origin: Point,
+
+    /// The current center between the fingers.
+    pub center: Point,
+
+    /// The distance between the fingers relative to the distance at the start of the pinch.
+    pub scale: f64,
+}
+
+/// `SwipeEvent` occurs when a single finger is lifted fast from the screen while it moves.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct SwipeEvent {
+    /// The position where the finger has touched the screen. It is used to find the target.
+    pub origin: Point,
+
+    pub direction: SwipeDirection,
+
+    /// The velocity of the finger in pixels per second.
+    pub velocity: Point,
+}
+
+/// A gesture that is recognized by the `GestureRecognizer`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Gesture {
+    Tap(TapEvent),
+    LongPress(LongPressEvent),
+    Pan(PanEvent),
+    Pinch(PinchEvent),
+    Swipe(SwipeEvent),
+}
+
+impl Gesture {
+    /// Registers the event of the gesture on the given queue.
+    pub fn register(self, event_queue: &mut EventQueue, source: Entity) {
+        match self {
+            Gesture::Tap(event) => event_queue.register_event(event, source),
+            Gesture::LongPress(event) => event_queue.register_event(event, source),
+            Gesture::Pan(event) => event_queue.register_event(event, source),
+            Gesture::Pinch(event) => event_queue.register_event(event, source),
+            Gesture::Swipe(event) => event_queue.register_event(event, source),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Finger {
+    id: u64,
+    start: Point,
+    position: Point,
+    start_time: Instant,
+    last_time: Instant,
+    velocity: Point,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Pinch {
+    origin: Point,
+    center: Point,
+    distance: f64,
+    scale: f64,
+}
+
+/// Recognizes taps, long presses, pans, pinches and swipes from the touch events of a window.
+///
+/// The time of each touch is passed to the recognizer, `update` has to be called periodically to
+/// recognize long presses.
+#[derive(Debug, Default)]
+pub struct GestureRecognizer {
+    fingers: Vec<Finger>,
+    pinch: Option<Pinch>,
+    panning: bool,
+    long_pressed: bool,
+    multi_touch: bool,
+}
+
+impl GestureRecognizer {
+    /// Creates a new gesture recognizer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Is called if a finger touches the screen.
+    pub fn touch_down(&mut self, id: u64, position: Point, time: Instant) -> Vec<Gesture> {
+        let mut gestures = vec![];
+
+        self.fingers.retain(|finger| finger.id != id);
+        self.fingers.push(Finger {
+            id,
+            start: position,
+            position,
+            start_time: time,
+            last_time: time,
+            velocity: Point::default(),
+        });
+
+        if self.fingers.len() == 2 {
+            self.multi_touch = true;
+
+            // a second finger turns the pan into a pinch
+            if let Some(pan) = self.end_pan(self.fingers[0], Point::default()) {
+                gestures.push(pan);
+            }
+
+            let (origin, distance) =
+                pinch_metrics(self.fingers[0].position, self.fingers[1].position);
+            self.pinch = Some(Pinch {
+                origin,
+                center: origin,
+                distance,
+                scale: 1.0,
+            });
+            gestures.push(Gesture::Pinch(PinchEvent {
+                phase: GesturePhase::Started,
+                origin,
+                center: origin,
+                scale: 1.0,
+            }));
+        }
+
+        gestures
+    }
+
+    /// Is called if a finger is moved on the screen.
+    pub fn touch_move(&mut self, id: u64, position: Point, time: Instant) -> Vec<Gesture> {
+        let finger = match self.fingers.iter_mut().find(|finger| finger.id == id) {
+            Some(finger) => finger,
+            None => return vec![],
+        };
+
+        let delta = position - finger.position;
+        let elapsed = time.duration_since(finger.last_time).as_secs_f64();
+
+        if elapsed > 0.0 {
+            finger.velocity = finger.velocity * 0.2 + delta * (0.8 / elapsed);
+        }
+
+        finger.position = position;
+        finger.last_time = time;
+        let finger = *finger;
+
+        if let Some(pinch) = &mut self.pinch {
+            let first = self.fingers[0].position;
+            let second = self.fingers[1].position;
+            let (center, distance) = pinch_metrics(first, second);
+            pinch.center = center;
+
+            if pinch.distance > 0.0 {
+                pinch.scale = distance / pinch.distance;
+            }
+
+            return vec![Gesture::Pinch(PinchEvent {
+                phase: GesturePhase::Changed,
+                origin: pinch.origin,
+                center,
+                scale: pinch.scale,
+            })];
+        }
+
+        if self.multi_touch || self.long_pressed {
+            return vec![];
+        }
+
+        if self.panning {
+            return vec![Gesture::Pan(PanEvent {
+                phase: GesturePhase::Changed,
+                origin: finger.start,
+                position,
+                delta,
+                velocity: finger.velocity,
+            })];
+        }
+
+        if finger.start.distance(position) > TAP_SLOP {
+            self.panning = true;
+
+            return vec![Gesture::Pan(PanEvent {
+                phase: GesturePhase::Started,
+                origin: finger.start,
+                position,
+                delta: position - finger.start,
+                velocity: finger.velocity,
+            })];
+        }
+
+        vec![]
+    }
+
+    /// Is called if a finger is lifted from the screen.
+    pub fn touch_up(&mut self, id: u64, position: Point, time: Instant) -> Vec<Gesture> {
+        let index = match self.fingers.iter().position(|finger| finger.id == id) {
+            Some(index) => index,
+            None => return vec![],
+        };
+
+        let finger = self.fingers.remove(index);
+        let mut gestures = vec![];
+
+        if let Some(pinch) = self.end_pinch() {
+            gestures.push(pinch);
+        }
+
+        if self.fingers.is_empty() {
+            let velocity = if time.duration_since(finger.last_time) > VELOCITY_TIMEOUT {
+                Point::default()
+            } else {
+                finger.velocity
+            };
+
+            if let Some(pan) = self.end_pan(finger, velocity) {
+                gestures.push(pan);
+
+                if let Some(direction) = swipe_direction(velocity) {
+                    gestures.push(Gesture::Swipe(SwipeEvent {
+                        origin: finger.start,
+                        direction,
+                        velocity,
+                    }));
+                }
+            } else if !self.multi_touch
+                && !self.long_pressed
+                && finger.start.distance(position) <= TAP_SLOP
+            {
+                gestures.push(Gesture::Tap(TapEvent { position }));
+            }
+
+            self.reset();
+        }
+
+        gestures
+    }
+
+    /// Is called if the system cancels a touch. Running gestures are ended without velocity.
+    pub fn touch_cancel(&mut self, id: u64) -> Vec<Gesture> {
+        let index = match self.fingers.iter().position(|finger| finger.id == id) {
+            Some(index) => index,
+            None => return vec![],
+        };
+
+        let finger = self.fingers.remove(index);
+        let mut gestures = vec![];
+
+        if let Some(pinch) = self.end_pinch() {
+            gestures.push(pinch);
+        }
+
+        if self.fingers.is_empty() {
+            if let Some(pan) = self.end_pan(finger, Point::default()) {
+                gestures.push(pan);
+            }
+
+            self.reset();
+        }
+
+        gestures
+    }
+
+    /// Recognizes a long press if a single finger rests on the screen long enough.
+    pub fn update(&mut self, time: Instant) -> Vec<Gesture> {
+        if let Some(deadline) = self.long_press_deadline() {
+            if time >= deadline {
+                self.long_pressed = true;
+
+                return vec![Gesture::LongPress(LongPressEvent {
+                    position: self.fingers[0].position,
+                })];
+            }
+        }
+
+        vec![]
+    }
+
+    /// Returns the time a long press will be recognized if the finger does not move.
+    pub fn long_press_deadline(&self) -> Option<Instant> {
+        if self.fingers.len() != 1 || self.panning || self.long_pressed || self.multi_touch {
+            return None;
+        }
+
+        Some(self.fingers[0].start_time + LONG_PRESS_DURATION)
+    }
+
+    fn end_pan(&mut self, finger: Finger, velocity: Point) -> Option<Gesture> {
+        if !self.panning {
+            return None;
+        }
+
+        self.panning = false;
+
+        Some(Gesture::Pan(PanEvent {
+            phase: GesturePhase::Ended,
+            origin: finger.start,
+            position: finger.position,
+            delta: Point::default(),
+            velocity,
+        }))
+    }
+
+    fn end_pinch(&mut self) -> Option<Gesture> {
+        if self.fingers.len() >= 2 {
+            return None;
+        }
+
+        self.pinch.take().map(|pinch| {
+            Gesture::Pinch(PinchEvent {
+                phase: GesturePhase::Ended,
+                origin: pinch.origin,
+                center: pinch.center,
+                scale: pinch.scale,
+            })
+        })
+    }
+
+    fn reset(&mut self) {
+        self.fingers.clear();
+        self.pinch = None;
+        self.panning = false;
+        self.long_pressed = false;
+        self.multi_touch = false;
+    }
+}
+
+// Returns the center and the distance between two fingers.
+fn pinch_metrics(first: Point, second: Point) -> (Point, f64) {
+    (
+        Point::new(
+            (first.x() + second.x()) / 2.0,
+            (first.y() + second.y()) / 2.0,
+        ),
+        first.distance(second),
+    )
+}
+
+// Returns the direction of a swipe with the given release velocity.
+fn swipe_direction(velocity: Point) -> Option<SwipeDirection> {
+    if velocity.x().abs().max(velocity.y().abs()) < SWIPE_VELOCITY {
+        return None;
+    }
+
+    if velocity.x().abs() > velocity.y().abs() {
+        if velocity.x() < 0.0 {
+            Some(SwipeDirection::Left)
+        } else {
+            Some(SwipeDirection::Right)
+        }
+    } else if velocity.y() < 0.0 {
+        Some(SwipeDirection::Up)
+    } else {
+        Some(SwipeDirection::Down)
+    }
+}
+
+/// Defines the pan handler function.
+pub type PanHandlerFunction = dyn Fn(&mut StatesContext, PanEvent) -> bool + 'static;
+
+/// Defines the pinch handler function.
+pub type PinchHandlerFunction = dyn Fn(&mut StatesContext, PinchEvent) -> bool + 'static;
+
+/// Defines the swipe handler function.
+pub type SwipeHandlerFunction = dyn Fn(&mut StatesContext, SwipeEvent) -> bool + 'static;
+
+/// Used to handle tap events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TapEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for TapEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TapEvent>() {
+            return (self.handler)(state_context, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TapEvent>()
+    }
+}
+
+/// Used to handle long press events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct LongPressEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for LongPressEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<LongPressEvent>() {
+            return (self.handler)(state_context, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LongPressEvent>()
+    }
+}
+
+/// Used to handle pan events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PanEventHandler {
+    handler: Rc<PanHandlerFunction>,
+}
+
+impl EventHandler for PanEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<PanEvent>() {
+            return (self.handler)(state_context, *event);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PanEvent>()
+    }
+}
+
+/// Used to handle pinch events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PinchEventHandler {
+    handler: Rc<PinchHandlerFunction>,
+}
+
+impl EventHandler for PinchEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<PinchEvent>() {
+            return (self.handler)(state_context, *event);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PinchEvent>()
+    }
+}
+
+/// Used to handle swipe events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct SwipeEventHandler {
+    handler: Rc<SwipeHandlerFunction>,
+}
+
+impl EventHandler for SwipeEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SwipeEvent>() {
+            return (self.handler)(state_context, *event);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SwipeEvent>()
+    }
+}
+
+pub trait GestureHandler: Sized + Widget {
+    /// Inserts a tap handler.
+    fn on_tap<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TapEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a long press handler.
+    fn on_long_press<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(LongPressEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a pan handler. Pan events are sent to the widgets below the start of the pan.
+    fn on_pan<H: Fn(&mut StatesContext, PanEvent) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(PanEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a pinch handler. Pinch events are sent to the widgets below the start of the pinch.
+    fn on_pinch<H: Fn(&mut StatesContext, PinchEvent) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(PinchEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a swipe handler. Swipe events are sent to the widgets below the start of the swipe.
+    fn on_swipe<H: Fn(&mut StatesContext, SwipeEvent) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(SwipeEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_tap() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new();
+
+        assert!(recognizer
+            .touch_down(1, Point::new(10.0, 10.0), start)
+            .is_empty());
+        assert!(recognizer
+            .touch_move(1, Point::new(12.0, 11.0), ms(start, 20))
+            .is_empty());
+        assert_eq!(
+            recognizer.touch_up(1, Point::new(12.0, 11.0), ms(start, 40)),
+            vec![Gesture::Tap(TapEvent {
+                position: Point::new(12.0, 11.0)
+            })]
+        );
+    }
+
+    #[test]
+    fn test_long_press() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_down(1, Point::new(10.0, 10.0), start);
+        assert_eq!(
+            recognizer.long_press_deadline(),
+            Some(start + LONG_PRESS_DURATION)
+        );
+        assert!(recognizer.update(ms(start, 100)).is_empty());
+        assert_eq!(
+            recognizer.update(ms(start, 500)),
+            vec![Gesture::LongPress(LongPressEvent {
+                position: Point::new(10.0, 10.0)
+            })]
+        );
+
+        // only once and no tap afterwards
+        assert!(recognizer.update(ms(start, 600)).is_empty());
+        assert!(recognizer
+            .touch_up(1, Point::new(10.0, 10.0), ms(start, 700))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_pan_and_swipe() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_down(1, Point::new(100.0, 10.0), start);
+
+        match recognizer.touch_move(1, Point::new(80.0, 10.0), ms(start, 20))[..] {
+            [Gesture::Pan(pan)] => {
+                assert_eq!(pan.phase, GesturePhase::Started);
+                assert_eq!(pan.origin, Point::new(100.0, 10.0));
+                assert_eq!(pan.delta, Point::new(-20.0, 0.0));
+            }
+            _ => panic!("pan expected"),
+        }
+
+        match recognizer.touch_move(1, Point::new(60.0, 10.0), ms(start, 40))[..] {
+            [Gesture::Pan(pan)] => {
+                assert_eq!(pan.phase, GesturePhase::Changed);
+                assert_eq!(pan.delta, Point::new(-20.0, 0.0));
+                assert!(pan.velocity.x() < -SWIPE_VELOCITY);
+            }
+            _ => panic!("pan expected"),
+        }
+
+        match recognizer.touch_up(1, Point::new(60.0, 10.0), ms(start, 50))[..] {
+            [Gesture::Pan(pan), Gesture::Swipe(swipe)] => {
+                assert_eq!(pan.phase, GesturePhase::Ended);
+                assert_eq!(swipe.direction, SwipeDirection::Left);
+                assert_eq!(swipe.origin, Point::new(100.0, 10.0));
+            }
+            _ => panic!("pan end and swipe expected"),
+        }
+    }
+
+    #[test]
+    fn test_pan_without_swipe() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_down(1, Point::new(10.0, 100.0), start);
+        recognizer.touch_move(1, Point::new(10.0, 60.0), ms(start, 20));
+
+        // the finger rests before it is lifted
+        match recognizer.touch_up(1, Point::new(10.0, 60.0), ms(start, 300))[..] {
+            [Gesture::Pan(pan)] => {
+                assert_eq!(pan.phase, GesturePhase::Ended);
+                assert_eq!(pan.velocity, Point::default());
+            }
+            _ => panic!("pan end expected"),
+        }
+    }
+
+    #[test]
+    fn test_pinch() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_down(1, Point::new(10.0, 10.0), start);
+
+        match recognizer.touch_down(2, Point::new(30.0, 10.0), ms(start, 10))[..] {
+            [Gesture::Pinch(pinch)] => {
+                assert_eq!(pinch.phase, GesturePhase::Started);
+                assert_eq!(pinch.origin, Point::new(20.0, 10.0));
+            }
+            _ => panic!("pinch expected"),
+        }
+
+        assert_eq!(recognizer.long_press_deadline(), None);
+
+        match recognizer.touch_move(2, Point::new(50.0, 10.0), ms(start, 20))[..] {
+            [Gesture::Pinch(pinch)] => {
+                assert_eq!(pinch.phase, GesturePhase::Changed);
+                assert_eq!(pinch.center, Point::new(30.0, 10.0));
+                assert!((pinch.scale - 2.0).abs() < f64::EPSILON);
+            }
+            _ => panic!("pinch expected"),
+        }
+
+        match recognizer.touch_up(2, Point::new(50.0, 10.0), ms(start, 30))[..] {
+            [Gesture::Pinch(pinch)] => assert_eq!(pinch.phase, GesturePhase::Ended),
+            _ => panic!("pinch end expected"),
+        }
+
+        // no tap after a multi touch
+        assert!(recognizer
+            .touch_up(1, Point::new(10.0, 10.0), ms(start, 40))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_cancel() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_down(1, Point::new(10.0, 10.0), start);
+        recognizer.touch_move(1, Point::new(40.0, 10.0), ms(start, 10));
+
+        match recognizer.touch_cancel(1)[..] {
+            [Gesture::Pan(pan)] => {
+                assert_eq!(pan.phase, GesturePhase::Ended);
+                assert_eq!(pan.velocity, Point::default());
+            }
+            _ => panic!("pan end expected"),
+        }
+
+        assert!(recognizer.touch_cancel(1).is_empty());
+    }
+}
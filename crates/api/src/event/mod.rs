@@ -6,31 +6,42 @@ use dces::entity::Entity;
 
 use crate::widget_base::StatesContext;
 
+pub use self::command::*;
+pub use self::drag::*;
 pub use self::drop::*;
 pub use self::editable::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
+pub use self::gesture::*;
 pub use self::key::*;
 pub use self::mouse::*;
 pub use self::system::*;
+pub use self::touch::*;
 pub use self::window::*;
+pub use self::window_event_sender::*;
 
+mod command;
+mod drag;
 mod drop;
 mod editable;
 mod event_handler;
 mod event_queue;
 mod focus;
+mod gesture;
 mod key;
 mod mouse;
 mod system;
+mod touch;
 mod window;
+mod window_event_sender;
 
 /// Defines the strategy of an event how it moves through the tree.
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventStrategy {
-    // /// From root to leaf.
-    // TopDown,
+    /// From root to leaf.
+    TopDown,
+
     /// From leaf to root.
     BottomUp,
 
@@ -23,6 +23,14 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
     rect.set_x(position.x());
     rect.set_y(position.y());
 
+    // the point is moved back into the untransformed space of the widget
+    if let Some(transform) = widget.try_get::<Matrix>("global_transform") {
+        return match transform.inverse() {
+            Some(inverse) => rect.contains(inverse.transform_point(mouse_position)),
+            None => false,
+        };
+    }
+
     rect.contains(mouse_position)
 }
 
@@ -33,6 +41,22 @@ pub struct MouseMoveEvent {
     pub position: Point,
 }
 
+/// `MouseEnterEvent` occurs when the mouse enters a widget. It is sent to each widget of the
+/// tree path below the mouse that was not hovered before.
+#[derive(Event)]
+pub struct MouseEnterEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `MouseLeaveEvent` occurs when the mouse leaves a widget. It is sent to each widget that was
+/// hovered before and is not longer on the tree path below the mouse.
+#[derive(Event)]
+pub struct MouseLeaveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
 /// `ScrollEvent` occurs when the mouse wheel is moved.
 #[derive(Event)]
 pub struct ScrollEvent {
@@ -77,6 +101,36 @@ pub struct MouseDownEvent {
     pub position: Point,
 }
 
+/// `PreviewMouseDownEvent` is sent from the root to the leaf before a `MouseDownEvent` is
+/// bubbled. If an ancestor handles it, the `MouseDownEvent` is not sent.
+#[derive(Event)]
+pub struct PreviewMouseDownEvent {
+    /// Indicates the mouse button that is pressed.
+    pub button: MouseButton,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `PreviewMouseUpEvent` is sent from the root to the leaf before a `MouseUpEvent` is bubbled.
+/// If an ancestor handles it, the `MouseUpEvent` is not sent.
+#[derive(Event)]
+pub struct PreviewMouseUpEvent {
+    /// Indicates the mouse button that is released.
+    pub button: MouseButton,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `PreviewMouseMoveEvent` is sent from the root to the leaf before a `MouseMoveEvent` is
+/// bubbled. If an ancestor handles it, the `MouseMoveEvent` is not sent.
+#[derive(Event)]
+pub struct PreviewMouseMoveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
 /// `GlobalMouseUpEvent` occurs when a mouse button is released.
 ///
 /// Global events could not be handled and could be read on each state.
@@ -101,6 +155,9 @@ pub type PositionHandlerFunction = dyn Fn(&mut StatesContext, Point) -> bool + '
 /// Defines the global bouse handler function.
 pub type GlobalMouseHandlerFunction = dyn Fn(&mut StatesContext, Mouse) + 'static;
 
+/// Defines the mouse enter and leave handler function.
+pub type HoverHandlerFunction = dyn Fn(&mut StatesContext, Point) + 'static;
+
 /// Used to handle click events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct ClickEventHandler {
@@ -239,6 +296,118 @@ impl EventHandler for ScrollEventHandler {
     }
 }
 
+/// Used to handle preview mouse down events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PreviewMouseDownEventHandler {
+    handler: Rc<MouseHandlerFunction>,
+}
+
+impl EventHandler for PreviewMouseDownEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<PreviewMouseDownEvent>() {
+            return (self.handler)(
+                state_context,
+                Mouse {
+                    button: event.button,
+                    position: event.position,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PreviewMouseDownEvent>()
+    }
+}
+
+/// Used to handle preview mouse up events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PreviewMouseUpEventHandler {
+    handler: Rc<MouseHandlerFunction>,
+}
+
+impl EventHandler for PreviewMouseUpEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<PreviewMouseUpEvent>() {
+            return (self.handler)(
+                state_context,
+                Mouse {
+                    button: event.button,
+                    position: event.position,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PreviewMouseUpEvent>()
+    }
+}
+
+/// Used to handle preview mouse move events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PreviewMouseMoveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for PreviewMouseMoveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<PreviewMouseMoveEvent>() {
+            return (self.handler)(state_context, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PreviewMouseMoveEvent>()
+    }
+}
+
+/// Used to handle mouse enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseEnterEventHandler {
+    handler: Rc<HoverHandlerFunction>,
+}
+
+impl EventHandler for MouseEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<MouseEnterEvent>() {
+            (self.handler)(state_context, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseEnterEvent>()
+    }
+}
+
+/// Used to handle mouse leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseLeaveEventHandler {
+    handler: Rc<HoverHandlerFunction>,
+}
+
+impl EventHandler for MouseLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<MouseLeaveEvent>() {
+            (self.handler)(state_context, event.position);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseLeaveEvent>()
+    }
+}
+
 pub trait MouseHandler: Sized + Widget {
     /// Inserts a click handler.
     fn on_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
@@ -275,6 +444,53 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a preview mouse down handler. It is called from the root to the leaf before the
+    /// mouse down handlers, return `true` to intercept the event.
+    fn on_preview_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewMouseDownEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a preview mouse up handler. It is called from the root to the leaf before the
+    /// mouse up handlers, return `true` to intercept the event.
+    fn on_preview_mouse_up<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewMouseUpEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a preview mouse move handler. It is called from the root to the leaf before the
+    /// mouse move handlers, return `true` to intercept the event.
+    fn on_preview_mouse_move<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewMouseMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a mouse enter handler.
+    fn on_mouse_enter<H: Fn(&mut StatesContext, Point) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(MouseEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a mouse leave handler.
+    fn on_mouse_leave<H: Fn(&mut StatesContext, Point) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(MouseLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse up handler.
     fn on_scroll<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(ScrollEventHandler {
@@ -0,0 +1,197 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// Represents the current state of a finger of a touch event.
+#[derive(Debug, Copy, Clone)]
+pub struct Touch {
+    /// Identifies the finger. The id is unique as long as the finger touches the screen.
+    pub id: u64,
+
+    /// Indicates position of the finger on the window.
+    pub position: Point,
+}
+
+/// `TouchDownEvent` occurs when a finger touches the screen.
+#[derive(Event)]
+pub struct TouchDownEvent {
+    /// Identifies the finger.
+    pub id: u64,
+
+    /// Indicates position of the finger on the window.
+    pub position: Point,
+}
+
+/// `TouchMoveEvent` occurs when a finger is moved on the screen.
+#[derive(Event)]
+pub struct TouchMoveEvent {
+    /// Identifies the finger.
+    pub id: u64,
+
+    /// Indicates position of the finger on the window.
+    pub position: Point,
+}
+
+/// `TouchUpEvent` occurs when a finger is lifted from the screen.
+#[derive(Event)]
+pub struct TouchUpEvent {
+    /// Identifies the finger.
+    pub id: u64,
+
+    /// Indicates position of the finger on the window.
+    pub position: Point,
+}
+
+/// `TouchCancelEvent` occurs when the system cancels a touch, e.g. if the window lost the focus.
+#[derive(Event)]
+pub struct TouchCancelEvent {
+    /// Identifies the finger.
+    pub id: u64,
+
+    /// Indicates the last position of the finger on the window.
+    pub position: Point,
+}
+
+/// Defines the touch handler function.
+pub type TouchHandlerFunction = dyn Fn(&mut StatesContext, Touch) -> bool + 'static;
+
+/// Used to handle touch down events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchDownEventHandler {
+    handler: Rc<TouchHandlerFunction>,
+}
+
+impl EventHandler for TouchDownEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TouchDownEvent>() {
+            return (self.handler)(
+                state_context,
+                Touch {
+                    id: event.id,
+                    position: event.position,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchDownEvent>()
+    }
+}
+
+/// Used to handle touch move events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchMoveEventHandler {
+    handler: Rc<TouchHandlerFunction>,
+}
+
+impl EventHandler for TouchMoveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TouchMoveEvent>() {
+            return (self.handler)(
+                state_context,
+                Touch {
+                    id: event.id,
+                    position: event.position,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchMoveEvent>()
+    }
+}
+
+/// Used to handle touch up events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchUpEventHandler {
+    handler: Rc<TouchHandlerFunction>,
+}
+
+impl EventHandler for TouchUpEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TouchUpEvent>() {
+            return (self.handler)(
+                state_context,
+                Touch {
+                    id: event.id,
+                    position: event.position,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchUpEvent>()
+    }
+}
+
+/// Used to handle touch cancel events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchCancelEventHandler {
+    handler: Rc<TouchHandlerFunction>,
+}
+
+impl EventHandler for TouchCancelEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<TouchCancelEvent>() {
+            return (self.handler)(
+                state_context,
+                Touch {
+                    id: event.id,
+                    position: event.position,
+                },
+            );
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchCancelEvent>()
+    }
+}
+
+pub trait TouchHandler: Sized + Widget {
+    /// Inserts a touch down handler.
+    fn on_touch_down<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TouchDownEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch move handler.
+    fn on_touch_move<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TouchMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch up handler.
+    fn on_touch_up<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TouchUpEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch cancel handler.
+    fn on_touch_cancel<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchCancelEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
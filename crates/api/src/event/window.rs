@@ -8,6 +8,7 @@ use crate::{proc_macros::*, widget_base::*};
 pub enum WindowEvent {
     Resize { width: f64, height: f64 },
     ActiveChanged(bool),
+    ScaleFactorChanged(f64),
     None,
 }
 
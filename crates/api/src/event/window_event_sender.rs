@@ -0,0 +1,47 @@
+use std::{cell::RefCell, rc::Rc, sync::mpsc};
+
+use dces::entity::Entity;
+
+use crate::shell::WindowRequest;
+
+use super::{Event, EventQueue, EventStrategy};
+
+/// The `WindowEventSender` sends events directly to a widget of its window. It could be used by
+/// another window of the application e.g. to deliver the result of a dialog window to its owner.
+#[derive(Clone)]
+pub struct WindowEventSender {
+    event_queue: Rc<RefCell<EventQueue>>,
+    window_sender: mpsc::Sender<WindowRequest>,
+    target: Entity,
+}
+
+impl WindowEventSender {
+    /// Creates a new event sender for the given target widget.
+    pub fn new(
+        event_queue: Rc<RefCell<EventQueue>>,
+        window_sender: mpsc::Sender<WindowRequest>,
+        target: Entity,
+    ) -> Self {
+        WindowEventSender {
+            event_queue,
+            window_sender,
+            target,
+        }
+    }
+
+    /// Gets the target widget of the sender.
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Sends the event to the target and requests a redraw of the target window, so the event
+    /// is handled on the next iteration.
+    pub fn send<E: Event>(&self, event: E) {
+        self.event_queue.borrow_mut().register_event_with_strategy(
+            event,
+            EventStrategy::Direct,
+            self.target,
+        );
+        let _ = self.window_sender.send(WindowRequest::Redraw);
+    }
+}
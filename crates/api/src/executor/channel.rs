@@ -0,0 +1,129 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+struct Shared<T> {
+    queue: VecDeque<T>,
+    waker: Option<Waker>,
+    senders: usize,
+    receiver: bool,
+}
+
+/// Creates a channel to send values from any thread to a future e.g. of the ui thread.
+///
+/// # Example
+///
+/// ```rust
+/// let (sender, mut receiver) = async_channel();
+///
+/// std::thread::spawn(move || {
+///     let _ = sender.send(download("https://redox-os.org"));
+/// });
+///
+/// ctx.spawn_local_then(
+///     async move { receiver.recv().await },
+///     |state: &mut MainState, page, ctx| state.show(page, ctx),
+/// );
+/// ```
+pub fn async_channel<T>() -> (AsyncSender<T>, AsyncReceiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        queue: VecDeque::new(),
+        waker: None,
+        senders: 1,
+        receiver: true,
+    }));
+
+    (AsyncSender(shared.clone()), AsyncReceiver(shared))
+}
+
+/// The sending half of an `async_channel`. It could be cloned and send to other threads.
+pub struct AsyncSender<T>(Arc<Mutex<Shared<T>>>);
+
+impl<T> AsyncSender<T> {
+    /// Sends a value to the receiver. Returns the value as error if the receiver is dropped.
+    pub fn send(&self, value: T) -> Result<(), T> {
+        let mut shared = self.0.lock().unwrap();
+
+        if !shared.receiver {
+            return Err(value);
+        }
+
+        shared.queue.push_back(value);
+
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Clone for AsyncSender<T> {
+    fn clone(&self) -> Self {
+        self.0.lock().unwrap().senders += 1;
+        AsyncSender(self.0.clone())
+    }
+}
+
+impl<T> Drop for AsyncSender<T> {
+    fn drop(&mut self) {
+        let mut shared = self.0.lock().unwrap();
+        shared.senders -= 1;
+
+        // wakes up the receiver to return `None`
+        if shared.senders == 0 {
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// The receiving half of an `async_channel`.
+pub struct AsyncReceiver<T>(Arc<Mutex<Shared<T>>>);
+
+impl<T> AsyncReceiver<T> {
+    /// Receives the next value. The future returns `None` if all senders are dropped.
+    pub fn recv(&mut self) -> Recv<'_, T> {
+        Recv { receiver: self }
+    }
+
+    /// Returns the next value if there is one.
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.0.lock().unwrap().queue.pop_front()
+    }
+}
+
+impl<T> Drop for AsyncReceiver<T> {
+    fn drop(&mut self) {
+        self.0.lock().unwrap().receiver = false;
+    }
+}
+
+/// Future that returns the next value of an `AsyncReceiver`.
+pub struct Recv<'a, T> {
+    receiver: &'a mut AsyncReceiver<T>,
+}
+
+impl<'a, T> Future for Recv<'a, T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<T>> {
+        let mut shared = self.receiver.0.lock().unwrap();
+
+        if let Some(value) = shared.queue.pop_front() {
+            return Poll::Ready(Some(value));
+        }
+
+        if shared.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
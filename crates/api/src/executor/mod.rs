@@ -0,0 +1,212 @@
+//! This module contains a single threaded executor that runs futures on the ui thread.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::BTreeMap,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{mpsc, Arc, Mutex},
+    task::{self, Poll, Wake, Waker},
+};
+
+use dces::entity::Entity;
+
+use crate::{shell::WindowRequest, widget_base::Context};
+
+pub use self::channel::*;
+pub use self::timer::*;
+
+mod channel;
+mod timer;
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Call that is delivered to the state of a widget after its future is completed.
+pub type LocalCall = Box<dyn FnOnce(&mut dyn Any, &mut Context)>;
+
+// Wakes a task of the executor, it could be called from any thread.
+struct TaskWaker {
+    task: usize,
+    woken: Arc<Mutex<Vec<usize>>>,
+    window_sender: Mutex<mpsc::Sender<WindowRequest>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.lock().unwrap().push(self.task);
+
+        // wakes up the shell, the task is polled on the next run
+        let _ = self
+            .window_sender
+            .lock()
+            .unwrap()
+            .send(WindowRequest::Redraw);
+    }
+}
+
+/// The `LocalExecutor` runs the futures of a window on the ui thread. The futures are polled
+/// before the widget states are updated. Futures could await timers, channels and the futures of
+/// other runtimes e.g. the `JoinHandle` of a task that runs on another thread.
+pub struct LocalExecutor {
+    next_task: usize,
+    tasks: BTreeMap<usize, LocalFuture>,
+    woken: Arc<Mutex<Vec<usize>>>,
+    completed: Rc<RefCell<Vec<(Entity, LocalCall)>>>,
+    timers: Rc<RefCell<Timers>>,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+impl LocalExecutor {
+    /// Creates a new executor. The sender is used to wake up the window.
+    pub fn new(window_sender: mpsc::Sender<WindowRequest>) -> Self {
+        LocalExecutor {
+            next_task: 0,
+            tasks: BTreeMap::new(),
+            woken: Arc::new(Mutex::new(vec![])),
+            completed: Rc::new(RefCell::new(vec![])),
+            timers: Rc::new(RefCell::new(Timers::default())),
+            window_sender,
+        }
+    }
+
+    /// Check if the executor has no running tasks.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Spawns a future that is polled on the next run.
+    pub fn spawn<F: Future<Output = ()> + 'static>(&mut self, future: F) {
+        let task = self.next_task;
+        self.next_task += 1;
+        self.tasks.insert(task, Box::pin(future));
+        self.waker(task).wake();
+    }
+
+    /// Spawns a future. After it is completed `then` is called with the state of the given widget.
+    pub fn spawn_then<S, F, C>(&mut self, entity: Entity, future: F, then: C)
+    where
+        S: Any,
+        F: Future + 'static,
+        C: FnOnce(&mut S, F::Output, &mut Context) + 'static,
+    {
+        let completed = self.completed.clone();
+
+        self.spawn(async move {
+            let output = future.await;
+
+            completed.borrow_mut().push((
+                entity,
+                Box::new(move |state: &mut dyn Any, ctx: &mut Context| {
+                    if let Some(state) = state.downcast_mut::<S>() {
+                        then(state, output, ctx);
+                    }
+                }),
+            ));
+        });
+    }
+
+    fn waker(&self, task: usize) -> Waker {
+        Waker::from(Arc::new(TaskWaker {
+            task,
+            woken: self.woken.clone(),
+            window_sender: Mutex::new(self.window_sender.clone()),
+        }))
+    }
+
+    /// Polls all woken futures once and returns the calls of the completed futures.
+    pub fn run(&mut self) -> Vec<(Entity, LocalCall)> {
+        self.timers.borrow_mut().wake_elapsed();
+
+        let mut woken = std::mem::take(&mut *self.woken.lock().unwrap());
+        woken.sort_unstable();
+        woken.dedup();
+
+        let _current = CurrentTimers::set(self.timers.clone());
+
+        for task in woken {
+            if let Some(mut future) = self.tasks.remove(&task) {
+                let waker = self.waker(task);
+                let mut cx = task::Context::from_waker(&waker);
+
+                if future.as_mut().poll(&mut cx).is_pending() {
+                    self.tasks.insert(task, future);
+                }
+            }
+        }
+
+        // wakes up the window when the next timer is elapsed
+        if let Some(deadline) = self.timers.borrow().next_deadline() {
+            let _ = self.window_sender.send(WindowRequest::RedrawAt(deadline));
+        }
+
+        std::mem::take(&mut *self.completed.borrow_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_spawn() {
+        let (sender, receiver) = mpsc::channel();
+        let mut executor = LocalExecutor::new(sender);
+
+        let (channel_sender, mut channel_receiver) = async_channel::<u32>();
+
+        executor.spawn_then(
+            Entity::from(1),
+            async move { channel_receiver.recv().await },
+            |_: &mut u32, _, _| {},
+        );
+        assert_eq!(receiver.try_recv(), Ok(WindowRequest::Redraw));
+
+        assert!(executor.run().is_empty());
+        assert!(!executor.is_empty());
+
+        std::thread::spawn(move || channel_sender.send(5).unwrap())
+            .join()
+            .unwrap();
+
+        // the receiver wakes up the window
+        assert_eq!(receiver.try_recv(), Ok(WindowRequest::Redraw));
+
+        let completed = executor.run();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].0, Entity::from(1));
+        assert!(executor.is_empty());
+    }
+
+    #[test]
+    fn test_delay() {
+        let (sender, receiver) = mpsc::channel();
+        let mut executor = LocalExecutor::new(sender);
+
+        executor.spawn(async {
+            delay(Duration::from_millis(20)).await;
+        });
+
+        executor.run();
+        assert!(!executor.is_empty());
+
+        // the first request wakes up the window for the first poll
+        assert_eq!(receiver.try_recv(), Ok(WindowRequest::Redraw));
+
+        match receiver.try_recv() {
+            Ok(WindowRequest::RedrawAt(_)) => {}
+            request => panic!("expected RedrawAt, got {:?}", request),
+        }
+
+        std::thread::sleep(Duration::from_millis(30));
+        executor.run();
+        assert!(executor.is_empty());
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+    thread,
+    time::Duration,
+};
+
+use crate::shell::Instant;
+
+thread_local! {
+    // timers of the executor that currently polls its futures.
+    static CURRENT_TIMERS: RefCell<Option<Rc<RefCell<Timers>>>> = const { RefCell::new(None) };
+}
+
+// ids of the timers, unique over all executors.
+static NEXT_TIMER_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Pending timers of an executor.
+#[derive(Default)]
+pub(crate) struct Timers {
+    timers: Vec<(usize, Instant, Waker)>,
+}
+
+impl Timers {
+    fn add(&mut self, deadline: Instant, waker: Waker) -> usize {
+        let id = NEXT_TIMER_ID.fetch_add(1, Ordering::Relaxed);
+        self.timers.push((id, deadline, waker));
+        id
+    }
+
+    // Replaces the waker of a pending timer if it has changed. Returns `false` if there is no
+    // pending timer with the given id.
+    fn update(&mut self, id: usize, waker: &Waker) -> bool {
+        match self.timers.iter_mut().find(|(timer, _, _)| *timer == id) {
+            Some((_, _, registered)) => {
+                if !registered.will_wake(waker) {
+                    *registered = waker.clone();
+                }
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Wakes the futures of all elapsed timers.
+    pub(crate) fn wake_elapsed(&mut self) {
+        let now = Instant::now();
+        let (elapsed, pending) = std::mem::take(&mut self.timers)
+            .into_iter()
+            .partition(|(_, deadline, _)| *deadline <= now);
+        self.timers = pending;
+
+        for (_, _, waker) in elapsed {
+            waker.wake();
+        }
+    }
+
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.timers.iter().map(|(_, deadline, _)| *deadline).min()
+    }
+}
+
+// Sets the timers of the executor that polls, resets them on drop.
+pub(crate) struct CurrentTimers {
+    previous: Option<Rc<RefCell<Timers>>>,
+}
+
+impl CurrentTimers {
+    pub(crate) fn set(timers: Rc<RefCell<Timers>>) -> Self {
+        CurrentTimers {
+            previous: CURRENT_TIMERS.with(|current| current.borrow_mut().replace(timers)),
+        }
+    }
+}
+
+impl Drop for CurrentTimers {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_TIMERS.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// A future that is completed after a point in time.
+///
+/// On the executor of the ui thread the window sleeps until the next timer is elapsed. On other
+/// executors a separate thread wakes up the future.
+pub struct Delay {
+    deadline: Instant,
+    // id of the registered timer, it is registered again only if it is not pending anymore
+    timer: Option<usize>,
+    thread_started: bool,
+}
+
+/// Creates a future that is completed after the given duration.
+///
+/// # Example
+///
+/// ```rust
+/// ctx.spawn_local_then(
+///     async { delay(Duration::from_secs(2)).await },
+///     |state: &mut MainState, _, ctx| state.hide_notification(ctx),
+/// );
+/// ```
+pub fn delay(duration: Duration) -> Delay {
+    delay_until(Instant::now() + duration)
+}
+
+/// Creates a future that is completed at the given point in time.
+pub fn delay_until(deadline: Instant) -> Delay {
+    Delay {
+        deadline,
+        timer: None,
+        thread_started: false,
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+
+        if Instant::now() >= this.deadline {
+            return Poll::Ready(());
+        }
+
+        let registered = CURRENT_TIMERS.with(|current| {
+            if let Some(timers) = &*current.borrow() {
+                let mut timers = timers.borrow_mut();
+
+                if !this.timer.is_some_and(|id| timers.update(id, cx.waker())) {
+                    this.timer = Some(timers.add(this.deadline, cx.waker().clone()));
+                }
+
+                return true;
+            }
+
+            false
+        });
+
+        if !registered && !this.thread_started {
+            this.thread_started = true;
+
+            let deadline = this.deadline;
+            let waker = cx.waker().clone();
+
+            thread::spawn(move || {
+                let now = Instant::now();
+
+                if deadline > now {
+                    thread::sleep(deadline - now);
+                }
+
+                waker.wake();
+            });
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, task::Wake};
+
+    use super::*;
+
+    struct TestWaker;
+
+    impl Wake for TestWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[test]
+    fn test_poll() {
+        let timers = Rc::new(RefCell::new(Timers::default()));
+        let _current = CurrentTimers::set(timers.clone());
+
+        let waker = Waker::from(Arc::new(TestWaker));
+        let mut delay = delay(Duration::from_secs(60));
+
+        for _ in 0..3 {
+            assert!(Pin::new(&mut delay)
+                .poll(&mut Context::from_waker(&waker))
+                .is_pending());
+        }
+
+        assert_eq!(timers.borrow().timers.len(), 1);
+
+        // a new waker replaces the waker of the pending timer
+        let other = Waker::from(Arc::new(TestWaker));
+        assert!(Pin::new(&mut delay)
+            .poll(&mut Context::from_waker(&other))
+            .is_pending());
+
+        assert_eq!(timers.borrow().timers.len(), 1);
+        assert!(timers.borrow().timers[0].2.will_wake(&other));
+    }
+}
@@ -19,10 +19,12 @@ use crate::{
 use super::{component, component_try_mut, Layout};
 
 /// Fixed size layout is defined by fixed bounds like the size of an image or the size of a text.
+/// Images with a `stretch` or a `nine_slice` are sized by their constraint and alignment instead.
 #[derive(Default, IntoLayout)]
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    stretched: Cell<bool>,
 }
 
 impl FixedSizeLayout {
@@ -115,10 +117,36 @@ impl Layout for FixedSizeLayout {
                                     + 1.0),
                         )
                     })
+            })
+            .or_else(|| {
+                // svg icons are as high as the icon size and keep the aspect ratio of the image
+                widget.try_get::<Image>("svg").map(|svg| {
+                    let icon_size = *widget.get::<f64>("icon_size");
+
+                    if svg.width() > 0.0 && svg.height() > 0.0 {
+                        (icon_size * svg.width() / svg.height(), icon_size)
+                    } else {
+                        (icon_size, icon_size)
+                    }
+                })
             });
 
+        let stretched = !matches!(
+            widget.try_get::<Stretch>("stretch"),
+            None | Some(Stretch::None)
+        ) || matches!(
+            widget.try_get::<Thickness>("nine_slice"),
+            Some(nine_slice) if *nine_slice != Thickness::default()
+        );
+        self.stretched.set(stretched);
+
         if let Some(size) = size {
-            if let Some(constraint) = component_try_mut::<Constraint>(ecm, entity, "constraint") {
+            if stretched {
+                // the natural size is used only if the constraint does not define the size
+                self.desired_size.borrow_mut().set_size(size.0, size.1);
+            } else if let Some(constraint) =
+                component_try_mut::<Constraint>(ecm, entity, "constraint")
+            {
                 constraint.set_width(size.0 as f64);
                 constraint.set_height(size.1 as f64);
             }
@@ -154,7 +182,7 @@ impl Layout for FixedSizeLayout {
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -165,6 +193,30 @@ impl Layout for FixedSizeLayout {
             return (0.0, 0.0);
         }
 
+        if self.stretched.get() {
+            let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+            let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+            let margin: Thickness = component(ecm, entity, "margin");
+            let constraint: Constraint = component(ecm, entity, "constraint");
+
+            let size = constraint.perform((
+                horizontal_alignment.align_measure(
+                    parent_size.0,
+                    self.desired_size.borrow().width(),
+                    margin.left(),
+                    margin.right(),
+                ),
+                vertical_alignment.align_measure(
+                    parent_size.1,
+                    self.desired_size.borrow().height(),
+                    margin.top(),
+                    margin.bottom(),
+                ),
+            ));
+
+            self.desired_size.borrow_mut().set_size(size.0, size.1);
+        }
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(self.desired_size.borrow().width());
             bounds.set_height(self.desired_size.borrow().height());
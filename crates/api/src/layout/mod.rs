@@ -40,6 +40,9 @@ pub trait Layout: Any {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64);
+
+    /// Forces a new arrangement e.g. after the order of the children is changed.
+    fn invalidate(&self) {}
 }
 
 fn component<C: Component + Clone>(
@@ -214,6 +214,10 @@ impl Layout for StackLayout {
 
         size
     }
+
+    fn invalidate(&self) {
+        self.set_dirty(true);
+    }
 }
 
 fn apply_arrangement(
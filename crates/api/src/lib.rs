@@ -15,8 +15,10 @@ pub(crate) use orbtk_tree::prelude as tree;
 pub(crate) use orbtk_utils::prelude as utils;
 
 pub mod application;
+pub mod command;
 #[macro_use]
 pub mod event;
+pub mod executor;
 pub mod layout;
 pub mod prelude;
 pub mod properties;
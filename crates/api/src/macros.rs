@@ -1,4 +1,4 @@
-pub use crate::{theming::prelude::*, utils::prelude::*};
+pub use crate::{shell::CursorIcon, theming::prelude::*, utils::prelude::*};
 pub use dces::prelude::*;
 
 #[macro_export]
@@ -98,6 +98,8 @@ macro_rules! widget {
             name: Option<String>,
             style: Option<String>,
             id: Option<String>,
+            cursor_icon: Option<PropertySource<CursorIcon>>,
+            render_transform: Option<PropertySource<RenderTransform>>,
             #[property(Alignment)]
             h_align: Alignment,
             #[property(Alignment)]
@@ -227,6 +229,24 @@ macro_rules! widget {
                 self.set_property("opacity", opacity)
             }
 
+            /// Sets or shares the shape of the mouse cursor over the widget.
+            pub fn cursor_icon(mut self, cursor_icon: impl IntoPropertySource<CursorIcon>) -> Self {
+                if !self.cursor_icon.is_none() {
+                    return self;
+                }
+                self.cursor_icon = Some(cursor_icon.into_source());
+                self
+            }
+
+            /// Sets or shares the transformation that is applied to the widget and its children while rendering.
+            pub fn render_transform(mut self, render_transform: impl IntoPropertySource<RenderTransform>) -> Self {
+                if !self.render_transform.is_none() {
+                    return self;
+                }
+                self.render_transform = Some(render_transform.into_source());
+                self
+            }
+
             /// Inserts a new width.
             pub fn width(mut self, width: impl Into<f64>) -> Self {
                 if !self.width.is_none() {
@@ -466,6 +486,36 @@ macro_rules! widget {
                     ctx.register_property("id", entity, id);
                 }
 
+                // the cursor icon is only registered if it is set, otherwise the icon of the parent is used
+                if let Some(cursor_icon) = this.cursor_icon {
+                    match cursor_icon {
+                        PropertySource::Value(value) => {
+                            ctx.register_property("cursor_icon", entity, value);
+                        }
+                        PropertySource::Source(source) => {
+                            ctx.register_shared_property::<CursorIcon>("cursor_icon", entity, source);
+                        }
+                        PropertySource::KeySource(source_key, source) => {
+                            ctx.register_shared_property_by_source_key::<CursorIcon>("cursor_icon", source_key.as_str(), entity, source);
+                        }
+                    }
+                }
+
+                // the render transform is only registered if it is set, to keep untransformed widgets cheap
+                if let Some(render_transform) = this.render_transform {
+                    match render_transform {
+                        PropertySource::Value(value) => {
+                            ctx.register_property("render_transform", entity, value);
+                        }
+                        PropertySource::Source(source) => {
+                            ctx.register_shared_property::<RenderTransform>("render_transform", entity, source);
+                        }
+                        PropertySource::KeySource(source_key, source) => {
+                            ctx.register_shared_property_by_source_key::<RenderTransform>("render_transform", source_key.as_str(), entity, source);
+                        }
+                    }
+                }
+
                 if let Some(style) = this.style {
                     ctx.register_property("selector", entity, Selector::new(style));
                 } else {
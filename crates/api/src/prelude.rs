@@ -3,7 +3,9 @@ pub use std::rc::Rc;
 
 // crates modules
 pub use crate::application::*;
+pub use crate::command::*;
 pub use crate::event::*;
+pub use crate::executor::*;
 pub use crate::layout::*;
 pub use crate::macros::*;
 pub use crate::properties::*;
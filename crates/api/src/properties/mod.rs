@@ -6,7 +6,7 @@ use dces::prelude::{Component, Entity, StringComponentStore};
 
 pub use self::layout::*;
 pub use self::widget::*;
-use crate::{into_property_source, render, theming, utils};
+use crate::{into_property_source, render, shell, theming, utils};
 
 mod layout;
 mod widget;
@@ -89,9 +89,12 @@ into_property_source!(i64);
 
 // Implementation of PropertySource for utils types
 into_property_source!(utils::Alignment: &str);
+into_property_source!(utils::BoxShadow: &str, String, utils::Value);
 into_property_source!(utils::Brush: &str, utils::Color, utils::Value);
+into_property_source!(utils::CornerRadius: f64, i32, (f64, f64, f64, f64), utils::Value);
 into_property_source!(utils::Orientation: &str);
 into_property_source!(utils::Point: f64, i32, (i32, i32), (f64, f64));
+into_property_source!(utils::RenderTransform: utils::Matrix);
 into_property_source!(utils::Size: f64, i32, (i32, i32), (f64, f64));
 into_property_source!(
     utils::Rectangle: (i32, i32, i32, i32),
@@ -108,6 +111,8 @@ into_property_source!(
     utils::Value
 );
 into_property_source!(utils::SelectionMode: &str);
+into_property_source!(utils::SortDirection: &str);
+into_property_source!(utils::Stretch: &str);
 into_property_source!(utils::Visibility: &str);
 into_property_source!(Vec<String>);
 into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
@@ -116,6 +121,9 @@ into_property_source!(utils::Filter: &str, String, Vec<String>, Vec<&str>);
 into_property_source!(theming::Selector: &str, String);
 into_property_source!(theming::Theme);
 
+// Implementation of shell property types
+into_property_source!(shell::CursorIcon: &str);
+
 // Implementation of render property types
 into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 
@@ -123,10 +131,16 @@ into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 into_property_source!(Columns: ColumnsBuilder);
 into_property_source!(utils::Constraint: utils::ConstraintBuilder);
 into_property_source!(DefaultRenderPipeline);
+into_property_source!(Drawing);
+into_property_source!(MessageBoxButtons: &str);
+into_property_source!(MessageBoxKind: &str);
 into_property_source!(Rows: RowsBuilder);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
+into_property_source!(SelectedPaths: HashSet<TreePath>);
+into_property_source!(Shortcut: &str, shell::Key);
 into_property_source!(TextSelection: (usize, usize));
+into_property_source!(TreePath: Vec<usize>);
 into_property_source!(FocusState);
 into_property_source!(KeyboardState);
@@ -0,0 +1,621 @@
+use crate::{
+    render::{Image, RenderContext2D},
+    utils::{Brush, Matrix, Point},
+};
+
+// Number of lines a curve is split into for hit testing.
+const CURVE_SEGMENTS: usize = 16;
+
+#[derive(Clone, Debug, PartialEq)]
+enum DrawingCommand {
+    BeginPath,
+    MoveTo(f64, f64),
+    LineTo(f64, f64),
+    QuadraticCurveTo(f64, f64, f64, f64),
+    BezierCurveTo(f64, f64, f64, f64, f64, f64),
+    Arc(f64, f64, f64, f64, f64),
+    Rect(f64, f64, f64, f64),
+    ClosePath,
+    Fill,
+    Stroke,
+    Clip,
+    FillText(String, f64, f64),
+    DrawImage(Image, f64, f64),
+    DrawImageWithSize(Image, f64, f64, f64, f64),
+    SetFillStyle(Brush),
+    SetStrokeStyle(Brush),
+    SetLineWidth(f64),
+    SetAlpha(f32),
+    SetFontFamily(String),
+    SetFontSize(f64),
+    Transform(Matrix),
+    Save,
+    Restore,
+}
+
+// Area of a named shape in coordinates of the drawing.
+#[derive(Clone, Debug, PartialEq)]
+enum ShapeArea {
+    // polygons filled by the non-zero winding rule
+    Fill(Vec<Vec<Point>>),
+
+    // lines with the half of their width
+    Stroke(Vec<Vec<Point>>, f64),
+}
+
+impl ShapeArea {
+    fn contains(&self, point: Point) -> bool {
+        match self {
+            ShapeArea::Fill(polygons) => winding(polygons, point) != 0,
+            ShapeArea::Stroke(lines, half_width) => lines.iter().any(|line| {
+                line.windows(2)
+                    .any(|segment| distance(segment[0], segment[1], point) <= *half_width)
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Shape {
+    name: String,
+    area: ShapeArea,
+}
+
+/// A `Drawing` records drawing commands through an API like the one of `RenderContext2D`. The
+/// commands are replayed each time the widget is drawn, so the drawing only has to be recorded
+/// again if its content changes.
+///
+/// Paths and images that are drawn between `begin_shape` and `end_shape` are named shapes,
+/// `hit_test` returns the name of the topmost shape at a point, e.g. to identify the bar of a
+/// chart that is clicked.
+///
+/// # Example
+///
+/// ```rust
+/// let mut drawing = Drawing::new();
+///
+/// drawing.begin_shape("bar");
+/// drawing.set_fill_style(Brush::from("#4d4c5a"));
+/// drawing.begin_path();
+/// drawing.rect(10.0, 20.0, 30.0, 80.0);
+/// drawing.fill();
+/// drawing.end_shape();
+///
+/// assert_eq!(drawing.hit_test((20.0, 50.0)), Some("bar"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drawing {
+    commands: Vec<DrawingCommand>,
+    shapes: Vec<Shape>,
+
+    // state of the recording used to build the shapes
+    shape: Option<String>,
+    path: Vec<Vec<Point>>,
+    current_point: Point,
+    start_point: Point,
+    transform: Matrix,
+    line_width: f64,
+    saved_states: Vec<(Matrix, f64)>,
+}
+
+impl Default for Drawing {
+    fn default() -> Self {
+        Drawing {
+            commands: vec![],
+            shapes: vec![],
+            shape: None,
+            path: vec![],
+            current_point: Point::default(),
+            start_point: Point::default(),
+            transform: Matrix::identity(),
+            line_width: 1.0,
+            saved_states: vec![],
+        }
+    }
+}
+
+impl Drawing {
+    /// Creates a new empty drawing.
+    pub fn new() -> Self {
+        Drawing::default()
+    }
+
+    /// Removes all commands and shapes of the drawing.
+    pub fn clear(&mut self) {
+        *self = Drawing::default();
+    }
+
+    /// Checks if the drawing has no commands.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    // Shapes
+
+    /// Starts a named shape. The paths that are filled or stroked and the images that are drawn
+    /// until `end_shape` is called are part of the shape.
+    pub fn begin_shape(&mut self, name: impl Into<String>) {
+        self.shape = Some(name.into());
+    }
+
+    /// Ends the current named shape.
+    pub fn end_shape(&mut self) {
+        self.shape = None;
+    }
+
+    /// Returns the name of the topmost shape that contains the given point. The point is
+    /// relative to the origin of the drawing.
+    pub fn hit_test(&self, point: impl Into<Point>) -> Option<&str> {
+        let point = point.into();
+
+        self.shapes
+            .iter()
+            .rev()
+            .find(|shape| shape.area.contains(point))
+            .map(|shape| shape.name.as_str())
+    }
+
+    // Paths
+
+    /// Starts a new path by emptying the list of sub-paths.
+    pub fn begin_path(&mut self) {
+        self.path.clear();
+        self.commands.push(DrawingCommand::BeginPath);
+    }
+
+    /// Begins a new sub-path at the given point.
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.path.push(vec![]);
+        self.start_point = Point::new(x, y);
+        self.add_point(Point::new(x, y));
+        self.commands.push(DrawingCommand::MoveTo(x, y));
+    }
+
+    /// Adds a straight line from the current point to the given point.
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        self.add_point(Point::new(x, y));
+        self.commands.push(DrawingCommand::LineTo(x, y));
+    }
+
+    /// Adds a quadratic Bézier curve to the current sub-path.
+    pub fn quadratic_curve_to(&mut self, cpx: f64, cpy: f64, x: f64, y: f64) {
+        let start = self.start_of_curve(cpx, cpy);
+
+        for index in 1..=CURVE_SEGMENTS {
+            let t = index as f64 / CURVE_SEGMENTS as f64;
+            let (a, b, c) = ((1.0 - t) * (1.0 - t), 2.0 * (1.0 - t) * t, t * t);
+
+            self.add_point(Point::new(
+                a * start.x() + b * cpx + c * x,
+                a * start.y() + b * cpy + c * y,
+            ));
+        }
+
+        self.commands
+            .push(DrawingCommand::QuadraticCurveTo(cpx, cpy, x, y));
+    }
+
+    /// Adds a cubic Bézier curve to the current sub-path.
+    pub fn bezier_curve_to(&mut self, cp1x: f64, cp1y: f64, cp2x: f64, cp2y: f64, x: f64, y: f64) {
+        let start = self.start_of_curve(cp1x, cp1y);
+
+        for index in 1..=CURVE_SEGMENTS {
+            let t = index as f64 / CURVE_SEGMENTS as f64;
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+
+            self.add_point(Point::new(
+                a * start.x() + b * cp1x + c * cp2x + d * x,
+                a * start.y() + b * cp1y + c * cp2y + d * y,
+            ));
+        }
+
+        self.commands
+            .push(DrawingCommand::BezierCurveTo(cp1x, cp1y, cp2x, cp2y, x, y));
+    }
+
+    /// Adds a circular arc centered at (x, y) with the given radius from the start angle to the
+    /// end angle in radians.
+    pub fn arc(&mut self, x: f64, y: f64, radius: f64, start_angle: f64, end_angle: f64) {
+        for index in 0..=CURVE_SEGMENTS {
+            let angle =
+                start_angle + (end_angle - start_angle) * index as f64 / CURVE_SEGMENTS as f64;
+            self.add_point(Point::new(
+                x + radius * angle.cos(),
+                y + radius * angle.sin(),
+            ));
+        }
+
+        self.commands
+            .push(DrawingCommand::Arc(x, y, radius, start_angle, end_angle));
+    }
+
+    /// Adds a rectangle as closed sub-path.
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.path.push(vec![]);
+
+        for point in &[
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+            (x, y),
+        ] {
+            self.add_point(Point::from(*point));
+        }
+
+        self.start_point = Point::new(x, y);
+        self.commands
+            .push(DrawingCommand::Rect(x, y, width, height));
+    }
+
+    /// Adds a straight line from the current point to the start of the current sub-path.
+    pub fn close_path(&mut self) {
+        if let Some(sub_path) = self.path.last_mut() {
+            if let Some(first) = sub_path.first().copied() {
+                sub_path.push(first);
+            }
+        }
+
+        self.current_point = self.start_point;
+        self.commands.push(DrawingCommand::ClosePath);
+    }
+
+    /// Fills the current path with the fill style.
+    pub fn fill(&mut self) {
+        if let Some(name) = self.shape.clone() {
+            self.shapes.push(Shape {
+                name,
+                area: ShapeArea::Fill(self.path.clone()),
+            });
+        }
+
+        self.commands.push(DrawingCommand::Fill);
+    }
+
+    /// Strokes the current path with the stroke style and the line width.
+    pub fn stroke(&mut self) {
+        if let Some(name) = self.shape.clone() {
+            let scale = (self.transform.m11 * self.transform.m22
+                - self.transform.m12 * self.transform.m21)
+                .abs()
+                .sqrt();
+
+            self.shapes.push(Shape {
+                name,
+                area: ShapeArea::Stroke(self.path.clone(), self.line_width * scale / 2.0),
+            });
+        }
+
+        self.commands.push(DrawingCommand::Stroke);
+    }
+
+    /// Creates a clipping path from the current path. Everything that is drawn after it appears
+    /// only inside of the clipping path.
+    pub fn clip(&mut self) {
+        self.commands.push(DrawingCommand::Clip);
+    }
+
+    // Text and images
+
+    /// Draws (fills) the given text at the given position.
+    pub fn fill_text(&mut self, text: impl Into<String>, x: f64, y: f64) {
+        self.commands
+            .push(DrawingCommand::FillText(text.into(), x, y));
+    }
+
+    /// Draws the image at the given position with its natural size.
+    pub fn draw_image(&mut self, image: Image, x: f64, y: f64) {
+        self.add_image_shape(x, y, image.width(), image.height());
+        self.commands.push(DrawingCommand::DrawImage(image, x, y));
+    }
+
+    /// Draws the image at the given position scaled to the given width and height.
+    pub fn draw_image_with_size(&mut self, image: Image, x: f64, y: f64, width: f64, height: f64) {
+        self.add_image_shape(x, y, width, height);
+        self.commands.push(DrawingCommand::DrawImageWithSize(
+            image, x, y, width, height,
+        ));
+    }
+
+    // Styles
+
+    /// Sets the brush that fills shapes, e.g. a color or a gradient.
+    pub fn set_fill_style(&mut self, fill_style: impl Into<Brush>) {
+        self.commands
+            .push(DrawingCommand::SetFillStyle(fill_style.into()));
+    }
+
+    /// Sets the brush that strokes lines.
+    pub fn set_stroke_style(&mut self, stroke_style: impl Into<Brush>) {
+        self.commands
+            .push(DrawingCommand::SetStrokeStyle(stroke_style.into()));
+    }
+
+    /// Sets the thickness of lines.
+    pub fn set_line_width(&mut self, line_width: f64) {
+        self.line_width = line_width;
+        self.commands.push(DrawingCommand::SetLineWidth(line_width));
+    }
+
+    /// Sets the alpha value.
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.commands.push(DrawingCommand::SetAlpha(alpha));
+    }
+
+    /// Specifies the font family.
+    pub fn set_font_family(&mut self, family: impl Into<String>) {
+        self.commands
+            .push(DrawingCommand::SetFontFamily(family.into()));
+    }
+
+    /// Specifies the font size.
+    pub fn set_font_size(&mut self, size: f64) {
+        self.commands.push(DrawingCommand::SetFontSize(size));
+    }
+
+    // Transformations
+
+    /// Multiplies the current transformation with the given matrix.
+    pub fn transform(&mut self, matrix: Matrix) {
+        self.transform = matrix.then(&self.transform);
+        self.commands.push(DrawingCommand::Transform(matrix));
+    }
+
+    /// Moves the origin of the following commands.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.transform(Matrix::translation(x, y));
+    }
+
+    /// Scales the following commands.
+    pub fn scale(&mut self, x: f64, y: f64) {
+        self.transform(Matrix::scaling(x, y));
+    }
+
+    /// Rotates the following commands clockwise by the given angle in degrees.
+    pub fn rotate(&mut self, degrees: f64) {
+        self.transform(Matrix::rotation(degrees));
+    }
+
+    // Canvas states
+
+    /// Saves the styles, the transformation and the clip by pushing them onto a stack.
+    pub fn save(&mut self) {
+        self.saved_states.push((self.transform, self.line_width));
+        self.commands.push(DrawingCommand::Save);
+    }
+
+    /// Restores the most recently saved state.
+    pub fn restore(&mut self) {
+        if let Some((transform, line_width)) = self.saved_states.pop() {
+            self.transform = transform;
+            self.line_width = line_width;
+        }
+
+        self.commands.push(DrawingCommand::Restore);
+    }
+
+    /// Replays the commands on the render context with the origin at the given position.
+    pub(crate) fn render(&mut self, render_context: &mut RenderContext2D, x: f64, y: f64) {
+        render_context.save();
+        render_context.transform(1.0, 0.0, 0.0, 1.0, x, y);
+        render_context.begin_path();
+
+        // states saved by the drawing are restored at the end even if it does not restore them
+        let mut saved_states = 0;
+
+        for command in &mut self.commands {
+            match command {
+                DrawingCommand::BeginPath => render_context.begin_path(),
+                DrawingCommand::MoveTo(x, y) => render_context.move_to(*x, *y),
+                DrawingCommand::LineTo(x, y) => render_context.line_to(*x, *y),
+                DrawingCommand::QuadraticCurveTo(cpx, cpy, x, y) => {
+                    render_context.quadratic_curve_to(*cpx, *cpy, *x, *y)
+                }
+                DrawingCommand::BezierCurveTo(cp1x, cp1y, cp2x, cp2y, x, y) => {
+                    render_context.bezier_curve_to(*cp1x, *cp1y, *cp2x, *cp2y, *x, *y)
+                }
+                DrawingCommand::Arc(x, y, radius, start_angle, end_angle) => {
+                    render_context.arc(*x, *y, *radius, *start_angle, *end_angle)
+                }
+                DrawingCommand::Rect(x, y, width, height) => {
+                    render_context.rect(*x, *y, *width, *height)
+                }
+                DrawingCommand::ClosePath => render_context.close_path(),
+                DrawingCommand::Fill => render_context.fill(),
+                DrawingCommand::Stroke => render_context.stroke(),
+                DrawingCommand::Clip => render_context.clip(),
+                DrawingCommand::FillText(text, x, y) => render_context.fill_text(text, *x, *y),
+                DrawingCommand::DrawImage(image, x, y) => render_context.draw_image(image, *x, *y),
+                DrawingCommand::DrawImageWithSize(image, x, y, width, height) => {
+                    render_context.draw_image_with_size(image, *x, *y, *width, *height)
+                }
+                DrawingCommand::SetFillStyle(brush) => render_context.set_fill_style(brush.clone()),
+                DrawingCommand::SetStrokeStyle(brush) => {
+                    render_context.set_stroke_style(brush.clone())
+                }
+                DrawingCommand::SetLineWidth(line_width) => {
+                    render_context.set_line_width(*line_width)
+                }
+                DrawingCommand::SetAlpha(alpha) => render_context.set_alpha(*alpha),
+                DrawingCommand::SetFontFamily(family) => {
+                    render_context.set_font_family(family.as_str())
+                }
+                DrawingCommand::SetFontSize(size) => render_context.set_font_size(*size),
+                DrawingCommand::Transform(m) => {
+                    render_context.transform(m.m11, m.m12, m.m21, m.m22, m.dx, m.dy)
+                }
+                DrawingCommand::Save => {
+                    saved_states += 1;
+                    render_context.save();
+                }
+                DrawingCommand::Restore => {
+                    if saved_states > 0 {
+                        saved_states -= 1;
+                        render_context.restore();
+                    }
+                }
+            }
+        }
+
+        for _ in 0..saved_states {
+            render_context.restore();
+        }
+
+        render_context.restore();
+    }
+
+    // Adds a point in coordinates of the current transformation to the current sub-path.
+    fn add_point(&mut self, point: Point) {
+        if self.path.is_empty() {
+            self.path.push(vec![]);
+            self.start_point = point;
+        }
+
+        if let Some(sub_path) = self.path.last_mut() {
+            sub_path.push(self.transform.transform_point(point));
+        }
+
+        self.current_point = point;
+    }
+
+    // Returns the start point of a curve, a curve without a current point starts at its first
+    // control point.
+    fn start_of_curve(&mut self, x: f64, y: f64) -> Point {
+        if self.path.is_empty() {
+            self.add_point(Point::new(x, y));
+        }
+
+        self.current_point
+    }
+
+    fn add_image_shape(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        if let Some(name) = self.shape.clone() {
+            let corners = [
+                (x, y),
+                (x + width, y),
+                (x + width, y + height),
+                (x, y + height),
+            ];
+
+            self.shapes.push(Shape {
+                name,
+                area: ShapeArea::Fill(vec![corners
+                    .iter()
+                    .map(|corner| self.transform.transform_point(Point::from(*corner)))
+                    .collect()]),
+            });
+        }
+    }
+}
+
+// Returns the winding number of the polygons around the point.
+fn winding(polygons: &[Vec<Point>], point: Point) -> i32 {
+    let mut winding = 0;
+
+    for polygon in polygons {
+        for (index, start) in polygon.iter().enumerate() {
+            let end = polygon[(index + 1) % polygon.len()];
+            let side = (end.x() - start.x()) * (point.y() - start.y())
+                - (point.x() - start.x()) * (end.y() - start.y());
+
+            if start.y() <= point.y() {
+                if end.y() > point.y() && side > 0.0 {
+                    winding += 1;
+                }
+            } else if end.y() <= point.y() && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    winding
+}
+
+// Returns the distance between the point and the line from start to end.
+fn distance(start: Point, end: Point, point: Point) -> f64 {
+    let (dx, dy) = (end.x() - start.x(), end.y() - start.y());
+    let length = dx * dx + dy * dy;
+
+    let t = if length > 0.0 {
+        (((point.x() - start.x()) * dx + (point.y() - start.y()) * dy) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let (x, y) = (
+        start.x() + t * dx - point.x(),
+        start.y() + t * dy - point.y(),
+    );
+
+    (x * x + y * y).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_test_fill() {
+        let mut drawing = Drawing::new();
+
+        drawing.begin_shape("bar");
+        drawing.begin_path();
+        drawing.rect(10.0, 20.0, 30.0, 80.0);
+        drawing.fill();
+        drawing.end_shape();
+
+        drawing.begin_path();
+        drawing.rect(0.0, 0.0, 100.0, 10.0);
+        drawing.fill();
+
+        drawing.begin_shape("point");
+        drawing.begin_path();
+        drawing.arc(40.0, 20.0, 5.0, 0.0, 2.0 * std::f64::consts::PI);
+        drawing.fill();
+        drawing.end_shape();
+
+        assert_eq!(drawing.hit_test((20.0, 50.0)), Some("bar"));
+        assert_eq!(drawing.hit_test((40.0, 20.0)), Some("point"));
+        assert_eq!(drawing.hit_test((5.0, 5.0)), None);
+        assert_eq!(drawing.hit_test((50.0, 50.0)), None);
+    }
+
+    #[test]
+    fn test_hit_test_stroke() {
+        let mut drawing = Drawing::new();
+
+        drawing.begin_shape("line");
+        drawing.set_line_width(4.0);
+        drawing.begin_path();
+        drawing.move_to(0.0, 0.0);
+        drawing.line_to(100.0, 0.0);
+        drawing.stroke();
+        drawing.end_shape();
+
+        assert_eq!(drawing.hit_test((50.0, 1.5)), Some("line"));
+        assert_eq!(drawing.hit_test((50.0, 3.0)), None);
+    }
+
+    #[test]
+    fn test_hit_test_transform() {
+        let mut drawing = Drawing::new();
+
+        drawing.save();
+        drawing.translate(100.0, 0.0);
+        drawing.scale(2.0, 2.0);
+        drawing.begin_shape("bar");
+        drawing.begin_path();
+        drawing.rect(0.0, 0.0, 10.0, 10.0);
+        drawing.fill();
+        drawing.end_shape();
+        drawing.restore();
+
+        assert_eq!(drawing.hit_test((115.0, 15.0)), Some("bar"));
+        assert_eq!(drawing.hit_test((5.0, 5.0)), None);
+
+        drawing.clear();
+        assert!(drawing.is_empty());
+        assert_eq!(drawing.hit_test((115.0, 15.0)), None);
+    }
+}
@@ -0,0 +1,86 @@
+/// Describes the buttons of a `MessageBox`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MessageBoxButtons {
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+impl Default for MessageBoxButtons {
+    fn default() -> Self {
+        MessageBoxButtons::Ok
+    }
+}
+
+impl From<&str> for MessageBoxButtons {
+    fn from(s: &str) -> Self {
+        match s {
+            "OkCancel" | "ok_cancel" => MessageBoxButtons::OkCancel,
+            "YesNo" | "yes_no" => MessageBoxButtons::YesNo,
+            "YesNoCancel" | "yes_no_cancel" => MessageBoxButtons::YesNoCancel,
+            _ => MessageBoxButtons::Ok,
+        }
+    }
+}
+
+/// Describes the kind of message of a `MessageBox`. It defines the displayed icon.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MessageBoxKind {
+    Info,
+    Warning,
+    Error,
+    Confirm,
+}
+
+impl Default for MessageBoxKind {
+    fn default() -> Self {
+        MessageBoxKind::Info
+    }
+}
+
+impl From<&str> for MessageBoxKind {
+    fn from(s: &str) -> Self {
+        match s {
+            "Warning" | "warning" => MessageBoxKind::Warning,
+            "Error" | "error" => MessageBoxKind::Error,
+            "Confirm" | "confirm" => MessageBoxKind::Confirm,
+            _ => MessageBoxKind::Info,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buttons_into() {
+        let buttons: MessageBoxButtons = "ok_cancel".into();
+        assert_eq!(buttons, MessageBoxButtons::OkCancel);
+
+        let buttons: MessageBoxButtons = "YesNo".into();
+        assert_eq!(buttons, MessageBoxButtons::YesNo);
+
+        let buttons: MessageBoxButtons = "yes_no_cancel".into();
+        assert_eq!(buttons, MessageBoxButtons::YesNoCancel);
+
+        let buttons: MessageBoxButtons = "other".into();
+        assert_eq!(buttons, MessageBoxButtons::Ok);
+    }
+
+    #[test]
+    fn test_kind_into() {
+        let kind: MessageBoxKind = "warning".into();
+        assert_eq!(kind, MessageBoxKind::Warning);
+
+        let kind: MessageBoxKind = "Error".into();
+        assert_eq!(kind, MessageBoxKind::Error);
+
+        let kind: MessageBoxKind = "confirm".into();
+        assert_eq!(kind, MessageBoxKind::Confirm);
+
+        let kind: MessageBoxKind = "other".into();
+        assert_eq!(kind, MessageBoxKind::Info);
+    }
+}
@@ -1,14 +1,24 @@
 // Widget related properties.
+pub use self::drawing::*;
 pub use self::focus_state::*;
 pub use self::keyboard_state::*;
+pub use self::message_box::*;
 pub use self::render_pipeline::*;
 pub use self::selected_entities::*;
 pub use self::selected_indices::*;
+pub use self::selected_paths::*;
+pub use self::shortcut::*;
 pub use self::text_selection::*;
+pub use self::tree_path::*;
 
+mod drawing;
 mod focus_state;
 mod keyboard_state;
+mod message_box;
 mod render_pipeline;
 mod selected_entities;
 mod selected_indices;
+mod selected_paths;
+mod shortcut;
 mod text_selection;
+mod tree_path;
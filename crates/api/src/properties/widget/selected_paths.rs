@@ -0,0 +1,13 @@
+use std::collections::HashSet;
+
+use super::TreePath;
+
+/// `SelectedPaths` describes a list of selected tree paths.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SelectedPaths(pub HashSet<TreePath>);
+
+impl From<HashSet<TreePath>> for SelectedPaths {
+    fn from(p: HashSet<TreePath>) -> Self {
+        SelectedPaths(p)
+    }
+}
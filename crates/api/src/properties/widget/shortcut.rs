@@ -0,0 +1,266 @@
+use std::fmt;
+
+use crate::shell::Key;
+
+use super::KeyboardState;
+
+/// `Shortcut` describes a key combination like `Ctrl+S` that could be used as keyboard accelerator.
+///
+/// # Example
+///
+/// ```rust
+/// let shortcut = Shortcut::from("Ctrl+Shift+S");
+/// assert_eq!(shortcut, Shortcut::new(Key::S(false)).ctrl().shift());
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The key of the shortcut. The shortcut is empty if it is `None`.
+    pub key: Option<Key>,
+
+    /// The control key (home on macos) has to be pressed.
+    pub ctrl: bool,
+
+    /// One of the shift keys has to be pressed.
+    pub shift: bool,
+
+    /// The alt key has to be pressed.
+    pub alt: bool,
+}
+
+impl Shortcut {
+    /// Creates a new shortcut without modifiers.
+    pub fn new(key: Key) -> Self {
+        Shortcut {
+            key: Some(key),
+            ..Default::default()
+        }
+    }
+
+    /// Adds the control modifier.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Adds the shift modifier.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Adds the alt modifier.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Returns `true` if the shortcut has no key.
+    pub fn is_empty(&self) -> bool {
+        self.key.is_none()
+    }
+
+    /// Checks if the given key and modifiers match the shortcut. Letters are compared case insensitive.
+    pub fn matches_modifiers(&self, key: Key, ctrl: bool, shift: bool, alt: bool) -> bool {
+        let shortcut_key = match self.key {
+            Some(shortcut_key) => shortcut_key,
+            None => return false,
+        };
+
+        if self.ctrl != ctrl || self.shift != shift || self.alt != alt {
+            return false;
+        }
+
+        let shortcut_text: &str = shortcut_key.into();
+        let text: &str = key.into();
+
+        if shortcut_text.is_empty() || text.is_empty() {
+            return shortcut_key == key;
+        }
+
+        shortcut_text.to_lowercase() == text.to_lowercase()
+    }
+
+    /// Checks if the given key matches the shortcut while the modifiers of the keyboard state are pressed.
+    pub fn matches(&self, key: Key, keyboard_state: &KeyboardState) -> bool {
+        let ctrl = if cfg!(target_os = "macos") {
+            keyboard_state.is_home_down()
+        } else {
+            keyboard_state.is_ctrl_down()
+        };
+
+        self.matches_modifiers(
+            key,
+            ctrl,
+            keyboard_state.is_shift_down(),
+            keyboard_state.is_alt_down(),
+        )
+    }
+}
+
+// Converts the name of a key e.g. `S`, `Enter` or `Esc` into a key. Letters are converted to lower case keys.
+fn key_from_name(name: &str) -> Option<Key> {
+    let key = match name.to_lowercase().as_str() {
+        "backspace" => Key::Backspace,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "delete" | "del" => Key::Delete,
+        "space" => Key::Space,
+        "enter" | "return" => Key::Enter,
+        "escape" | "esc" => Key::Escape,
+        "home" => Key::Home,
+        "plus" => Key::NumpadAdd,
+        "minus" => Key::NumpadSubtract,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        name => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Key::from(c),
+                _ => Key::Unknown,
+            }
+        }
+    };
+
+    if key == Key::Unknown {
+        return None;
+    }
+
+    Some(key)
+}
+
+impl From<&str> for Shortcut {
+    fn from(s: &str) -> Self {
+        let mut shortcut = Shortcut::default();
+
+        for part in s.split('+').map(|p| p.trim()) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" => shortcut.ctrl = true,
+                "shift" => shortcut.shift = true,
+                "alt" => shortcut.alt = true,
+                _ => shortcut.key = key_from_name(part),
+            }
+        }
+
+        shortcut
+    }
+}
+
+impl From<Key> for Shortcut {
+    fn from(key: Key) -> Self {
+        Shortcut::new(key)
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match self.key {
+            Some(key) => key,
+            None => return Ok(()),
+        };
+
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+
+        let text: &str = key.into();
+
+        match key {
+            Key::Backspace => write!(f, "Backspace"),
+            Key::Up => write!(f, "Up"),
+            Key::Down => write!(f, "Down"),
+            Key::Left => write!(f, "Left"),
+            Key::Right => write!(f, "Right"),
+            Key::Delete => write!(f, "Del"),
+            Key::Space => write!(f, "Space"),
+            Key::Enter => write!(f, "Enter"),
+            Key::Escape => write!(f, "Esc"),
+            Key::Home => write!(f, "Home"),
+            Key::NumpadAdd => write!(f, "Plus"),
+            Key::NumpadSubtract => write!(f, "Minus"),
+            Key::F1 => write!(f, "F1"),
+            Key::F2 => write!(f, "F2"),
+            Key::F3 => write!(f, "F3"),
+            Key::F4 => write!(f, "F4"),
+            Key::F5 => write!(f, "F5"),
+            Key::F6 => write!(f, "F6"),
+            Key::F7 => write!(f, "F7"),
+            Key::F8 => write!(f, "F8"),
+            Key::F9 => write!(f, "F9"),
+            Key::F10 => write!(f, "F10"),
+            Key::F11 => write!(f, "F11"),
+            Key::F12 => write!(f, "F12"),
+            _ => write!(f, "{}", text.to_uppercase()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Shortcut::from("Ctrl+S"),
+            Shortcut::new(Key::S(false)).ctrl()
+        );
+        assert_eq!(
+            Shortcut::from("ctrl + shift + n"),
+            Shortcut::new(Key::N(false)).ctrl().shift()
+        );
+        assert_eq!(Shortcut::from("Alt+Enter"), Shortcut::new(Key::Enter).alt());
+        assert_eq!(Shortcut::from("Esc"), Shortcut::new(Key::Escape));
+        assert_eq!(Shortcut::from("F5"), Shortcut::new(Key::F5));
+        assert_eq!(
+            Shortcut::from("Ctrl+Shift+F12"),
+            Shortcut::new(Key::F12).ctrl().shift()
+        );
+        assert!(Shortcut::from("Ctrl+Unknown").is_empty());
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(Shortcut::from("ctrl+s").to_string(), "Ctrl+S");
+        assert_eq!(Shortcut::from("Shift+Alt+Del").to_string(), "Shift+Alt+Del");
+        assert_eq!(Shortcut::from("Ctrl+Plus").to_string(), "Ctrl+Plus");
+        assert_eq!(Shortcut::from("f5").to_string(), "F5");
+        assert_eq!(Shortcut::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_matches_modifiers() {
+        let shortcut = Shortcut::from("Ctrl+S");
+
+        assert!(shortcut.matches_modifiers(Key::S(false), true, false, false));
+        assert!(shortcut.matches_modifiers(Key::S(true), true, false, false));
+        assert!(!shortcut.matches_modifiers(Key::S(false), false, false, false));
+        assert!(!shortcut.matches_modifiers(Key::S(false), true, true, false));
+        assert!(!shortcut.matches_modifiers(Key::A(false), true, false, false));
+
+        let shortcut = Shortcut::from("Alt+Enter");
+        assert!(shortcut.matches_modifiers(Key::Enter, false, false, true));
+        assert!(!shortcut.matches_modifiers(Key::Escape, false, false, true));
+
+        assert!(!Shortcut::default().matches_modifiers(Key::Unknown, false, false, false));
+    }
+}
@@ -0,0 +1,82 @@
+/// `TreePath` describes the position of an item inside of a hierarchy e.g. a `TreeView`. Each
+/// entry is the index of the item inside of its parent. The empty path describes the root.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct TreePath(pub Vec<usize>);
+
+impl TreePath {
+    /// Gets the depth of the path. Top level items have a depth of `0`.
+    pub fn depth(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    /// Returns the path of the parent item or `None` if the path describes the root.
+    pub fn parent(&self) -> Option<TreePath> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        Some(TreePath(self.0[..self.0.len() - 1].to_vec()))
+    }
+
+    /// Returns the path of the child with the given index.
+    pub fn child(&self, index: usize) -> TreePath {
+        let mut path = self.0.clone();
+        path.push(index);
+        TreePath(path)
+    }
+
+    /// Returns `true` if the path is a (indirect) parent of the given path.
+    pub fn is_ancestor_of(&self, other: &TreePath) -> bool {
+        other.0.len() > self.0.len() && other.0.starts_with(&self.0)
+    }
+
+    /// Gets the indices of the path as slice.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+impl From<Vec<usize>> for TreePath {
+    fn from(p: Vec<usize>) -> Self {
+        TreePath(p)
+    }
+}
+
+impl From<&[usize]> for TreePath {
+    fn from(p: &[usize]) -> Self {
+        TreePath(p.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depth() {
+        assert_eq!(TreePath::default().depth(), 0);
+        assert_eq!(TreePath(vec![2]).depth(), 0);
+        assert_eq!(TreePath(vec![2, 0, 1]).depth(), 2);
+    }
+
+    #[test]
+    fn test_parent() {
+        assert_eq!(TreePath::default().parent(), None);
+        assert_eq!(TreePath(vec![2]).parent(), Some(TreePath::default()));
+        assert_eq!(TreePath(vec![2, 1]).parent(), Some(TreePath(vec![2])));
+    }
+
+    #[test]
+    fn test_child() {
+        assert_eq!(TreePath::default().child(1), TreePath(vec![1]));
+        assert_eq!(TreePath(vec![2]).child(0), TreePath(vec![2, 0]));
+    }
+
+    #[test]
+    fn test_is_ancestor_of() {
+        assert!(TreePath::default().is_ancestor_of(&TreePath(vec![0])));
+        assert!(TreePath(vec![1]).is_ancestor_of(&TreePath(vec![1, 3, 2])));
+        assert!(!TreePath(vec![1]).is_ancestor_of(&TreePath(vec![1])));
+        assert!(!TreePath(vec![1]).is_ancestor_of(&TreePath(vec![2, 1])));
+    }
+}
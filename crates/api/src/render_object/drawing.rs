@@ -0,0 +1,24 @@
+use crate::{proc_macros::IntoRenderObject, render_object::*};
+
+/// Used to render the `drawing` of a widget. The commands of the drawing are replayed with the
+/// origin at the position of the widget.
+#[derive(Debug, IntoRenderObject)]
+pub struct DrawingRenderObject;
+
+impl RenderObject for DrawingRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, mut drawing) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.clone::<Drawing>("drawing"),
+            )
+        };
+
+        drawing.render(
+            ctx.render_context_2_d(),
+            global_position.x() + bounds.x(),
+            global_position.y() + bounds.y(),
+        );
+    }
+}
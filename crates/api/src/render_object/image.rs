@@ -1,25 +1,253 @@
-use crate::{proc_macros::IntoRenderObject, render::Image, render_object::*};
+use std::cell::RefCell;
 
-/// Used to render an image.
-#[derive(Debug, IntoRenderObject)]
-pub struct ImageRenderObject;
+use crate::{
+    proc_macros::IntoRenderObject,
+    render::Image,
+    render_object::*,
+    utils::{Point, Rectangle, Size, Stretch, Thickness},
+};
+
+/// Used to render an image. The image is resized as described by the `stretch` of the widget or
+/// drawn as nine-slice image if `nine_slice` is set. Images that are drawn smaller than their
+/// natural size are downsampled before they are drawn.
+#[derive(Debug, Default, IntoRenderObject)]
+pub struct ImageRenderObject {
+    // downsampled image, resized again if the image, the size or the scale factor changes
+    cache: RefCell<Option<(Image, Image)>>,
+}
+
+impl ImageRenderObject {
+    pub fn new() -> Self {
+        ImageRenderObject::default()
+    }
+}
 
 impl RenderObject for ImageRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, mut image) = {
+        let (bounds, image, stretch, nine_slice) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.try_clone::<Image>("image"),
+                widget.clone_or_default::<Stretch>("stretch"),
+                widget.clone_or_default::<Thickness>("nine_slice"),
             )
         };
 
-        if let Some(image) = &mut image {
-            ctx.render_context_2_d().draw_image(
-                image,
-                bounds.x() + global_position.x(),
-                bounds.y() + global_position.y(),
+        let mut image = match image {
+            Some(image) if image.width() > 0.0 && image.height() > 0.0 => image,
+            _ => return,
+        };
+
+        let x = bounds.x() + global_position.x();
+        let y = bounds.y() + global_position.y();
+
+        if nine_slice != Thickness::default() {
+            for (clip, target) in nine_slice_parts(
+                Size::new(image.width(), image.height()),
+                nine_slice,
+                Rectangle::new((x, y), bounds.size()),
+            ) {
+                ctx.render_context_2_d().draw_image_with_clip_and_size(
+                    &mut image,
+                    clip,
+                    target.x(),
+                    target.y(),
+                    target.width(),
+                    target.height(),
+                );
+            }
+
+            return;
+        }
+
+        if stretch == Stretch::None {
+            ctx.render_context_2_d().draw_image(&mut image, x, y);
+            return;
+        }
+
+        let target = stretch.apply(Size::new(image.width(), image.height()), bounds.size());
+        let scale_factor = ctx.render_context_2_d().scale_factor();
+        let width = (target.width() * scale_factor).round() as u32;
+        let height = (target.height() * scale_factor).round() as u32;
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        // uniform to fill could be larger than the widget
+        let clip = stretch == Stretch::UniformToFill;
+
+        if clip {
+            ctx.render_context_2_d().save();
+            ctx.render_context_2_d()
+                .rect(x, y, bounds.width(), bounds.height());
+            ctx.render_context_2_d().clip();
+        }
+
+        if (width as f64) < image.width() || (height as f64) < image.height() {
+            let mut cache = self.cache.borrow_mut();
+
+            let outdated = match &*cache {
+                Some((source, resized)) => {
+                    *source != image
+                        || resized.width() as u32 != width
+                        || resized.height() as u32 != height
+                }
+                None => true,
+            };
+
+            if outdated {
+                let resized = image.resized(width, height);
+                *cache = Some((image, resized));
+            }
+
+            if let Some((_, resized)) = &mut *cache {
+                ctx.render_context_2_d().draw_image_with_size(
+                    resized,
+                    x + target.x(),
+                    y + target.y(),
+                    target.width(),
+                    target.height(),
+                );
+            }
+        } else {
+            ctx.render_context_2_d().draw_image_with_size(
+                &mut image,
+                x + target.x(),
+                y + target.y(),
+                target.width(),
+                target.height(),
             );
         }
+
+        if clip {
+            ctx.render_context_2_d().restore();
+        }
+    }
+}
+
+// Splits an image by the given insets into nine parts and returns each part together with the
+// rectangle it is drawn into. The corners keep their size, the edges and the center are
+// stretched. Corners that do not fit into the target are scaled down.
+fn nine_slice_parts(
+    image: Size,
+    nine_slice: Thickness,
+    target: Rectangle,
+) -> Vec<(Rectangle, Rectangle)> {
+    let columns = slices(
+        image.width(),
+        nine_slice.left(),
+        nine_slice.right(),
+        target.x(),
+        target.width(),
+    );
+    let rows = slices(
+        image.height(),
+        nine_slice.top(),
+        nine_slice.bottom(),
+        target.y(),
+        target.height(),
+    );
+
+    let mut parts = vec![];
+
+    for &(source_y, source_height, y, height) in &rows {
+        for &(source_x, source_width, x, width) in &columns {
+            if source_width > 0.0 && source_height > 0.0 && width > 0.0 && height > 0.0 {
+                parts.push((
+                    Rectangle::new((source_x, source_y), (source_width, source_height)),
+                    Rectangle::new((x, y), (width, height)),
+                ));
+            }
+        }
+    }
+
+    parts
+}
+
+// Returns the start and the length of the three slices of one dimension, first in the image
+// and then in the target.
+fn slices(
+    length: f64,
+    start_inset: f64,
+    end_inset: f64,
+    target_start: f64,
+    target_length: f64,
+) -> [(f64, f64, f64, f64); 3] {
+    let start_inset = start_inset.max(0.0).min(length);
+    let end_inset = end_inset.max(0.0).min(length - start_inset);
+
+    let scale = if start_inset + end_inset > target_length {
+        target_length.max(0.0) / (start_inset + end_inset)
+    } else {
+        1.0
+    };
+
+    let start = start_inset * scale;
+    let end = end_inset * scale;
+
+    [
+        (0.0, start_inset, target_start, start),
+        (
+            start_inset,
+            length - start_inset - end_inset,
+            target_start + start,
+            target_length - start - end,
+        ),
+        (
+            length - end_inset,
+            end_inset,
+            target_start + target_length - end,
+            end,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nine_slice_parts() {
+        let parts = nine_slice_parts(
+            Size::new(30.0, 30.0),
+            Thickness::new(10.0, 10.0, 10.0, 10.0),
+            Rectangle::new((5.0, 5.0), (50.0, 40.0)),
+        );
+
+        assert_eq!(parts.len(), 9);
+        assert_eq!(
+            parts[0],
+            (
+                Rectangle::new((0.0, 0.0), (10.0, 10.0)),
+                Rectangle::new((5.0, 5.0), (10.0, 10.0))
+            )
+        );
+        assert_eq!(
+            parts[4],
+            (
+                Rectangle::new((10.0, 10.0), (10.0, 10.0)),
+                Rectangle::new((15.0, 15.0), (30.0, 20.0))
+            )
+        );
+        assert_eq!(
+            parts[8],
+            (
+                Rectangle::new((20.0, 20.0), (10.0, 10.0)),
+                Rectangle::new((45.0, 35.0), (10.0, 10.0))
+            )
+        );
+
+        // corners are scaled down if they do not fit, the center is not drawn
+        let parts = nine_slice_parts(
+            Size::new(30.0, 30.0),
+            Thickness::new(10.0, 10.0, 10.0, 10.0),
+            Rectangle::new((0.0, 0.0), (10.0, 40.0)),
+        );
+
+        assert_eq!(parts.len(), 6);
+        assert_eq!(parts[0].1, Rectangle::new((0.0, 0.0), (5.0, 10.0)));
+        assert_eq!(parts[1].1, Rectangle::new((5.0, 0.0), (5.0, 10.0)));
     }
 }
@@ -11,18 +11,22 @@ use crate::{
 
 pub use self::cursor::*;
 pub use self::default::*;
+pub use self::drawing::*;
 pub use self::font_icon::*;
 pub use self::image::*;
 pub use self::pipeline::*;
 pub use self::rectangle::*;
+pub use self::svg_icon::*;
 pub use self::text::*;
 
 mod cursor;
 mod default;
+mod drawing;
 mod font_icon;
 mod image;
 mod pipeline;
 mod rectangle;
+mod svg_icon;
 mod text;
 
 pub trait RenderObject: Any {
@@ -55,12 +59,86 @@ pub trait RenderObject: Any {
             return;
         }
 
+        let parent = ecm.entity_store().parent[&entity];
+        let opacity = opacity_of(ecm, entity);
+        let inherited = parent.map_or(1.0, |parent| inherited_opacity(ecm, parent));
+
+        // The widget and its children are drawn on a layer that is blended with the opacity as a whole.
+        // Widgets that share the opacity of the nearest layered ancestor are already part of its layer.
+        let layer = is_layered(ecm, entity, opacity, inherited);
+
         render_context.begin_path();
-        render_context.set_alpha(
-            *ecm.component_store()
-                .get::<f32>("opacity", entity)
-                .unwrap_or(&1.0),
-        );
+
+        if !layer && opacity < 1.0 && (opacity - inherited).abs() > f32::EPSILON {
+            render_context.set_alpha(opacity);
+        } else {
+            render_context.set_alpha(1.0);
+        }
+
+        let parent_transform = parent
+            .and_then(|parent| {
+                ecm.component_store()
+                    .get::<Matrix>("global_transform", parent)
+                    .ok()
+            })
+            .copied()
+            .unwrap_or_default();
+        let mut global_transform = parent_transform;
+
+        let mut transformed = false;
+
+        if let Ok(render_transform) = ecm
+            .component_store()
+            .get::<RenderTransform>("render_transform", entity)
+        {
+            if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
+                let matrix = render_transform.to_matrix(Rectangle::new(
+                    (
+                        global_position.x() + bounds.x(),
+                        global_position.y() + bounds.y(),
+                    ),
+                    (bounds.width(), bounds.height()),
+                ));
+                global_transform = matrix.then(&parent_transform);
+                transformed = true;
+
+                render_context.save();
+                render_context.transform(
+                    matrix.m11, matrix.m12, matrix.m21, matrix.m22, matrix.dx, matrix.dy,
+                );
+            }
+        }
+
+        // the layer is sized to the bounds of the widget and follows its transform
+        if layer {
+            let bounds = ecm
+                .component_store()
+                .get::<Rectangle>("bounds", entity)
+                .ok()
+                .copied()
+                .unwrap_or_default();
+            render_context.push_layer(
+                opacity,
+                Rectangle::new(
+                    (
+                        global_position.x() + bounds.x(),
+                        global_position.y() + bounds.y(),
+                    ),
+                    (bounds.width(), bounds.height()),
+                ),
+            );
+        }
+
+        // the global transform is used by the hit tests of the event state system
+        if let Ok(transform) = ecm
+            .component_store_mut()
+            .get_mut::<Matrix>("global_transform", entity)
+        {
+            *transform = global_transform;
+        } else if !global_transform.is_identity() {
+            ecm.component_store_mut()
+                .register("global_transform", entity, global_transform);
+        }
 
         // Could be unwrap because every widget has the clip property
         let clip = *ecm.component_store().get::<bool>("clip", entity).unwrap();
@@ -116,6 +194,14 @@ pub trait RenderObject: Any {
             render_context.restore();
         }
 
+        if layer {
+            render_context.pop_layer();
+        }
+
+        if transformed {
+            render_context.restore();
+        }
+
         // render debug border for each widget
         if debug {
             if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", entity) {
@@ -161,3 +247,92 @@ pub trait RenderObject: Any {
         }
     }
 }
+
+// Returns the opacity of the widget, widgets without the property are opaque.
+fn opacity_of(ecm: &EntityComponentManager<Tree, StringComponentStore>, entity: Entity) -> f32 {
+    *ecm.component_store()
+        .get::<f32>("opacity", entity)
+        .unwrap_or(&1.0)
+}
+
+// Checks if the widget and its children are drawn on a layer with the opacity of the widget.
+fn is_layered(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+    opacity: f32,
+    inherited_opacity: f32,
+) -> bool {
+    opacity < 1.0
+        && (opacity - inherited_opacity).abs() > f32::EPSILON
+        && !ecm.entity_store().children[&entity].is_empty()
+}
+
+// Returns the opacity of the nearest layered widget on the path from the given widget to the
+// root. The descendants of the widget are already blended with it.
+fn inherited_opacity(
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    entity: Entity,
+) -> f32 {
+    let mut path = vec![entity];
+
+    while let Some(parent) = ecm.entity_store().parent[&path[path.len() - 1]] {
+        path.push(parent);
+    }
+
+    path.iter().rev().fold(1.0, |inherited, widget| {
+        let opacity = opacity_of(ecm, *widget);
+
+        if is_layered(ecm, *widget, opacity, inherited) {
+            opacity
+        } else {
+            inherited
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inherited_opacity() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        // a disabled button with a container that keeps its own opacity and a shared text
+        let window = ecm.create_entity().build();
+        let button = ecm.create_entity().build();
+        let container = ecm.create_entity().build();
+        let text = ecm.create_entity().build();
+        let icon = ecm.create_entity().build();
+        ecm.entity_store_mut().set_root(window);
+        ecm.entity_store_mut().append_child(window, button).unwrap();
+        ecm.entity_store_mut()
+            .append_child(button, container)
+            .unwrap();
+        ecm.entity_store_mut()
+            .append_child(container, text)
+            .unwrap();
+        ecm.entity_store_mut()
+            .append_child(container, icon)
+            .unwrap();
+        ecm.component_store_mut()
+            .register("opacity", button, 0.5_f32);
+        ecm.component_store_mut()
+            .register("opacity", container, 1.0_f32);
+        ecm.component_store_mut().register("opacity", text, 0.5_f32);
+        ecm.component_store_mut()
+            .register("opacity", icon, 0.25_f32);
+
+        // the button is drawn on a layer, its text is part of it
+        assert!(is_layered(ecm, button, 0.5, 1.0));
+        assert!((inherited_opacity(ecm, button) - 0.5).abs() < f32::EPSILON);
+        assert!((inherited_opacity(ecm, container) - 0.5).abs() < f32::EPSILON);
+        assert!((opacity_of(ecm, text) - inherited_opacity(ecm, container)).abs() < f32::EPSILON);
+
+        // a different opacity is applied inside of the layer
+        assert!((opacity_of(ecm, icon) - inherited_opacity(ecm, container)).abs() > f32::EPSILON);
+        assert!((inherited_opacity(ecm, window) - 1.0).abs() < f32::EPSILON);
+    }
+}
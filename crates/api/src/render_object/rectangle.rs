@@ -5,7 +5,7 @@ use crate::{
     render::RenderContext2D,
     render_object::*,
     utils,
-    utils::{Brush, Point, Rectangle, Thickness},
+    utils::{BoxShadow, Brush, CornerRadius, Point, Rectangle, Thickness},
 };
 
 #[derive(Debug, IntoRenderObject)]
@@ -35,7 +35,7 @@ impl RectangleRenderObject {
         }
     }
 
-    // Builds rectangle path with radius and without border.
+    // Builds rectangle path with a radius for each corner and without border.
     fn render_rounded_rect_path(
         &self,
         render_context_2_d: &mut RenderContext2D,
@@ -43,19 +43,19 @@ impl RectangleRenderObject {
         y: f64,
         width: f64,
         height: f64,
-        radius: f64,
+        radius: CornerRadius,
     ) {
         let r = x + width;
         let b = y + height;
-        render_context_2_d.move_to(x + radius, y);
-        render_context_2_d.line_to(r - radius, y);
-        render_context_2_d.quadratic_curve_to(r, y, r, y + radius);
-        render_context_2_d.line_to(r, y + height - radius);
-        render_context_2_d.quadratic_curve_to(r, b, r - radius, b);
-        render_context_2_d.line_to(x + radius, b);
-        render_context_2_d.quadratic_curve_to(x, b, x, b - radius);
-        render_context_2_d.line_to(x, y + radius);
-        render_context_2_d.quadratic_curve_to(x, y, x + radius, y);
+        render_context_2_d.move_to(x + radius.top_left(), y);
+        render_context_2_d.line_to(r - radius.top_right(), y);
+        render_context_2_d.quadratic_curve_to(r, y, r, y + radius.top_right());
+        render_context_2_d.line_to(r, b - radius.bottom_right());
+        render_context_2_d.quadratic_curve_to(r, b, r - radius.bottom_right(), b);
+        render_context_2_d.line_to(x + radius.bottom_left(), b);
+        render_context_2_d.quadratic_curve_to(x, b, x, b - radius.bottom_left());
+        render_context_2_d.line_to(x, y + radius.top_left());
+        render_context_2_d.quadratic_curve_to(x, y, x + radius.top_left(), y);
         render_context_2_d.close_path();
     }
 
@@ -103,7 +103,7 @@ impl RectangleRenderObject {
         &self,
         render_context_2_d: &mut RenderContext2D,
         rect: Rectangle,
-        radius: f64,
+        radius: CornerRadius,
         brush: utils::Brush,
         border_brush: utils::Brush,
         border_thickness: Thickness,
@@ -128,16 +128,15 @@ impl RectangleRenderObject {
             render_context_2_d.stroke();
         }
     }
-}
 
-impl RenderObject for RectangleRenderObject {
-    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+    // Renders the background and the border of the box.
+    fn render_box(&self, ctx: &mut Context, global_position: &Point) {
         let (bounds, background, border_radius, border_thickness, border_brush) = {
             let widget = ctx.widget();
             (
                 widget.clone::<Rectangle>("bounds"),
                 widget.get::<Brush>("background").clone(),
-                widget.clone_or_default::<f64>("border_radius"),
+                widget.clone_or_default::<CornerRadius>("border_radius"),
                 widget.clone_or_default::<Thickness>("border_width"),
                 widget.clone_or_default::<Brush>("border_brush"),
             )
@@ -161,9 +160,14 @@ impl RenderObject for RectangleRenderObject {
 
         ctx.render_context_2_d().begin_path();
 
-        if (bounds.width() - bounds.height()).abs() < f64::EPSILON
-            && border_radius >= bounds.width() / 2.0
-        {
+        let is_circle = (bounds.width() - bounds.height()).abs() < f64::EPSILON
+            && border_radius.is_uniform()
+            && border_radius.top_left() >= bounds.width() / 2.0;
+
+        // neighboring corners could not overlap
+        let border_radius = border_radius.fit(bounds.size());
+
+        if is_circle {
             if !has_thickness {
                 self.render_circle(
                     ctx.render_context_2_d(),
@@ -171,7 +175,7 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y() + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left(),
                 );
                 ctx.render_context_2_d().set_fill_style(background);
                 ctx.render_context_2_d().fill();
@@ -182,13 +186,13 @@ impl RenderObject for RectangleRenderObject {
                     global_position.y() + bounds.y(),
                     bounds.width(),
                     bounds.height(),
-                    border_radius,
+                    border_radius.top_left(),
                     background,
                     border_brush,
                     border_thickness,
                 );
             }
-        } else if border_radius > 0. && has_thickness {
+        } else if !border_radius.is_zero() && has_thickness {
             self.render_rounded_bordered_rect_path(
                 ctx.render_context_2_d(),
                 Rectangle::new(*global_position + bounds.position(), bounds.size()),
@@ -197,7 +201,7 @@ impl RenderObject for RectangleRenderObject {
                 border_brush,
                 border_thickness,
             );
-        } else if border_radius > 0. {
+        } else if !border_radius.is_zero() {
             self.render_rounded_rect_path(
                 ctx.render_context_2_d(),
                 global_position.x() + bounds.x(),
@@ -228,3 +232,31 @@ impl RenderObject for RectangleRenderObject {
         }
     }
 }
+
+impl RenderObject for RectangleRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, border_radius, box_shadow) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.clone_or_default::<CornerRadius>("border_radius"),
+                widget.clone_or_default::<BoxShadow>("box_shadow"),
+            )
+        };
+
+        let rect = Rectangle::new(*global_position + bounds.position(), bounds.size());
+
+        // outer shadows are drawn below and inset shadows above the background
+        if box_shadow.is_visible() && !box_shadow.inset() {
+            ctx.render_context_2_d()
+                .draw_box_shadow(rect, border_radius, &box_shadow);
+        }
+
+        self.render_box(ctx, global_position);
+
+        if box_shadow.is_visible() && box_shadow.inset() {
+            ctx.render_context_2_d()
+                .draw_box_shadow(rect, border_radius, &box_shadow);
+        }
+    }
+}
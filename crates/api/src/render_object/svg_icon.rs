@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+
+use crate::{
+    proc_macros::IntoRenderObject,
+    render::Image,
+    render_object::*,
+    utils::{Brush, Point, Rectangle},
+};
+
+/// Used to render a svg icon. The svg image is rasterized with the size of the widget in physical
+/// pixels and tinted with a solid `icon_brush`. A transparent or gradient `icon_brush` keeps the colors of the image.
+#[derive(Debug, Default, IntoRenderObject)]
+pub struct SvgIconRenderObject {
+    // rasterized icon, rendered again if the image, the brush, the size or the scale factor changes
+    cache: RefCell<Option<(Image, Brush, Image)>>,
+}
+
+impl SvgIconRenderObject {
+    pub fn new() -> Self {
+        SvgIconRenderObject::default()
+    }
+}
+
+impl RenderObject for SvgIconRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        let (bounds, svg, icon_brush) = {
+            let widget = ctx.widget();
+            (
+                widget.clone::<Rectangle>("bounds"),
+                widget.try_clone::<Image>("svg"),
+                widget.clone_or_default::<Brush>("icon_brush"),
+            )
+        };
+
+        let svg = match svg {
+            Some(svg) if svg.is_svg() => svg,
+            _ => return,
+        };
+
+        let scale_factor = ctx.render_context_2_d().scale_factor();
+        let width = (bounds.width() * scale_factor).round() as u32;
+        let height = (bounds.height() * scale_factor).round() as u32;
+
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let mut cache = self.cache.borrow_mut();
+
+        let outdated = match &*cache {
+            Some((source, brush, icon)) => {
+                *source != svg
+                    || *brush != icon_brush
+                    || icon.width() as u32 != width
+                    || icon.height() as u32 != height
+            }
+            None => true,
+        };
+
+        if outdated {
+            let mut icon = svg.clone();
+
+            if icon.rasterize(width, height).is_err() {
+                *cache = None;
+                return;
+            }
+
+            if let Brush::SolidColor(color) = icon_brush {
+                if color.a() > 0 {
+                    icon.tint(color);
+                }
+            }
+
+            *cache = Some((svg, icon_brush, icon));
+        }
+
+        if let Some((_, _, icon)) = &mut *cache {
+            ctx.render_context_2_d().draw_image_with_size(
+                icon,
+                global_position.x() + bounds.x(),
+                global_position.y() + bounds.y(),
+                bounds.width(),
+                bounds.height(),
+            );
+        }
+    }
+}
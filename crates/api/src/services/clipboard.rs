@@ -1,20 +1,61 @@
-/// Clipboard leads you read and store a value.
+use std::{fmt, path::PathBuf, sync::mpsc};
+
+use dces::entity::Entity;
+
+pub use orbtk_shell::clipboard::{
+    ClipboardContent, ClipboardKind, MIME_HTML, MIME_PNG, MIME_TEXT, MIME_URI_LIST,
+};
+
+use crate::{render::Image, shell::WindowRequest, widget_base::Dispatcher};
+
+/// This message is sent to the subscribed widgets after the content of the clipboard or the
+/// primary selection is changed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClipboardChanged(pub ClipboardKind);
+
+#[derive(Clone)]
+struct Subscriber {
+    entity: Entity,
+    dispatcher: Dispatcher,
+}
+
+/// Clipboard leads you read and store the content of the clipboard and the primary selection.
+/// The content could be stored in multiple formats: plain text, html, an image and file paths.
 ///
-/// To access the value of systems clipboard it must be used in combination with a window shell.
+/// To access the content of systems clipboard it must be used in combination with a window shell.
+/// Widgets could subscribe to get a `ClipboardChanged` message after the content is changed.
 ///
 /// # Examples
 /// ```
 /// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         registry
+///             .get_mut::<Clipboard>("clipboard")
+///             .subscribe(ctx.entity, ctx.dispatcher());
+///     }
+///
 ///     fn update(&mut self, registry: &mut Registry, _: &mut Context) {
-///         let mut clipboard = registry.get_mut;:<Clipboard>("clipboard");
+///         let mut clipboard = registry.get_mut::<Clipboard>("clipboard");
 ///         println!("{:?}", clipboard.get());
-///         clipboard.set("paste");
+///         clipboard.set_content(
+///             ClipboardKind::Clipboard,
+///             ClipboardContent::from_text("paste").html("<b>paste</b>"),
+///         );
+///     }
+///
+///     fn message(&mut self, message: Message, registry: &mut Registry, _: &mut Context) {
+///         if let Ok(ClipboardChanged(kind)) = message.downcast::<ClipboardChanged>() {
+///             println!("{:?}", registry.get::<Clipboard>("clipboard").content(kind));
+///         }
 ///     }
 /// }
 /// ```
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default)]
 pub struct Clipboard {
-    value: Option<String>,
+    clipboard: ClipboardContent,
+    selection: ClipboardContent,
+    window_sender: Option<mpsc::Sender<WindowRequest>>,
+    subscribers: Vec<Subscriber>,
 }
 
 impl Clipboard {
@@ -23,15 +64,112 @@ impl Clipboard {
         Clipboard::default()
     }
 
-    /// Return the latest value of the clipboard.
-    /// If there is no value present on the clipboard it will return `None`.
+    /// Return the latest text of the clipboard.
+    /// If there is no text present on the clipboard it will return `None`.
     pub fn get(&self) -> Option<String> {
-        self.value.clone()
+        self.clipboard.text.clone()
     }
 
-    /// Sets the value of the clipboard.
+    /// Sets the text of the clipboard.
     pub fn set(&mut self, value: impl Into<String>) {
-        self.value = Some(value.into());
+        self.set_content(ClipboardKind::Clipboard, ClipboardContent::from_text(value));
+    }
+
+    /// Return the latest html of the clipboard.
+    pub fn html(&self) -> Option<String> {
+        self.clipboard.html.clone()
+    }
+
+    /// Return the latest image of the clipboard.
+    pub fn image(&self) -> Option<Image> {
+        self.clipboard.image.clone()
+    }
+
+    /// Return the file paths of the clipboard.
+    pub fn files(&self) -> &[PathBuf] {
+        &self.clipboard.files
+    }
+
+    /// Return the latest text of the primary selection.
+    pub fn selection(&self) -> Option<String> {
+        self.selection.text.clone()
+    }
+
+    /// Sets the text of the primary selection.
+    pub fn set_selection(&mut self, value: impl Into<String>) {
+        self.set_content(ClipboardKind::Selection, ClipboardContent::from_text(value));
+    }
+
+    /// Gets the content of the clipboard or the primary selection.
+    pub fn content(&self, kind: ClipboardKind) -> &ClipboardContent {
+        match kind {
+            ClipboardKind::Clipboard => &self.clipboard,
+            ClipboardKind::Selection => &self.selection,
+        }
+    }
+
+    /// Sets the content of the clipboard or the primary selection and updates the content of
+    /// the system.
+    pub fn set_content(&mut self, kind: ClipboardKind, content: ClipboardContent) {
+        if *self.content(kind) == content {
+            return;
+        }
+
+        if let Some(window_sender) = &self.window_sender {
+            window_sender
+                .send(WindowRequest::SetClipboard(kind, content.clone()))
+                .ok();
+        }
+
+        self.replace(kind, content);
+    }
+
+    /// Subscribes the given widget. Its state gets a `ClipboardChanged` message after the content
+    /// is changed.
+    pub fn subscribe(&mut self, entity: Entity, dispatcher: Dispatcher) {
+        self.subscribers.push(Subscriber { entity, dispatcher });
+    }
+
+    /// Removes the subscription of the given widget.
+    pub fn unsubscribe(&mut self, entity: Entity) {
+        self.subscribers
+            .retain(|subscriber| subscriber.entity != entity);
+    }
+
+    // Connects the clipboard with the system clipboard of the window.
+    pub(crate) fn connect(&mut self, window_sender: mpsc::Sender<WindowRequest>) {
+        self.window_sender = Some(window_sender);
+    }
+
+    // Is called after the content of the system is changed by another application.
+    pub(crate) fn system_changed(&mut self, kind: ClipboardKind, content: ClipboardContent) {
+        if *self.content(kind) != content {
+            self.replace(kind, content);
+        }
+    }
+
+    fn replace(&mut self, kind: ClipboardKind, content: ClipboardContent) {
+        match kind {
+            ClipboardKind::Clipboard => self.clipboard = content,
+            ClipboardKind::Selection => self.selection = content,
+        }
+
+        // drops the subscribers of closed windows
+        self.subscribers.retain(|subscriber| {
+            subscriber
+                .dispatcher
+                .send(subscriber.entity, ClipboardChanged(kind))
+        });
+    }
+}
+
+impl fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clipboard")
+            .field("clipboard", &self.clipboard)
+            .field("selection", &self.selection)
+            .field("subscribers", &self.subscribers.len())
+            .finish()
     }
 }
 
@@ -47,4 +185,102 @@ mod tests {
         clipboard.set(test.clone());
         assert_eq!(test, clipboard.get().unwrap());
     }
+
+    #[test]
+    fn test_selection() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set("test");
+        assert_eq!(clipboard.selection(), None);
+
+        clipboard.set_selection("selected");
+        assert_eq!(clipboard.selection(), Some(String::from("selected")));
+        assert_eq!(clipboard.get(), Some(String::from("test")));
+    }
+
+    #[test]
+    fn test_formats() {
+        let mut clipboard = Clipboard::new();
+        let files = vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("/tmp/b.png")];
+
+        clipboard.set_content(
+            ClipboardKind::Clipboard,
+            ClipboardContent::from_text("OrbTk")
+                .html("<b>OrbTk</b>")
+                .files(files.clone()),
+        );
+        assert_eq!(clipboard.get(), Some(String::from("OrbTk")));
+        assert_eq!(clipboard.html(), Some(String::from("<b>OrbTk</b>")));
+        assert_eq!(clipboard.files(), files.as_slice());
+        assert_eq!(clipboard.image(), None);
+
+        // a new content replaces all formats
+        clipboard.set("text");
+        assert_eq!(clipboard.html(), None);
+        assert!(clipboard.files().is_empty());
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "glupath")))]
+    #[test]
+    fn test_image() {
+        let image = Image::from_data(1, 1, vec![0xFF_00_FF_00]).unwrap();
+        let mut clipboard = Clipboard::new();
+
+        clipboard.set_content(
+            ClipboardKind::Clipboard,
+            ClipboardContent::default().image(image.clone()),
+        );
+        assert_eq!(clipboard.image(), Some(image));
+        assert_eq!(clipboard.get(), None);
+    }
+
+    #[test]
+    fn test_set_content() {
+        let (sender, receiver) = mpsc::channel();
+        let mut clipboard = Clipboard::new();
+        clipboard.connect(sender);
+
+        let content = ClipboardContent::from_text("OrbTk").html("<b>OrbTk</b>");
+        clipboard.set_content(ClipboardKind::Clipboard, content.clone());
+        assert_eq!(clipboard.html(), Some(String::from("<b>OrbTk</b>")));
+        assert_eq!(
+            receiver.try_recv(),
+            Ok(WindowRequest::SetClipboard(
+                ClipboardKind::Clipboard,
+                content.clone()
+            ))
+        );
+
+        // nothing changed
+        clipboard.set_content(ClipboardKind::Clipboard, content);
+        assert!(receiver.try_recv().is_err());
+
+        // changes of the system are not sent back
+        clipboard.system_changed(
+            ClipboardKind::Selection,
+            ClipboardContent::from_text("selected"),
+        );
+        assert_eq!(clipboard.selection(), Some(String::from("selected")));
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_subscribe() {
+        let (sender, receiver) = mpsc::channel();
+        let (window_sender, _window_receiver) = mpsc::channel();
+        let mut clipboard = Clipboard::new();
+        clipboard.subscribe(Entity::from(2), Dispatcher::new(sender, window_sender));
+
+        clipboard.set("test");
+        assert_eq!(receiver.try_recv().unwrap().target(), Entity::from(2));
+
+        clipboard.system_changed(
+            ClipboardKind::Clipboard,
+            ClipboardContent::from_text("test"),
+        );
+        assert!(receiver.try_recv().is_err());
+
+        clipboard.unsubscribe(Entity::from(2));
+        clipboard.set_selection("selected");
+        assert!(receiver.try_recv().is_err());
+    }
 }
@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use dces::entity::Entity;
+
+pub use orbtk_shell::desktop::{FileDialogBackend, FileDialogKind, FileDialogRequest, FileFilter};
+
+use crate::widget_base::Context;
+
+/// Shows the file dialog rendered by OrbTk if there is no native file dialog. The result must be
+/// sent as `FileDialogResult` message to the given target.
+pub type FileDialogFallback = fn(&mut Context, FileDialogRequest, Entity);
+
+/// This message is sent to the state of the widget that requested a file dialog after the dialog
+/// is closed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileDialogResult {
+    /// The kind of the closed dialog.
+    pub kind: FileDialogKind,
+
+    /// The picked paths. It is empty if the dialog was canceled.
+    pub paths: Vec<PathBuf>,
+}
+
+impl FileDialogResult {
+    /// Creates a new result of a canceled dialog.
+    pub fn canceled(kind: FileDialogKind) -> Self {
+        FileDialogResult {
+            kind,
+            paths: vec![],
+        }
+    }
+
+    /// Check if the dialog was canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Gets the first picked path.
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.paths.first()
+    }
+}
+
+/// `FileDialogs` is a global service that shows file dialogs to open and save files and to pick
+/// folders. It uses the native file dialogs of the platform, on Linux the XDG desktop portal
+/// (feature `desktop`). If there is no native file dialog the dialog rendered by OrbTk is
+/// shown. It's registered as `file_dialog`.
+///
+/// # Example
+///
+/// ```rust
+/// impl State for EditorState {
+///     fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+///         if self.open {
+///             self.open = false;
+///             registry.get_mut::<FileDialogs>("file_dialog").show(
+///                 ctx,
+///                 FileDialogRequest::open().filter(FileFilter::new("Text").pattern("*.txt")),
+///             );
+///         }
+///     }
+///
+///     fn message(&mut self, message: Message, _: &mut Registry, ctx: &mut Context) {
+///         if let Ok(result) = message.downcast::<FileDialogResult>() {
+///             if let Some(path) = result.path() {
+///                 self.load(path, ctx);
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct FileDialogs {
+    backend: Option<Box<dyn FileDialogBackend>>,
+    fallback: Option<FileDialogFallback>,
+}
+
+impl FileDialogs {
+    /// Creates a new service with the native file dialogs of the current platform.
+    #[cfg(all(target_os = "linux", feature = "desktop"))]
+    pub fn new() -> Self {
+        FileDialogs::from_backend(orbtk_shell::desktop::PortalFileDialog::new())
+    }
+
+    /// Creates a new service with the native file dialogs of the current platform.
+    #[cfg(not(all(target_os = "linux", feature = "desktop")))]
+    pub fn new() -> Self {
+        FileDialogs::default()
+    }
+
+    /// Creates a new service with the given backend.
+    pub fn from_backend(backend: impl FileDialogBackend + 'static) -> Self {
+        FileDialogs {
+            backend: Some(Box::new(backend)),
+            fallback: None,
+        }
+    }
+
+    /// Sets the dialog that is shown if there is no native file dialog.
+    pub fn set_fallback(&mut self, fallback: FileDialogFallback) {
+        self.fallback = Some(fallback);
+    }
+
+    /// Shows a file dialog for the widget of the given context. After the dialog is closed the
+    /// state of the widget receives a `FileDialogResult` message.
+    pub fn show(&mut self, ctx: &mut Context, request: FileDialogRequest) {
+        let entity = ctx.entity;
+        let kind = request.kind;
+        let dispatcher = ctx.dispatcher();
+
+        if let Some(backend) = &mut self.backend {
+            let reply_dispatcher = dispatcher.clone();
+            let result = backend.show(
+                &request,
+                Box::new(move |paths| {
+                    reply_dispatcher.send(entity, FileDialogResult { kind, paths });
+                }),
+            );
+
+            if result.is_ok() {
+                return;
+            }
+        }
+
+        if let Some(fallback) = self.fallback {
+            fallback(ctx, request, entity);
+            return;
+        }
+
+        dispatcher.send(entity, FileDialogResult::canceled(kind));
+    }
+}
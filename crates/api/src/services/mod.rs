@@ -1,7 +1,9 @@
 //! This module contains global services.
 //!
 pub use self::clipboard::*;
+pub use self::file_dialog::*;
 pub use self::settings::*;
 
 mod clipboard;
+mod file_dialog;
 mod settings;
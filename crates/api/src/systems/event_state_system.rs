@@ -1,8 +1,164 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree, utils::*};
+use crate::{
+    prelude::*,
+    render::RenderContext2D,
+    shell::{Instant, Key, WindowRequest},
+    theming::Theme,
+    tree::Tree,
+    utils::*,
+};
+
+// Upper bound of event passes of one run. Events that are pushed while events are handled are
+// processed in the next pass, events that are left after the last pass wait for the next run.
+const MAX_EVENT_PASSES: usize = 100;
+
+// Returns the position that is used to find the target widgets of a touch or gesture event.
+fn touch_position(event: &EventBox) -> Option<Point> {
+    if let Ok(event) = event.downcast_ref::<TouchDownEvent>() {
+        return Some(event.position);
+    }
+    if let Ok(event) = event.downcast_ref::<TouchMoveEvent>() {
+        return Some(event.position);
+    }
+    if let Ok(event) = event.downcast_ref::<TapEvent>() {
+        return Some(event.position);
+    }
+    if let Ok(event) = event.downcast_ref::<LongPressEvent>() {
+        return Some(event.position);
+    }
+    if let Ok(event) = event.downcast_ref::<PanEvent>() {
+        return Some(event.origin);
+    }
+    if let Ok(event) = event.downcast_ref::<PinchEvent>() {
+        return Some(event.origin);
+    }
+    if let Ok(event) = event.downcast_ref::<SwipeEvent>() {
+        return Some(event.origin);
+    }
+
+    None
+}
+
+// Delivers a message or a call to the state of a widget.
+type Delivery = Box<dyn FnOnce(&mut dyn State, &mut Registry, &mut Context)>;
+
+// Returns the widget that has captured the mouse if it receives the event independent of the
+// mouse position. That are the mouse moves and the release of the button that ends the capture.
+fn captured_target(event: &EventBox, capture: Option<Entity>) -> Option<Entity> {
+    capture.filter(|_| event.is_type::<MouseMoveEvent>() || event.is_type::<MouseUpEvent>())
+}
+
+// Searches the cursor icon of the top most widget below the given position.
+fn cursor_icon_at(
+    position: Point,
+    parent: Entity,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    theme: &Theme,
+    cursor_icon: &mut CursorIcon,
+) {
+    let children = ecm
+        .entity_store()
+        .children
+        .get(&parent)
+        .cloned()
+        .unwrap_or_default();
+
+    for child in children {
+        {
+            let widget = WidgetContainer::new(child, ecm, theme, None);
+
+            if *widget.get::<Visibility>("visibility") != Visibility::Visible
+                || !check_mouse_condition(position, &widget)
+            {
+                continue;
+            }
+
+            if let Some(icon) = widget.try_clone::<CursorIcon>("cursor_icon") {
+                *cursor_icon = icon;
+            }
+        }
+
+        cursor_icon_at(position, child, ecm, theme, cursor_icon);
+    }
+}
+
+// Searches the tree path of the top most widget below the given position.
+fn hovered_path_at(
+    position: Point,
+    parent: Entity,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    theme: &Theme,
+) -> Vec<Entity> {
+    let children = ecm
+        .entity_store()
+        .children
+        .get(&parent)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut path = vec![];
+
+    for child in children {
+        {
+            let widget = WidgetContainer::new(child, ecm, theme, None);
+
+            if *widget.get::<Visibility>("visibility") != Visibility::Visible
+                || !check_mouse_condition(position, &widget)
+            {
+                continue;
+            }
+        }
+
+        path = vec![child];
+        path.append(&mut hovered_path_at(position, child, ecm, theme));
+    }
+
+    path
+}
+
+// Searches the top most drop target below the given position. Widgets for which `is_target`
+// returns `None` are skipped with their children.
+fn drop_target_at(
+    position: Point,
+    parent: Entity,
+    ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    theme: &Theme,
+    is_target: &dyn Fn(Entity) -> Option<bool>,
+    target: &mut Option<Entity>,
+) {
+    let children = ecm
+        .entity_store()
+        .children
+        .get(&parent)
+        .cloned()
+        .unwrap_or_default();
+
+    for child in children {
+        let child_is_target = match is_target(child) {
+            Some(child_is_target) => child_is_target,
+            None => continue,
+        };
+
+        {
+            let widget = WidgetContainer::new(child, ecm, theme, None);
+
+            if *widget.get::<Visibility>("visibility") != Visibility::Visible
+                || !check_mouse_condition(position, &widget)
+            {
+                continue;
+            }
+        }
+
+        if child_is_target {
+            *target = Some(child);
+        }
+
+        drop_target_at(position, child, ecm, theme, is_target, target);
+    }
+}
 
 /// The `EventStateSystem` pops events from the event queue and delegates the events to the corresponding event handlers of the widgets and updates the states.
 #[derive(Constructor)]
@@ -48,6 +204,348 @@ impl EventStateSystem {
             .handler_map
             .borrow_mut()
             .remove(&entity);
+        self.context_provider
+            .hovered_widgets
+            .borrow_mut()
+            .retain(|hovered| *hovered != entity);
+
+        if self.context_provider.mouse_capture.get() == Some(entity) {
+            self.context_provider.mouse_capture.set(None);
+        }
+    }
+
+    // Removes a widget with all its children.
+    fn remove_widget_tree(
+        &self,
+        entity: Entity,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        render_context: &mut RenderContext2D,
+    ) {
+        let mut children = vec![];
+        get_all_children(&mut children, entity, ecm.entity_store());
+
+        // remove children of target widget.
+        for child in children.iter().rev() {
+            self.remove_widget(*child, theme, ecm, render_context);
+        }
+
+        // remove target widget
+        self.remove_widget(entity, theme, ecm, render_context);
+    }
+
+    fn change_cursor_icon(&self, cursor_icon: CursorIcon) {
+        if cursor_icon != self.context_provider.cursor_icon.get() {
+            self.context_provider.cursor_icon.set(cursor_icon);
+            let _ = self
+                .context_provider
+                .window_sender
+                .send(WindowRequest::ChangeCursor(cursor_icon));
+        }
+    }
+
+    // Changes the mouse cursor to the cursor icon of the widget below the mouse.
+    fn update_cursor_icon(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let root = ecm.entity_store().root();
+        let overlay = ecm.entity_store().overlay;
+
+        let theme = ecm
+            .component_store()
+            .get::<Theme>("theme", root)
+            .unwrap()
+            .clone();
+
+        let mut cursor_icon = CursorIcon::default();
+        cursor_icon_at(position, root, ecm, &theme, &mut cursor_icon);
+
+        if let Some(overlay) = overlay {
+            cursor_icon_at(position, overlay, ecm, &theme, &mut cursor_icon);
+        }
+
+        self.change_cursor_icon(cursor_icon);
+    }
+
+    // Sends mouse leave events to the widgets that are no longer below the mouse and mouse enter
+    // events to the new ones.
+    fn update_hovered_widgets(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let root = ecm.entity_store().root();
+        let overlay = ecm.entity_store().overlay;
+
+        let theme = ecm
+            .component_store()
+            .get::<Theme>("theme", root)
+            .unwrap()
+            .clone();
+
+        let mut hovered = hovered_path_at(position, root, ecm, &theme);
+
+        if let Some(overlay) = overlay {
+            let overlay_path = hovered_path_at(position, overlay, ecm, &theme);
+
+            if !overlay_path.is_empty() {
+                hovered = overlay_path;
+            }
+        }
+
+        let old_hovered = self
+            .context_provider
+            .hovered_widgets
+            .replace(hovered.clone());
+
+        // leaves from the leaf to the root
+        for entity in old_hovered.iter().rev() {
+            if !hovered.contains(entity) {
+                self.process_direct_handlers(*entity, MouseLeaveEvent { position }, ecm);
+            }
+        }
+
+        // enters from the root to the leaf
+        for entity in hovered.iter() {
+            if !old_hovered.contains(entity) {
+                self.process_direct_handlers(*entity, MouseEnterEvent { position }, ecm);
+            }
+        }
+    }
+
+    // Calls the handlers of the given target that handle the event without bubbling. Returns
+    // `None` if there is no handler, otherwise if one of them returns `true`.
+    fn process_direct_handlers<E: Event>(
+        &self,
+        target: Entity,
+        event: E,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<bool> {
+        self.process_handlers(
+            target,
+            &EventBox::new(event, EventStrategy::Direct, target),
+            ecm,
+        )
+    }
+
+    // Calls the handlers of the given target that handle the event box.
+    fn process_handlers(
+        &self,
+        target: Entity,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<bool> {
+        let handlers: Vec<Rc<dyn EventHandler>> = self
+            .context_provider
+            .handler_map
+            .borrow()
+            .get(&target)?
+            .iter()
+            .filter(|handler| handler.handles_event(event))
+            .cloned()
+            .collect();
+
+        if handlers.is_empty() {
+            return None;
+        }
+
+        Some(handlers.iter().fold(false, |handled, handler| {
+            handler.handle_event(
+                &mut StatesContext::new(
+                    &mut *self.context_provider.states.borrow_mut(),
+                    ecm,
+                    &self.context_provider.mouse_capture,
+                ),
+                event,
+            ) || handled
+        }))
+    }
+
+    // Moves the drag visual and informs the drop targets below the mouse.
+    fn process_drag_move(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let (payload, visual, offset, old_target, mut accepted) =
+            match &*self.context_provider.drag_session.borrow() {
+                Some(session) => (
+                    session.payload.clone(),
+                    session.visual,
+                    session.offset,
+                    session.target,
+                    session.accepted,
+                ),
+                None => return,
+            };
+
+        if let Some(visual) = visual {
+            if let Ok(bounds) = ecm
+                .component_store_mut()
+                .get_mut::<Rectangle>("bounds", visual)
+            {
+                bounds.set_x(position.x() + offset.x());
+                bounds.set_y(position.y() + offset.y());
+            }
+
+            mark_as_dirty_self(visual, ecm);
+        }
+
+        let root = ecm.entity_store().root();
+        let overlay = ecm.entity_store().overlay;
+
+        let theme = ecm
+            .component_store()
+            .get::<Theme>("theme", root)
+            .unwrap()
+            .clone();
+
+        let probes = [
+            EventBox::new(
+                DragEnterEvent {
+                    payload: payload.clone(),
+                    position,
+                },
+                EventStrategy::Direct,
+                root,
+            ),
+            EventBox::new(
+                DragOverEvent {
+                    payload: payload.clone(),
+                    position,
+                },
+                EventStrategy::Direct,
+                root,
+            ),
+            EventBox::new(
+                DragDropEvent {
+                    payload: payload.clone(),
+                    position,
+                },
+                EventStrategy::Direct,
+                root,
+            ),
+        ];
+
+        let mut target = None;
+
+        {
+            let handler_map = self.context_provider.handler_map.borrow();
+
+            // the drag visual is never a drop target
+            let is_target = |entity: Entity| {
+                if Some(entity) == visual {
+                    return None;
+                }
+
+                Some(
+                    handler_map
+                        .get(&entity)
+                        .into_iter()
+                        .flatten()
+                        .any(|handler| probes.iter().any(|probe| handler.handles_event(probe))),
+                )
+            };
+
+            drop_target_at(position, root, ecm, &theme, &is_target, &mut target);
+
+            if let Some(overlay) = overlay {
+                drop_target_at(position, overlay, ecm, &theme, &is_target, &mut target);
+            }
+        }
+
+        if target != old_target {
+            if let Some(old_target) = old_target {
+                self.process_direct_handlers(
+                    old_target,
+                    DragLeaveEvent {
+                        payload: payload.clone(),
+                    },
+                    ecm,
+                );
+            }
+
+            accepted = false;
+
+            if let Some(target) = target {
+                accepted = self
+                    .process_direct_handlers(
+                        target,
+                        DragEnterEvent {
+                            payload: payload.clone(),
+                            position,
+                        },
+                        ecm,
+                    )
+                    .unwrap_or(false);
+            }
+        }
+
+        if let Some(target) = target {
+            if let Some(accepted_over) =
+                self.process_direct_handlers(target, DragOverEvent { payload, position }, ecm)
+            {
+                accepted = accepted_over;
+            }
+        }
+
+        if let Some(session) = &mut *self.context_provider.drag_session.borrow_mut() {
+            session.target = target;
+            session.accepted = accepted;
+        }
+
+        self.change_cursor_icon(if accepted {
+            CursorIcon::Move
+        } else {
+            CursorIcon::NotAllowed
+        });
+    }
+
+    // Finishes the drag. The payload is dropped at the given position on an accepting target,
+    // without a position the drag is canceled. Returns the drag visual that has to be removed.
+    fn process_drag_end(
+        &self,
+        position: Option<Point>,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<Entity> {
+        let session = self.context_provider.drag_session.borrow_mut().take()?;
+        let payload = session.payload;
+        let mut dropped = false;
+
+        if let Some(target) = session.target {
+            match position {
+                Some(position) if session.accepted => {
+                    dropped = self
+                        .process_direct_handlers(
+                            target,
+                            DragDropEvent {
+                                payload: payload.clone(),
+                                position,
+                            },
+                            ecm,
+                        )
+                        .unwrap_or(false);
+                }
+                _ => {
+                    self.process_direct_handlers(
+                        target,
+                        DragLeaveEvent {
+                            payload: payload.clone(),
+                        },
+                        ecm,
+                    );
+                }
+            }
+        }
+
+        self.process_direct_handlers(session.source, DragEndEvent { payload, dropped }, ecm);
+
+        let mouse_position = self.context_provider.mouse_position.get();
+        self.update_cursor_icon(position.unwrap_or(mouse_position), ecm);
+
+        session.visual
     }
 
     fn process_direct(
@@ -72,6 +570,7 @@ impl EventStateSystem {
                         &mut StatesContext::new(
                             &mut *self.context_provider.states.borrow_mut(),
                             ecm,
+                            &self.context_provider.mouse_capture,
                         ),
                         &event,
                     )
@@ -83,26 +582,51 @@ impl EventStateSystem {
         false
     }
 
-    fn process_bottom_up_event(
+    // Delivers a command to the command handlers of the window until one of them handles it.
+    fn process_command(
         &self,
-        mouse_position: Point,
         event: &EventBox,
         ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
     ) -> bool {
-        let mut matching_nodes = vec![];
-        let mut update = false;
+        let targets: Vec<Entity> = self
+            .context_provider
+            .handler_map
+            .borrow()
+            .iter()
+            .filter(|(_, handlers)| handlers.iter().any(|handler| handler.handles_event(event)))
+            .map(|(entity, _)| *entity)
+            .collect();
 
-        let mut current_node = event.source;
-        let root = ecm.entity_store().root();
-        let mut disabled_parents = vec![];
+        for target in targets {
+            if let Some(handlers) = self.context_provider.handler_map.borrow().get(&target) {
+                let handled = handlers.iter().any(|handler| {
+                    handler.handle_event(
+                        &mut StatesContext::new(
+                            &mut *self.context_provider.states.borrow_mut(),
+                            ecm,
+                            &self.context_provider.mouse_capture,
+                        ),
+                        event,
+                    )
+                });
 
-        let theme = ecm
-            .component_store()
-            .get::<Theme>("theme", root)
-            .unwrap()
-            .clone();
+                if handled {
+                    break;
+                }
+            }
+        }
+
+        true
+    }
+
+    // Updates the keyboard state by a key event before it is handled, e.g. by a shortcut.
+    fn update_keyboard_state(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) {
+        let root = ecm.entity_store().root();
 
-        // global key handling
         if let Ok(event) = event.downcast_ref::<KeyDownEvent>() {
             if let Ok(keyboard_state) = ecm
                 .component_store_mut()
@@ -122,6 +646,202 @@ impl EventStateSystem {
                 keyboard_state.set_key_state(event.event.key, false);
             }
         }
+    }
+
+    // Executes the command whose shortcut matches the pressed key independent of the focused
+    // widget. The commands of the window hide the application commands. Returns `true` if a
+    // command was executed and the key event is consumed.
+    fn process_shortcut(
+        &self,
+        event: &KeyDownEvent,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let root = ecm.entity_store().root();
+
+        let keyboard_state = match ecm
+            .component_store()
+            .get::<KeyboardState>("keyboard_state", root)
+        {
+            Ok(keyboard_state) => keyboard_state.clone(),
+            Err(_) => return false,
+        };
+
+        let command = self
+            .context_provider
+            .commands
+            .find(event.event.key, &keyboard_state)
+            .or_else(|| {
+                self.context_provider
+                    .application_commands
+                    .find(event.event.key, &keyboard_state)
+            });
+
+        match command {
+            Some(command) if command.enabled => self.process_command(
+                &EventBox::new(
+                    CommandEvent {
+                        command: command.id,
+                    },
+                    EventStrategy::Direct,
+                    root,
+                ),
+                ecm,
+            ),
+            _ => false,
+        }
+    }
+
+    // Processes the event from the leaf to the root until it is handled. `captured` has already
+    // received the event as the widget that has captured the mouse and is skipped.
+    fn process_bottom_up_event(
+        &self,
+        mouse_position: Point,
+        event: &EventBox,
+        captured: Option<Entity>,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let mut update = false;
+
+        let matching_nodes = self.matching_nodes(mouse_position, event, event, ecm);
+        let mut handled = false;
+
+        for node in matching_nodes.iter().rev() {
+            if Some(*node) == captured {
+                continue;
+            }
+
+            if let Some(handlers) = self.context_provider.handler_map.borrow().get(node) {
+                handled = handlers.iter().any(|handler| {
+                    handler.handle_event(
+                        &mut StatesContext::new(
+                            &mut *self.context_provider.states.borrow_mut(),
+                            ecm,
+                            &self.context_provider.mouse_capture,
+                        ),
+                        event,
+                    )
+                });
+
+                update = true;
+            }
+
+            if handled {
+                break;
+            }
+        }
+
+        update
+    }
+
+    // Processes the event from the root to the leaf until it is handled.
+    fn process_top_down_event(
+        &self,
+        mouse_position: Point,
+        event: &EventBox,
+        handler_event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        for node in self.matching_nodes(mouse_position, event, handler_event, ecm) {
+            let handlers: Vec<Rc<dyn EventHandler>> = self
+                .context_provider
+                .handler_map
+                .borrow()
+                .get(&node)
+                .cloned()
+                .unwrap_or_default();
+
+            let handled = handlers.iter().any(|handler| {
+                handler.handle_event(
+                    &mut StatesContext::new(
+                        &mut *self.context_provider.states.borrow_mut(),
+                        ecm,
+                        &self.context_provider.mouse_capture,
+                    ),
+                    handler_event,
+                )
+            });
+
+            if handled {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // Sends the preview event of a mouse event from the root to the leaf. Returns `true` if an
+    // ancestor has handled it, then the mouse event is not bubbled.
+    fn process_preview_event(
+        &self,
+        mouse_position: Point,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let preview = if let Ok(mouse) = event.downcast_ref::<MouseDownEvent>() {
+            EventBox::new(
+                PreviewMouseDownEvent {
+                    button: mouse.button,
+                    position: mouse.position,
+                },
+                EventStrategy::TopDown,
+                event.source,
+            )
+        } else if let Ok(mouse) = event.downcast_ref::<MouseUpEvent>() {
+            EventBox::new(
+                PreviewMouseUpEvent {
+                    button: mouse.button,
+                    position: mouse.position,
+                },
+                EventStrategy::TopDown,
+                event.source,
+            )
+        } else if let Ok(mouse) = event.downcast_ref::<MouseMoveEvent>() {
+            EventBox::new(
+                PreviewMouseMoveEvent {
+                    position: mouse.position,
+                },
+                EventStrategy::TopDown,
+                event.source,
+            )
+        } else {
+            return false;
+        };
+
+        // skips the search of the nodes if there is no preview handler
+        if !self
+            .context_provider
+            .handler_map
+            .borrow()
+            .values()
+            .flatten()
+            .any(|handler| handler.handles_event(&preview))
+        {
+            return false;
+        }
+
+        self.process_top_down_event(mouse_position, event, &preview, ecm)
+    }
+
+    // Collects the nodes below the source of the event that could handle it in tree order.
+    // `handler_event` is used to check the handlers of a node, e.g. the preview of `event`.
+    fn matching_nodes(
+        &self,
+        mouse_position: Point,
+        event: &EventBox,
+        handler_event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Vec<Entity> {
+        let mut matching_nodes = vec![];
+
+        let mut current_node = event.source;
+        let root = ecm.entity_store().root();
+        let mut disabled_parents = vec![];
+
+        let theme = ecm
+            .component_store()
+            .get::<Theme>("theme", root)
+            .unwrap()
+            .clone();
 
         let mut unknown_event = true;
         let mut clipped_parent = vec![];
@@ -159,7 +879,10 @@ impl EventStateSystem {
                     .borrow()
                     .get(&current_node)
                 {
-                    if handlers.iter().any(|handler| handler.handles_event(event)) {
+                    if handlers
+                        .iter()
+                        .any(|handler| handler.handles_event(handler_event))
+                    {
                         has_handler = true;
                     }
                 }
@@ -283,6 +1006,37 @@ impl EventStateSystem {
                     }
                     unknown_event = false;
                 }
+                // touch and gesture handling
+                if let Some(position) = touch_position(event) {
+                    if check_mouse_condition(
+                        position,
+                        &WidgetContainer::new(
+                            current_node,
+                            ecm,
+                            &theme,
+                            Some(&self.context_provider.event_queue),
+                        ),
+                    ) {
+                        let mut add = true;
+                        if let Some(op) = clipped_parent.get(0) {
+                            if !check_mouse_condition(
+                                position,
+                                &WidgetContainer::new(
+                                    *op,
+                                    ecm,
+                                    &theme,
+                                    Some(&self.context_provider.event_queue),
+                                ),
+                            ) {
+                                add = false;
+                            }
+                        }
+                        if add && has_handler {
+                            matching_nodes.push(current_node);
+                        }
+                    }
+                    unknown_event = false;
+                }
 
                 if unknown_event
                     && *WidgetContainer::new(
@@ -314,29 +1068,7 @@ impl EventStateSystem {
             }
         }
 
-        let mut handled = false;
-
-        for node in matching_nodes.iter().rev() {
-            if let Some(handlers) = self.context_provider.handler_map.borrow().get(node) {
-                handled = handlers.iter().any(|handler| {
-                    handler.handle_event(
-                        &mut StatesContext::new(
-                            &mut *self.context_provider.states.borrow_mut(),
-                            ecm,
-                        ),
-                        event,
-                    )
-                });
-
-                update = true;
-            }
-
-            if handled {
-                break;
-            }
-        }
-
-        update
+        matching_nodes
     }
 }
 
@@ -348,10 +1080,100 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
     ) {
         let mut update = false;
 
+        // long presses are recognized by time without a touch event
+        {
+            let root = ecm.entity_store().root();
+            let mut gestures = self.context_provider.gestures.borrow_mut();
+
+            for gesture in gestures.update(Instant::now()) {
+                gesture.register(&mut *self.context_provider.event_queue.borrow_mut(), root);
+            }
+
+            if let Some(deadline) = gestures.long_press_deadline() {
+                let _ = self
+                    .context_provider
+                    .window_sender
+                    .send(WindowRequest::RedrawAt(deadline));
+            }
+        }
+
+        // the messages of the dispatchers and the calls of completed futures are delivered
+        // before the update of their targets
+        let mut deliveries: BTreeMap<Entity, Vec<Delivery>> = BTreeMap::new();
+
+        for dispatch in self.context_provider.dispatch_receiver.try_iter() {
+            deliveries
+                .entry(dispatch.target())
+                .or_insert_with(Vec::new)
+                .push(Box::new(
+                    move |state: &mut dyn State, registry: &mut Registry, ctx: &mut Context| {
+                        dispatch.deliver(state, registry, ctx)
+                    },
+                ));
+        }
+
+        let completed = self.context_provider.executor.borrow_mut().run();
+
+        for (entity, call) in completed {
+            deliveries
+                .entry(entity)
+                .or_insert_with(Vec::new)
+                .push(Box::new(
+                    move |state: &mut dyn State, _: &mut Registry, ctx: &mut Context| {
+                        call(state.as_any_mut(), ctx)
+                    },
+                ));
+        }
+
+        // skip deliveries to removed widgets
+        let states = self.context_provider.states.clone();
+        deliveries.retain(|target, _| states.borrow().contains_key(target));
+
+        for target in deliveries.keys() {
+            mark_as_dirty_self(*target, ecm);
+        }
+
+        let mut passes = 0;
+
         loop {
+            passes += 1;
+
+            // the drag visual of a finished drag
+            let mut drag_visual = None;
+
             {
                 let mouse_position = self.context_provider.mouse_position.get();
                 for event in self.context_provider.event_queue.borrow_mut().into_iter() {
+                    let dragging = self.context_provider.drag_session.borrow().is_some();
+
+                    self.update_keyboard_state(&event, ecm);
+
+                    if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+                        self.update_hovered_widgets(event.position, ecm);
+
+                        if dragging {
+                            self.process_drag_move(event.position, ecm);
+                        } else {
+                            self.update_cursor_icon(event.position, ecm);
+                        }
+                    }
+
+                    if dragging {
+                        // the mouse up event is processed afterwards to release the pressed widgets
+                        if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+                            drag_visual = self.process_drag_end(Some(event.position), ecm);
+                            update = true;
+                        }
+
+                        if let Ok(key_event) = event.downcast_ref::<KeyDownEvent>() {
+                            if key_event.event.key == Key::Escape {
+                                drag_visual = self.process_drag_end(None, ecm);
+                                update = true;
+                                continue;
+                            }
+                        }
+                    }
+
                     if let Ok(event) = event.downcast_ref::<SystemEvent>() {
                         match event {
                             SystemEvent::Quit => {
@@ -361,21 +1183,74 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         }
                     }
 
+                    if let Ok(key_event) = event.downcast_ref::<KeyDownEvent>() {
+                        if self.process_shortcut(key_event, ecm) {
+                            update = true;
+                            continue;
+                        }
+                    }
+
+                    if event.downcast_ref::<CommandEvent>().is_ok() {
+                        update = self.process_command(&event, ecm) || update;
+                        continue;
+                    }
+
+                    if event.is_type::<MouseDownEvent>() {
+                        self.context_provider.mouse_pressed.set(true);
+                    }
+
+                    // a capture without a pressed button is stale, e.g. if it was requested after
+                    // the mouse up
+                    if event.is_type::<MouseMoveEvent>()
+                        && !self.context_provider.mouse_pressed.get()
+                    {
+                        self.context_provider.mouse_capture.set(None);
+                    }
+
+                    // mouse moves are sent only to the widget that has captured the mouse, the
+                    // release is sent to it first and is afterwards routed as usual
+                    let capture =
+                        captured_target(&event, self.context_provider.mouse_capture.get());
+
+                    if let Some(capture) = capture {
+                        update = self.process_handlers(capture, &event, ecm).is_some() || update;
+
+                        if event.is_type::<MouseMoveEvent>() {
+                            continue;
+                        }
+                    }
+
                     match event.strategy {
                         EventStrategy::Direct => {
                             if event.strategy == EventStrategy::Direct {
                                 update = self.process_direct(&event, ecm) || update;
                             }
                         }
-                        // EventStrategy::TopDown => {
-                        //     self.process_top_down_event(&event, ecm);
-                        // }
+                        EventStrategy::TopDown => {
+                            update =
+                                self.process_top_down_event(mouse_position, &event, &event, ecm)
+                                    || update;
+                        }
                         EventStrategy::BottomUp => {
-                            let should_update =
-                                self.process_bottom_up_event(mouse_position, &event, ecm);
-                            update = update || should_update;
+                            if self.process_preview_event(mouse_position, &event, ecm) {
+                                update = true;
+                            } else {
+                                let should_update = self.process_bottom_up_event(
+                                    mouse_position,
+                                    &event,
+                                    capture,
+                                    ecm,
+                                );
+                                update = update || should_update;
+                            }
                         }
                     }
+
+                    // the capture ends with the release of the mouse button
+                    if event.is_type::<MouseUpEvent>() {
+                        self.context_provider.mouse_pressed.set(false);
+                        self.context_provider.mouse_capture.set(None);
+                    }
                 }
             }
 
@@ -393,6 +1268,14 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
 
             let mut remove_widget_list: Vec<Entity> = vec![];
 
+            if let Some(drag_visual) = drag_visual {
+                self.remove_widget_tree(drag_visual, &theme, ecm, render_context);
+
+                if let Some(overlay) = ecm.entity_store().overlay {
+                    mark_as_dirty_self(overlay, ecm);
+                }
+            }
+
             let mut dirty_index = 0;
 
             loop {
@@ -435,6 +1318,10 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         if let Some(state) =
                             self.context_provider.states.borrow_mut().get_mut(&widget)
                         {
+                            for delivery in deliveries.remove(&widget).unwrap_or_default() {
+                                delivery(state.as_mut(), registry, &mut ctx);
+                            }
+
                             state.update(registry, &mut ctx);
                         }
 
@@ -461,16 +1348,7 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                     }
 
                     while let Some(remove_widget) = remove_widget_list.pop() {
-                        let mut children = vec![];
-                        get_all_children(&mut children, remove_widget, ecm.entity_store());
-
-                        // remove children of target widget.
-                        for entity in children.iter().rev() {
-                            self.remove_widget(*entity, &theme, ecm, render_context);
-                        }
-
-                        // remove target widget
-                        self.remove_widget(remove_widget, &theme, ecm, render_context);
+                        self.remove_widget_tree(remove_widget, &theme, ecm, render_context);
                     }
                 }
 
@@ -482,6 +1360,61 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
             if self.context_provider.event_queue.borrow().is_empty() {
                 break;
             }
+
+            // a state pushes new events on each update, the remaining events are handled with
+            // the next run to keep the window responsive
+            if passes >= MAX_EVENT_PASSES {
+                crate::shell::CONSOLE.log(format!(
+                    "Warning: the event queue did not drain after {} passes.",
+                    MAX_EVENT_PASSES
+                ));
+                let _ = self
+                    .context_provider
+                    .window_sender
+                    .send(WindowRequest::Redraw);
+                break;
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell::MouseButton;
+
+    #[test]
+    fn test_captured_target() {
+        let capture = Some(Entity::from(2));
+        let outside = Point::new(-100.0, -100.0);
+
+        // the release outside of the widget reaches it and ends its drag
+        let release = EventBox::new(
+            MouseUpEvent {
+                button: MouseButton::Left,
+                position: outside,
+            },
+            EventStrategy::BottomUp,
+            Entity::from(0),
+        );
+        assert_eq!(captured_target(&release, capture), capture);
+        assert_eq!(captured_target(&release, None), None);
+
+        let moved = EventBox::new(
+            MouseMoveEvent { position: outside },
+            EventStrategy::BottomUp,
+            Entity::from(0),
+        );
+        assert_eq!(captured_target(&moved, capture), capture);
+
+        let pressed = EventBox::new(
+            MouseDownEvent {
+                button: MouseButton::Left,
+                position: outside,
+            },
+            EventStrategy::BottomUp,
+            Entity::from(0),
+        );
+        assert_eq!(captured_target(&pressed, capture), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, sync::mpsc};
+use std::{collections::BTreeMap, future::Future, sync::mpsc};
 
 use dces::prelude::*;
 
@@ -11,6 +11,7 @@ use crate::{
     shell::{ShellRequest, WindowRequest},
     theming::prelude::*,
     tree::Tree,
+    utils::{Point, Rectangle},
 };
 
 use super::WidgetContainer;
@@ -239,7 +240,7 @@ impl<'a> Context<'a> {
     /// exists an error will be returned.
     pub fn append_child_entity_to_overlay(&mut self, child: Entity) -> Result<(), String> {
         if let Some(overlay) = self.ecm.entity_store().overlay {
-            self.append_child_entity_to(overlay, child);
+            self.append_child_entity_to(child, overlay);
             return Ok(());
         }
 
@@ -290,6 +291,28 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Moves a child to the given index of the children of its parent e.g. to reorder the items
+    /// of a list. If the index is out of range the child is moved to the end.
+    pub fn move_child(&mut self, child: Entity, index: usize) {
+        let parent = match self.ecm.entity_store().parent.get(&child) {
+            Some(Some(parent)) => *parent,
+            _ => return,
+        };
+
+        if let Some(children) = self.ecm.entity_store().children.get_mut(&parent) {
+            if let Some(old_index) = children.iter().position(|&c| c == child) {
+                children.remove(old_index);
+                children.insert(index.min(children.len()), child);
+            }
+        }
+
+        if let Some(layout) = self.provider.layouts.borrow().get(&parent) {
+            layout.invalidate();
+        }
+
+        mark_as_dirty_self(parent, self.ecm);
+    }
+
     /// Returns a mutable reference of the children that should be removed.
     pub fn remove_widget_list(&mut self) -> &mut Vec<Entity> {
         &mut self.remove_widget_list
@@ -447,19 +470,155 @@ impl<'a> Context<'a> {
             .register_event_with_strategy(event, strategy, entity);
     }
 
-    /// Creates and show a new window.
-    pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(&mut self, create_fn: F) {
+    /// Creates and show a new window and returns its id.
+    pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+    ) -> WindowId {
+        self.create_window(create_fn, None)
+    }
+
+    /// Creates and show a new window that is owned by the current window e.g. a tool window. It
+    /// is closed together with the current window.
+    pub fn show_child_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+    ) -> WindowId {
+        let owner = self.provider.window_id;
+        self.create_window(create_fn, Some(owner))
+    }
+
+    fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+        owner: Option<WindowId>,
+    ) -> WindowId {
         let (adapter, settings, receiver) = create_window(
             self.provider.application_name.clone(),
             self.theme.clone(),
             self.provider.shell_sender.clone(),
             create_fn,
             self.provider.localization.clone(),
+            self.provider.window_manager.clone(),
+            owner,
+            self.provider.application_commands.clone(),
         );
+        let id = adapter.window_id();
+
         self.provider
             .shell_sender
             .send(ShellRequest::CreateWindow(adapter, settings, receiver))
             .expect("Context.show_window: Could not send shell request.");
+
+        id
+    }
+
+    /// Starts an internal drag and drop operation with the current widget as source. The
+    /// optional visual is appended to the overlay and moved with the mouse until the drag ends.
+    /// Returns `false` if there is already a running drag.
+    pub fn start_drag(&mut self, payload: DragPayload, visual: Option<Entity>) -> bool {
+        if self.is_dragging() {
+            return false;
+        }
+
+        let mouse_position = self.provider.mouse_position.get();
+        let position = self.widget().clone_or_default::<Point>("position");
+
+        if let Some(visual) = visual {
+            if self.append_child_entity_to_overlay(visual).is_ok() {
+                let mut visual = self.get_widget(visual);
+                visual.get_mut::<Rectangle>("bounds").set_x(position.x());
+                visual.get_mut::<Rectangle>("bounds").set_y(position.y());
+            }
+        }
+
+        *self.provider.drag_session.borrow_mut() = Some(DragSession {
+            source: self.entity,
+            payload,
+            visual,
+            offset: Point::new(
+                position.x() - mouse_position.x(),
+                position.y() - mouse_position.y(),
+            ),
+            target: None,
+            accepted: false,
+        });
+
+        true
+    }
+
+    /// Check if there is a running drag and drop operation on the window.
+    pub fn is_dragging(&self) -> bool {
+        self.provider.drag_session.borrow().is_some()
+    }
+
+    /// Captures the mouse. All mouse moves and the release of the mouse button are sent directly
+    /// to the current widget, also if the mouse is outside of its bounds.
+    ///
+    /// Use `StatesContext::capture_mouse` in a mouse down handler to capture before the next
+    /// event. A capture without a pressed mouse button is dropped with the next mouse move.
+    pub fn capture_mouse(&mut self) {
+        self.provider.mouse_capture.set(Some(self.entity));
+    }
+
+    /// Releases the mouse capture of the current widget.
+    pub fn release_mouse_capture(&mut self) {
+        if self.has_mouse_capture() {
+            self.provider.mouse_capture.set(None);
+        }
+    }
+
+    /// Check if the current widget has captured the mouse.
+    pub fn has_mouse_capture(&self) -> bool {
+        self.provider.mouse_capture.get() == Some(self.entity)
+    }
+
+    /// Gets the commands of the current window.
+    pub fn commands(&self) -> Commands {
+        self.provider.commands.clone()
+    }
+
+    /// Gets the commands of the application that are shared by all windows.
+    pub fn application_commands(&self) -> Commands {
+        self.provider.application_commands.clone()
+    }
+
+    /// Gets the command with the given id. A command of the window hides the application command
+    /// with the same id.
+    pub fn command(&self, id: &str) -> Option<Command> {
+        self.provider
+            .commands
+            .get(id)
+            .or_else(|| self.provider.application_commands.get(id))
+    }
+
+    /// Executes the command with the given id. The command is delivered to the command handlers of
+    /// the window. Returns `false` if the command is not registered or disabled.
+    pub fn execute_command(&mut self, id: &str) -> bool {
+        match self.command(id) {
+            Some(command) if command.enabled => {
+                let root = self.ecm.entity_store().root();
+                self.push_event_strategy_by_entity(
+                    CommandEvent {
+                        command: command.id,
+                    },
+                    root,
+                    EventStrategy::Direct,
+                );
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the id of the current window.
+    pub fn window_id(&self) -> WindowId {
+        self.provider.window_id
+    }
+
+    /// Gets the window manager that keeps track of all windows of the application.
+    pub fn window_manager(&self) -> WindowManager {
+        self.provider.window_manager.clone()
     }
 
     /// Returns a mutable reference of the 2d render ctx.
@@ -480,6 +639,53 @@ impl<'a> Context<'a> {
         self.provider.window_sender.clone()
     }
 
+    /// Creates a sender that pushes events directly to the given widget of the current window.
+    /// It could be handed over to another window e.g. to deliver the result of a dialog window.
+    pub fn window_event_sender(&self, target: Entity) -> WindowEventSender {
+        WindowEventSender::new(
+            self.provider.event_queue.clone(),
+            self.provider.window_sender.clone(),
+            target,
+        )
+    }
+
+    /// Creates a dispatcher that delivers messages and calls from any thread to the widget states
+    /// of the current window.
+    pub fn dispatcher(&self) -> Dispatcher {
+        Dispatcher::new(
+            self.provider.dispatch_sender.clone(),
+            self.provider.window_sender.clone(),
+        )
+    }
+
+    /// Spawns a future that runs on the ui thread.
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(&self, future: F) {
+        self.provider.executor.borrow_mut().spawn(future);
+    }
+
+    /// Spawns a future that runs on the ui thread. After it is completed `then` is called with
+    /// the state of the current widget, the output of the future and the context.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ctx.spawn_local_then(
+    ///     async { delay(Duration::from_millis(500)).await },
+    ///     |state: &mut SearchState, _, ctx| state.search(ctx),
+    /// );
+    /// ```
+    pub fn spawn_local_then<S, F, C>(&self, future: F, then: C)
+    where
+        S: State,
+        F: Future + 'static,
+        C: FnOnce(&mut S, F::Output, &mut Context) + 'static,
+    {
+        self.provider
+            .executor
+            .borrow_mut()
+            .spawn_then(self.entity, future, then);
+    }
+
     /// Returns a keys collection of new added states.
     pub fn new_states_keys(&self) -> Vec<Entity> {
         self.new_states.keys().cloned().collect()
@@ -542,3 +748,47 @@ pub fn get_all_children(children: &mut Vec<Entity>, parent: Entity, tree: &Tree)
 }
 
 // -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::{application::WindowManager, command::Commands};
+
+    #[test]
+    fn test_append_child_entity_to_overlay() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+        let root = ecm.create_entity().build();
+        let overlay = ecm.create_entity().build();
+        let child = ecm.create_entity().build();
+        ecm.entity_store_mut().set_root(root);
+        ecm.entity_store_mut().set_overlay(overlay);
+
+        let (window_sender, _) = mpsc::channel();
+        let (shell_sender, _) = mpsc::channel();
+        let provider = ContextProvider::new(
+            window_sender,
+            shell_sender,
+            "",
+            None,
+            WindowManager::new(),
+            None,
+            Commands::new(),
+        );
+        let mut render_context = RenderContext2D::new(10.0, 10.0);
+        let mut ctx = Context::new(
+            (root, ecm),
+            &Theme::default(),
+            &provider,
+            &mut render_context,
+        );
+
+        assert!(ctx.append_child_entity_to_overlay(child).is_ok());
+        assert_eq!(ctx.ecm.entity_store().parent[&child], Some(overlay));
+        assert_eq!(ctx.ecm.entity_store().children[&overlay], vec![child]);
+        assert_eq!(ctx.ecm.entity_store().parent[&overlay], None);
+    }
+}
@@ -0,0 +1,175 @@
+use std::{any::Any, sync::mpsc};
+
+use dces::entity::Entity;
+
+use crate::shell::WindowRequest;
+
+use super::{Context, Registry, State};
+
+type CallFn = dyn FnOnce(&mut dyn Any, &mut Context) + Send;
+
+/// A message that is sent by a [`Dispatcher`] to the state of a widget.
+///
+/// [`Dispatcher`]: ./struct.Dispatcher.html
+pub struct Message(Box<dyn Any + Send>);
+
+impl Message {
+    /// Creates a new message from the given value.
+    pub fn new<M: Any + Send>(message: M) -> Self {
+        Message(Box::new(message))
+    }
+
+    /// Check if the message is of the given type.
+    pub fn is<M: Any>(&self) -> bool {
+        self.0.is::<M>()
+    }
+
+    /// Gets the value of the message. If the message is of another type it is returned as error.
+    pub fn downcast<M: Any>(self) -> Result<M, Message> {
+        self.0.downcast::<M>().map(|m| *m).map_err(Message)
+    }
+}
+
+enum Payload {
+    Message(Message),
+    Call(Box<CallFn>),
+}
+
+/// A message or call that is sent by a [`Dispatcher`] and waits for its delivery on the ui thread.
+///
+/// [`Dispatcher`]: ./struct.Dispatcher.html
+pub struct Dispatch {
+    target: Entity,
+    payload: Payload,
+}
+
+impl Dispatch {
+    /// Gets the target widget.
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+
+    /// Delivers the message or call to the state of the target.
+    pub fn deliver(self, state: &mut dyn State, registry: &mut Registry, ctx: &mut Context) {
+        match self.payload {
+            Payload::Message(message) => state.message(message, registry, ctx),
+            Payload::Call(call) => call(state.as_any_mut(), ctx),
+        }
+    }
+}
+
+/// The `Dispatcher` delivers messages and calls from any thread to the widget states of a window.
+/// It could be cloned and send to a worker thread to post the results of the work back to the
+/// ui. Each dispatch wakes up the shell, the messages are delivered before the states are updated.
+///
+/// # Example
+///
+/// ```rust
+/// let dispatcher = ctx.dispatcher();
+/// let entity = ctx.entity;
+///
+/// std::thread::spawn(move || {
+///     let content = std::fs::read_to_string("notes.txt").unwrap_or_default();
+///     dispatcher.call(entity, move |state: &mut NotesState, ctx| {
+///         state.set_content(content, ctx);
+///     });
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Dispatcher {
+    sender: mpsc::Sender<Dispatch>,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+impl Dispatcher {
+    /// Creates a new dispatcher.
+    pub fn new(sender: mpsc::Sender<Dispatch>, window_sender: mpsc::Sender<WindowRequest>) -> Self {
+        Dispatcher {
+            sender,
+            window_sender,
+        }
+    }
+
+    /// Sends a message to the state of the target widget. It is handled by `State::message`.
+    /// Returns `false` if the window is already closed.
+    pub fn send<M: Any + Send>(&self, target: Entity, message: M) -> bool {
+        self.dispatch(Dispatch {
+            target,
+            payload: Payload::Message(Message::new(message)),
+        })
+    }
+
+    /// Calls `call` with the state of the target widget on the ui thread. The call is skipped if
+    /// the state of the target is not of type `S`. Returns `false` if the window is already
+    /// closed.
+    pub fn call<S, F>(&self, target: Entity, call: F) -> bool
+    where
+        S: State,
+        F: FnOnce(&mut S, &mut Context) + Send + 'static,
+    {
+        self.dispatch(Dispatch {
+            target,
+            payload: Payload::Call(Box::new(move |state: &mut dyn Any, ctx: &mut Context| {
+                if let Some(state) = state.downcast_mut::<S>() {
+                    call(state, ctx);
+                }
+            })),
+        })
+    }
+
+    fn dispatch(&self, dispatch: Dispatch) -> bool {
+        if self.sender.send(dispatch).is_err() {
+            return false;
+        }
+
+        // wakes up the shell
+        self.window_sender.send(WindowRequest::Redraw).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[test]
+    fn test_message_downcast() {
+        let message = Message::new(42_u32);
+        assert!(message.is::<u32>());
+        assert!(!message.is::<String>());
+
+        let message = message.downcast::<String>().unwrap_err();
+        assert_eq!(message.downcast::<u32>().ok(), Some(42));
+    }
+
+    #[test]
+    fn test_send() {
+        let (sender, receiver) = mpsc::channel();
+        let (window_sender, window_receiver) = mpsc::channel();
+        let dispatcher = Dispatcher::new(sender, window_sender);
+        assert_send(&dispatcher);
+
+        let worker = dispatcher.clone();
+        std::thread::spawn(move || worker.send(Entity::from(3), String::from("done")))
+            .join()
+            .unwrap();
+
+        let dispatch = receiver.try_recv().unwrap();
+        assert_eq!(dispatch.target(), Entity::from(3));
+        assert_eq!(window_receiver.try_recv(), Ok(WindowRequest::Redraw));
+
+        match dispatch.payload {
+            Payload::Message(message) => {
+                assert_eq!(
+                    message.downcast::<String>().ok(),
+                    Some(String::from("done"))
+                )
+            }
+            Payload::Call(_) => panic!("expected a message"),
+        }
+
+        drop(receiver);
+        assert!(!dispatcher.send(Entity::from(3), 0));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Context, Registry};
+use super::{Context, Message, Registry};
 use std::any::Any;
 
 pub trait AsAny: Any {
@@ -83,4 +83,15 @@ pub trait State: AsAny {
     ///
     /// [`event`]: ../trait.Event.html
     fn update_post_layout(&mut self, _registry: &mut Registry, _ctx: &mut Context) {}
+
+    /// Handles a message that is sent to the widget by a [`Dispatcher`] e.g. from a worker thread.
+    /// It is called on the ui thread before `update`.
+    ///
+    /// # Arguments
+    /// * `_message`: The message, use `downcast` to get its value.
+    /// * `_registry`: Provides access to the global Service Registry.
+    /// * `_ctx`: Represents the context of the current widget.Allows manipulation of the widget tree.
+    ///
+    /// [`Dispatcher`]: ./struct.Dispatcher.html
+    fn message(&mut self, _message: Message, _registry: &mut Registry, _ctx: &mut Context) {}
 }
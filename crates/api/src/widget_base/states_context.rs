@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{cell::Cell, collections::BTreeMap};
 
 use dces::prelude::{Component, Entity, EntityComponentManager, StringComponentStore};
 
@@ -10,6 +10,7 @@ use super::State;
 pub struct StatesContext<'a> {
     states: &'a mut BTreeMap<Entity, Box<dyn State>>,
     ecm: &'a mut EntityComponentManager<Tree, StringComponentStore>,
+    mouse_capture: &'a Cell<Option<Entity>>,
 }
 
 impl<'a> StatesContext<'a> {
@@ -17,8 +18,13 @@ impl<'a> StatesContext<'a> {
     pub fn new(
         states: &'a mut BTreeMap<Entity, Box<dyn State>>,
         ecm: &'a mut EntityComponentManager<Tree, StringComponentStore>,
+        mouse_capture: &'a Cell<Option<Entity>>,
     ) -> Self {
-        StatesContext { states, ecm }
+        StatesContext {
+            states,
+            ecm,
+            mouse_capture,
+        }
     }
 
     // Mark the widget as dirty.
@@ -92,6 +98,22 @@ impl<'a> StatesContext<'a> {
             })
     }
 
+    /// Captures the mouse for the given widget. All mouse moves and the release of the mouse
+    /// button are sent directly to the widget, also if the mouse is outside of its bounds. The
+    /// capture ends with the release.
+    ///
+    /// Call it from a mouse down handler, the capture is active for the next event.
+    pub fn capture_mouse(&mut self, entity: Entity) {
+        self.mouse_capture.set(Some(entity));
+    }
+
+    /// Releases the mouse capture of the given widget.
+    pub fn release_mouse_capture(&mut self, entity: Entity) {
+        if self.mouse_capture.get() == Some(entity) {
+            self.mouse_capture.set(None);
+        }
+    }
+
     /// Try to get the state of the given widget.
     pub fn try_get<S: Component>(&self, entity: Entity) -> Option<&S> {
         if let Some(e) = self.states.get(&entity) {
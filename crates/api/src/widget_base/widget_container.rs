@@ -510,6 +510,18 @@ impl<'a> WidgetContainer<'a> {
                             }
                         }
 
+                        if let Some(is_type) = self.is::<CornerRadius>(key) {
+                            if is_type {
+                                self.update_value::<CornerRadius, Value>(key, Value(value.clone()));
+                            }
+                        }
+
+                        if let Some(is_type) = self.is::<BoxShadow>(key) {
+                            if is_type {
+                                self.update_value::<BoxShadow, Value>(key, Value(value.clone()));
+                            }
+                        }
+
                         if let Some(is_type) = self.is::<String>(key) {
                             if is_type {
                                 self.update_value::<String, Value>(key, Value(value.clone()));
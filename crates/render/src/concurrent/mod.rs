@@ -25,6 +25,7 @@ enum RenderTask {
         width: f64,
         height: f64,
     },
+    SetScaleFactor(f64),
     RegisterFont {
         family: String,
         font_file: &'static [u8],
@@ -97,12 +98,27 @@ enum RenderTask {
         x: f64,
         y: f64,
     },
+    DrawImageWithSize {
+        image: Image,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
     DrawImageWithClip {
         image: Image,
         clip: Rectangle,
         x: f64,
         y: f64,
     },
+    DrawImageWithClipAndSize {
+        image: Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
     DrawPipeline {
         x: f64,
         y: f64,
@@ -142,6 +158,24 @@ enum RenderTask {
         h_moving: f64,
         v_moving: f64,
     },
+    Transform {
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    },
+    DrawBoxShadow {
+        rect: Rectangle,
+        radius: CornerRadius,
+        box_shadow: BoxShadow,
+    },
+    PushLayer {
+        opacity: f32,
+        rect: Rectangle,
+    },
+    PopLayer(),
     Finish(),
     Terminate(),
 }
@@ -161,10 +195,13 @@ fn is_single_tasks(task: &RenderTask) -> bool {
         RenderTask::Start() => true,
         RenderTask::SetBackground(_) => true,
         RenderTask::Resize { .. } => true,
+        RenderTask::SetScaleFactor(_) => true,
         RenderTask::RegisterFont { .. } => true,
         RenderTask::DrawRenderTarget { .. } => true,
         RenderTask::DrawImage { .. } => true,
+        RenderTask::DrawImageWithSize { .. } => true,
         RenderTask::DrawImageWithClip { .. } => true,
+        RenderTask::DrawImageWithClipAndSize { .. } => true,
         RenderTask::DrawPipeline { .. } => true,
         RenderTask::SetTransform { .. } => true,
         RenderTask::Terminate { .. } => true,
@@ -204,6 +241,10 @@ impl RenderWorker {
                             render_context_2_d.resize(width, height);
                             continue;
                         }
+                        RenderTask::SetScaleFactor(scale_factor) => {
+                            render_context_2_d.set_scale_factor(scale_factor);
+                            continue;
+                        }
                         RenderTask::RegisterFont { family, font_file } => {
                             render_context_2_d.register_font(family.as_str(), font_file);
                             continue;
@@ -218,9 +259,29 @@ impl RenderWorker {
                         RenderTask::DrawImage { image, x, y } => {
                             render_context_2_d.draw_image(&image, x, y);
                         }
+                        RenderTask::DrawImageWithSize {
+                            image,
+                            x,
+                            y,
+                            width,
+                            height,
+                        } => {
+                            render_context_2_d.draw_image_with_size(&image, x, y, width, height);
+                        }
                         RenderTask::DrawImageWithClip { image, clip, x, y } => {
                             render_context_2_d.draw_image_with_clip(&image, clip, x, y);
                         }
+                        RenderTask::DrawImageWithClipAndSize {
+                            image,
+                            clip,
+                            x,
+                            y,
+                            width,
+                            height,
+                        } => {
+                            render_context_2_d
+                                .draw_image_with_clip_and_size(&image, clip, x, y, width, height);
+                        }
                         RenderTask::DrawPipeline {
                             x,
                             y,
@@ -351,6 +412,31 @@ impl RenderWorker {
                             RenderTask::Clear { brush } => {
                                 render_context_2_d.clear(&brush);
                             }
+                            RenderTask::Transform {
+                                h_scaling,
+                                h_skewing,
+                                v_skewing,
+                                v_scaling,
+                                h_moving,
+                                v_moving,
+                            } => {
+                                render_context_2_d.transform(
+                                    h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+                                );
+                            }
+                            RenderTask::DrawBoxShadow {
+                                rect,
+                                radius,
+                                box_shadow,
+                            } => {
+                                render_context_2_d.draw_box_shadow(rect, radius, &box_shadow);
+                            }
+                            RenderTask::PushLayer { opacity, rect } => {
+                                render_context_2_d.push_layer(opacity, rect);
+                            }
+                            RenderTask::PopLayer() => {
+                                render_context_2_d.pop_layer();
+                            }
                             RenderTask::Finish() => {
                                 sender
                                     .lock()
@@ -385,6 +471,7 @@ pub struct RenderContext2D {
     finish_receiver: mpsc::Receiver<bool>,
     tasks: Vec<RenderTask>,
     measure_context: platform::RenderContext2D,
+    scale_factor: f64,
 }
 
 impl Drop for RenderContext2D {
@@ -419,6 +506,7 @@ impl RenderContext2D {
             finish_receiver,
             tasks: vec![],
             measure_context: platform::RenderContext2D::new(width, height),
+            scale_factor: 1.0,
         }
     }
 
@@ -462,6 +550,19 @@ impl RenderContext2D {
             .expect("Could not send resize to render thread.");
     }
 
+    /// Sets the scale factor, the number of physical pixels per logical unit.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.sender
+            .send(vec![RenderTask::SetScaleFactor(scale_factor)])
+            .expect("Could not send scale factor to render thread.");
+    }
+
+    /// Gets the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
     /// Registers a new font file.
     pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
         self.measure_context.register_font(family, font_file);
@@ -623,6 +724,26 @@ impl RenderContext2D {
             .expect("Could not send image to render thread.");
     }
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(
+        &mut self,
+        image: &mut Image,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.sender
+            .send(vec![RenderTask::DrawImageWithSize {
+                image: image.clone(),
+                x,
+                y,
+                width,
+                height,
+            }])
+            .expect("Could not send image to render thread.");
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &mut Image, clip: Rectangle, x: f64, y: f64) {
         self.sender
@@ -635,6 +756,28 @@ impl RenderContext2D {
             .expect("Could not send clipped image to render thread.");
     }
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &mut Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.sender
+            .send(vec![RenderTask::DrawImageWithClipAndSize {
+                image: image.clone(),
+                clip,
+                x,
+                y,
+                width,
+                height,
+            }])
+            .expect("Could not send clipped image to render thread.");
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -654,6 +797,20 @@ impl RenderContext2D {
             .expect("Could not send draw_pipeline to render thread.");
     }
 
+    /// Draws the shadow of a box with the given bounds and corner radius.
+    pub fn draw_box_shadow(
+        &mut self,
+        rect: Rectangle,
+        radius: CornerRadius,
+        box_shadow: &BoxShadow,
+    ) {
+        self.tasks.push(RenderTask::DrawBoxShadow {
+            rect,
+            radius,
+            box_shadow: *box_shadow,
+        });
+    }
+
     /// Creates a clipping path from the current sub-paths.
     /// Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
@@ -717,6 +874,41 @@ impl RenderContext2D {
         });
     }
 
+    /// Multiplies the current transformation with the given one.
+    pub fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        self.tasks.push(RenderTask::Transform {
+            h_scaling,
+            h_skewing,
+            v_skewing,
+            v_scaling,
+            h_moving,
+            v_moving,
+        });
+    }
+
+    // Layers
+
+    /// Redirects all following drawing operations to an offscreen layer until `pop_layer` is called.
+    /// The layer covers the given rectangle, drawings outside of it are dropped. It is drawn as a
+    /// whole with the given opacity.
+    pub fn push_layer(&mut self, opacity: f32, rect: Rectangle) {
+        self.tasks.push(RenderTask::PushLayer { opacity, rect });
+    }
+
+    /// Draws the most recently pushed layer on its parent.
+    /// If there is no pushed layer, this method does nothing.
+    pub fn pop_layer(&mut self) {
+        self.tasks.push(RenderTask::PopLayer());
+    }
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
@@ -122,6 +122,16 @@ impl RenderContext2D {
         }
     }
 
+    /// Sets the scale factor, the number of physical pixels per logical unit.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.device_pixel_ratio = scale_factor as f32;
+    }
+
+    /// Gets the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.device_pixel_ratio as f64
+    }
+
     pub fn resize(&mut self, _width: f64, _height: f64) {
         // if let Some(renderer) = &mut self.renderer {
         //     renderer.replace_dest_framebuffer(DestFramebuffer::full_window(vec2i(
@@ -287,15 +297,105 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the shadow of a box with the given bounds and corner radius. Inset shadows are not
+    /// supported yet.
+    pub fn draw_box_shadow(
+        &mut self,
+        rect: Rectangle,
+        radius: CornerRadius,
+        box_shadow: &BoxShadow,
+    ) {
+        if !box_shadow.is_visible() || box_shadow.inset() {
+            return;
+        }
+
+        // distance between the shape and its shadow, only the shadow is visible
+        const SHADOW_SHIFT: f32 = 100_000.0;
+
+        let device_pixel_ratio = self.device_pixel_ratio();
+        let spread = box_shadow.spread();
+        let x = (rect.x() + box_shadow.offset().x() - spread) as f32 - SHADOW_SHIFT;
+        let y = (rect.y() + box_shadow.offset().y() - spread) as f32;
+        let width = (rect.width() + 2.0 * spread).max(0.0) as f32;
+        let height = (rect.height() + 2.0 * spread).max(0.0) as f32;
+        let radius = radius
+            .spread(spread)
+            .fit(Size::new(width as f64, height as f64));
+        let top_left = radius.top_left() as f32;
+        let top_right = radius.top_right() as f32;
+        let bottom_right = radius.bottom_right() as f32;
+        let bottom_left = radius.bottom_left() as f32;
+        let (r, b) = (x + width, y + height);
+
+        let mut path = Path2D::new();
+        path.move_to(vec2f(x + top_left, y) * device_pixel_ratio);
+        path.line_to(vec2f(r - top_right, y) * device_pixel_ratio);
+        path.quadratic_curve_to(
+            vec2f(r, y) * device_pixel_ratio,
+            vec2f(r, y + top_right) * device_pixel_ratio,
+        );
+        path.line_to(vec2f(r, b - bottom_right) * device_pixel_ratio);
+        path.quadratic_curve_to(
+            vec2f(r, b) * device_pixel_ratio,
+            vec2f(r - bottom_right, b) * device_pixel_ratio,
+        );
+        path.line_to(vec2f(x + bottom_left, b) * device_pixel_ratio);
+        path.quadratic_curve_to(
+            vec2f(x, b) * device_pixel_ratio,
+            vec2f(x, b - bottom_left) * device_pixel_ratio,
+        );
+        path.line_to(vec2f(x, y + top_left) * device_pixel_ratio);
+        path.quadratic_curve_to(
+            vec2f(x, y) * device_pixel_ratio,
+            vec2f(x + top_left, y) * device_pixel_ratio,
+        );
+        path.close_path();
+
+        let color = box_shadow.color();
+
+        self.canvas().save();
+        self.canvas()
+            .set_shadow_color(ColorU::new(color.r(), color.g(), color.b(), color.a()));
+        self.canvas()
+            .set_shadow_blur(box_shadow.blur() as f32 * device_pixel_ratio);
+        self.canvas()
+            .set_shadow_offset(vec2f(SHADOW_SHIFT * device_pixel_ratio, 0.0));
+        self.canvas().fill_path(path, FillRule::Winding);
+        self.canvas().restore();
+    }
+
     /// Draws a render target.
     pub fn draw_render_target(&mut self, _render_target: &RenderTarget, _x: f64, _y: f64) {}
 
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {}
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(
+        &mut self,
+        _image: &Image,
+        _x: f64,
+        _y: f64,
+        _width: f64,
+        _height: f64,
+    ) {
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, _image: &Image, _clip: Rectangle, _x: f64, _y: f64) {}
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        _image: &Image,
+        _clip: Rectangle,
+        _x: f64,
+        _y: f64,
+        _width: f64,
+        _height: f64,
+    ) {
+    }
+
     pub fn draw_pipeline(
         &mut self,
         _x: f64,
@@ -390,6 +490,26 @@ impl RenderContext2D {
     ) {
     }
 
+    /// Multiplies the current transformation with the given one.
+    pub fn transform(
+        &mut self,
+        _h_scaling: f64,
+        _h_skewing: f64,
+        _v_skewing: f64,
+        _v_scaling: f64,
+        _h_moving: f64,
+        _v_moving: f64,
+    ) {
+    }
+
+    // Layers
+
+    /// Redirects all following drawing operations to an offscreen layer until `pop_layer` is called.
+    pub fn push_layer(&mut self, _opacity: f32, _rect: Rectangle) {}
+
+    /// Draws the most recently pushed layer on its parent.
+    pub fn pop_layer(&mut self) {}
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
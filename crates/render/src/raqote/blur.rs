@@ -0,0 +1,121 @@
+// Blurs the given premultiplied argb pixels with a gaussian kernel of the given standard deviation.
+// Pixels outside of the image are handled as transparent.
+pub fn gaussian_blur(data: &mut [u32], width: usize, height: usize, sigma: f64) {
+    if sigma <= 0.0 || width == 0 || height == 0 || data.len() < width * height {
+        return;
+    }
+
+    let kernel = kernel(sigma);
+    let mut buffer = vec![0; width * height];
+
+    // the blur is separable, first blur the rows and then the columns
+    blur_pass(data, &mut buffer, width, height, &kernel, true);
+    blur_pass(&buffer, data, width, height, &kernel, false);
+}
+
+// Calculates the normalized weights of the kernel, index 0 is the center.
+fn kernel(sigma: f64) -> Vec<f32> {
+    let radius = (sigma * 3.0).ceil() as usize;
+    let mut kernel: Vec<f32> = (0..=radius)
+        .map(|i| (-((i * i) as f64) / (2.0 * sigma * sigma)).exp() as f32)
+        .collect();
+
+    let sum = kernel[0] + 2.0 * kernel.iter().skip(1).sum::<f32>();
+
+    for weight in kernel.iter_mut() {
+        *weight /= sum;
+    }
+
+    kernel
+}
+
+fn blur_pass(
+    source: &[u32],
+    target: &mut [u32],
+    width: usize,
+    height: usize,
+    kernel: &[f32],
+    horizontal: bool,
+) {
+    let (lines, length) = if horizontal {
+        (height, width)
+    } else {
+        (width, height)
+    };
+
+    let index = |line: usize, i: usize| {
+        if horizontal {
+            line * width + i
+        } else {
+            i * width + line
+        }
+    };
+
+    let radius = kernel.len() as isize - 1;
+
+    for line in 0..lines {
+        for i in 0..length {
+            let mut channels = [0.0_f32; 4];
+
+            for k in -radius..=radius {
+                let j = i as isize + k;
+
+                if j < 0 || j >= length as isize {
+                    continue;
+                }
+
+                let pixel = source[index(line, j as usize)];
+
+                if pixel == 0 {
+                    continue;
+                }
+
+                let weight = kernel[k.unsigned_abs()];
+
+                for (c, channel) in channels.iter_mut().enumerate() {
+                    *channel += ((pixel >> (c * 8)) & 0xFF) as f32 * weight;
+                }
+            }
+
+            target[index(line, i)] = channels.iter().enumerate().fold(0, |pixel, (c, channel)| {
+                pixel | ((channel.round().min(255.0) as u32) << (c * 8))
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernel() {
+        let kernel = kernel(2.0);
+        assert_eq!(kernel.len(), 7);
+
+        let sum = kernel[0] + 2.0 * kernel.iter().skip(1).sum::<f32>();
+        assert!((sum - 1.0).abs() < 0.0001);
+        assert!(kernel.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn test_gaussian_blur() {
+        let mut data = vec![0; 9 * 9];
+        data[4 * 9 + 4] = 0xFF00_0000;
+
+        gaussian_blur(&mut data, 9, 9, 1.0);
+
+        // the dot is spread symmetrically and the center keeps the highest alpha
+        let alpha = |x: usize, y: usize| data[y * 9 + x] >> 24;
+        assert!(alpha(4, 4) > alpha(3, 4));
+        assert_eq!(alpha(3, 4), alpha(5, 4));
+        assert_eq!(alpha(4, 3), alpha(4, 5));
+        assert_eq!(alpha(3, 4), alpha(4, 3));
+        assert_eq!(data[4 * 9 + 4] & 0x00FF_FFFF, 0);
+
+        // a blur with a radius of zero does not change the data
+        let mut data = vec![0xFF00_0000, 0];
+        gaussian_blur(&mut data, 2, 1, 0.0);
+        assert_eq!(data, vec![0xFF00_0000, 0]);
+    }
+}
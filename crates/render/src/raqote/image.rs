@@ -1,11 +1,15 @@
-use std::{fmt, path::Path};
+use std::{fmt, fs::File, io::BufReader, path::Path, sync::Arc, time::Duration};
 
-use crate::RenderTarget;
+use image::AnimationDecoder;
+
+use crate::{utils::Color, RenderTarget};
 
 #[derive(Clone, Default)]
 pub struct Image {
     render_target: RenderTarget,
     source: String,
+    // document of a vector image, kept to rasterize it again at another size
+    svg: Option<Arc<Vec<u8>>>,
 }
 
 impl fmt::Debug for Image {
@@ -17,6 +21,11 @@ impl fmt::Debug for Image {
 impl std::cmp::PartialEq for Image {
     fn eq(&self, other: &Self) -> bool {
         self.source == other.source
+            && self.svg == other.svg
+            // images without source are only equal if they have the same content
+            && (!self.source.is_empty()
+                || (self.render_target == other.render_target
+                    && self.render_target.data == other.render_target.data))
     }
 }
 
@@ -26,6 +35,7 @@ impl Image {
         Image {
             render_target: RenderTarget::new(width, height),
             source: String::default(),
+            svg: None,
         }
     }
 
@@ -39,6 +49,7 @@ impl Image {
         Ok(Image {
             render_target: RenderTarget::from_data(width, height, data).unwrap(),
             source: String::new(),
+            svg: None,
         })
     }
 
@@ -53,17 +64,119 @@ impl Image {
         Self::from_data(image.width(), image.height(), data)
     }
 
-    /// Load an image from file path. Supports BMP and PNG
+    /// Creates a new image from the data of a svg document. The image is rasterized at the size
+    /// of the document and could be rasterized again at any other size without losing quality.
+    pub fn from_svg_data(data: &[u8]) -> Result<Self, String> {
+        let size = svg_tree(data)?.svg_node().size;
+
+        let mut image = Image {
+            render_target: RenderTarget::new(0, 0),
+            source: String::new(),
+            svg: Some(Arc::new(data.to_vec())),
+        };
+        image.rasterize(size.width().ceil() as u32, size.height().ceil() as u32)?;
+
+        Ok(image)
+    }
+
+    /// Load a svg image from file path.
+    pub fn from_svg_path<P: AsRef<Path> + std::fmt::Debug + Clone>(
+        path: P,
+    ) -> Result<Self, String> {
+        let data = std::fs::read(path.clone())
+            .map_err(|_| format!("Could not load svg image width path: {:?}", path))?;
+
+        let mut image = Self::from_svg_data(&data)?;
+        image.source = path.as_ref().to_string_lossy().to_string();
+
+        Ok(image)
+    }
+
+    /// Load an image from file path. Supports BMP, PNG and SVG
     pub fn from_path<P: AsRef<Path> + std::fmt::Debug + Clone>(path: P) -> Result<Self, String> {
+        let is_svg = path
+            .as_ref()
+            .extension()
+            .map(|extension| extension.eq_ignore_ascii_case("svg"))
+            .unwrap_or(false);
+
+        if is_svg {
+            return Self::from_svg_path(path);
+        }
+
         let img = image::open(path.clone());
 
         if let Ok(img) = img {
-            return Self::from_rgba_image(img.to_rgba());
+            let mut image = Self::from_rgba_image(img.to_rgba())?;
+            image.source = path.as_ref().to_string_lossy().to_string();
+            return Ok(image);
         }
 
         Err(format!("Could not load image width path: {:?}", path))
     }
 
+    /// Loads all frames of an animated GIF or PNG image together with the time each frame is
+    /// shown. Other images are loaded as a single frame.
+    pub fn frames_from_path<P: AsRef<Path> + std::fmt::Debug + Clone>(
+        path: P,
+    ) -> Result<Vec<(Self, Duration)>, String> {
+        let error =
+            |e: image::ImageError| format!("Could not load image width path: {:?}, {}", path, e);
+        let reader = || {
+            File::open(path.as_ref())
+                .map(BufReader::new)
+                .map_err(|_| format!("Could not load image width path: {:?}", path))
+        };
+
+        let extension = path
+            .as_ref()
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        let frames = match extension.as_str() {
+            "gif" => image::gif::GifDecoder::new(reader()?)
+                .and_then(|decoder| decoder.into_frames().collect_frames())
+                .map_err(error)?,
+            "png" => {
+                let decoder = image::png::PngDecoder::new(reader()?).map_err(error)?;
+
+                if !decoder.is_apng() {
+                    return Ok(vec![(Self::from_path(path)?, Duration::default())]);
+                }
+
+                decoder
+                    .apng()
+                    .into_frames()
+                    .collect_frames()
+                    .map_err(error)?
+            }
+            _ => return Ok(vec![(Self::from_path(path)?, Duration::default())]),
+        };
+
+        let source = path.as_ref().to_string_lossy().to_string();
+
+        frames
+            .into_iter()
+            .enumerate()
+            .map(|(index, frame)| {
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+                let mut delay =
+                    Duration::from_micros(numerator as u64 * 1000 / denominator.max(1) as u64);
+
+                // like browsers frames without a proper delay are shown for 100 milliseconds
+                if delay <= Duration::from_millis(10) {
+                    delay = Duration::from_millis(100);
+                }
+
+                let mut image = Self::from_rgba_image(frame.into_buffer())?;
+                image.source = format!("{}#{}", source, index);
+
+                Ok((image, delay))
+            })
+            .collect()
+    }
+
     /// Gets the width.
     pub fn width(&self) -> f64 {
         self.render_target.width() as f64
@@ -74,6 +187,129 @@ impl Image {
         self.render_target.height() as f64
     }
 
+    /// Checks if the image is rasterized from a svg document.
+    pub fn is_svg(&self) -> bool {
+        self.svg.is_some()
+    }
+
+    /// Rasterizes a svg image again with the given size, e.g. if the image is drawn with another
+    /// size or on a display with another scale factor. The document is scaled uniformly to fit
+    /// into the size. Images that are not loaded from a svg document are not changed.
+    pub fn rasterize(&mut self, width: u32, height: u32) -> Result<(), String> {
+        let svg = match &self.svg {
+            Some(svg) => svg.clone(),
+            None => return Ok(()),
+        };
+
+        if self.render_target.width() as u32 == width
+            && self.render_target.height() as u32 == height
+        {
+            return Ok(());
+        }
+
+        if width == 0 || height == 0 {
+            self.render_target = RenderTarget::new(width, height);
+            return Ok(());
+        }
+
+        let tree = svg_tree(&svg)?;
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)
+            .ok_or_else(|| format!("Could not create svg image with size {}x{}", width, height))?;
+
+        resvg::render(&tree, usvg::FitTo::Size(width, height), pixmap.as_mut())
+            .ok_or_else(|| "Could not rasterize svg image".to_string())?;
+
+        // tiny-skia and raqote both use premultiplied colors
+        let data = pixmap
+            .data()
+            .chunks_exact(4)
+            .map(|p| {
+                ((p[3] as u32) << 24) | ((p[0] as u32) << 16) | ((p[1] as u32) << 8) | (p[2] as u32)
+            })
+            .collect();
+
+        self.render_target = RenderTarget::from_data(width, height, data)?;
+
+        Ok(())
+    }
+
+    /// Returns a copy of the image that is scaled to the given size. Each pixel is the average of
+    /// the pixels it covers, so images that are drawn smaller than their natural size keep
+    /// their details. Svg images are rasterized again with the given size.
+    pub fn resized(&self, width: u32, height: u32) -> Self {
+        let mut image = self.clone();
+
+        if self.svg.is_some() {
+            if image.rasterize(width, height).is_err() {
+                image.render_target = RenderTarget::new(width, height);
+            }
+
+            return image;
+        }
+
+        let source_width = self.render_target.width() as u32;
+        let source_height = self.render_target.height() as u32;
+
+        if source_width == width && source_height == height {
+            return image;
+        }
+
+        if source_width == 0 || source_height == 0 || width == 0 || height == 0 {
+            image.render_target = RenderTarget::new(width, height);
+            return image;
+        }
+
+        let columns = coverage(source_width, width);
+        let rows = coverage(source_height, height);
+        let mut data = Vec::with_capacity((width * height) as usize);
+
+        for row in &rows {
+            for column in &columns {
+                let mut channels = [0.0; 4];
+
+                for &(y, row_weight) in row {
+                    for &(x, column_weight) in column {
+                        let pixel = self.render_target.data[y * source_width as usize + x];
+
+                        for (index, channel) in channels.iter_mut().enumerate() {
+                            *channel += ((pixel >> (24 - index * 8)) & 0xFF) as f64
+                                * row_weight
+                                * column_weight;
+                        }
+                    }
+                }
+
+                data.push(
+                    channels
+                        .iter()
+                        .enumerate()
+                        .fold(0, |pixel, (index, channel)| {
+                            pixel | ((channel.round().min(255.0) as u32) << (24 - index * 8))
+                        }),
+                );
+            }
+        }
+
+        image.render_target = RenderTarget::from_data(width, height, data)
+            .unwrap_or_else(|_| RenderTarget::new(width, height));
+
+        image
+    }
+
+    /// Replaces the color of each pixel by the given color and keeps its alpha, e.g. to tint a
+    /// monochrome icon.
+    pub fn tint(&mut self, color: Color) {
+        for pixel in self.render_target.data.iter_mut() {
+            let alpha = (*pixel >> 24) * color.a() as u32 / 255;
+            let premultiply = |c: u8| c as u32 * alpha / 255;
+
+            *pixel = (alpha << 24)
+                | (premultiply(color.r()) << 16)
+                | (premultiply(color.g()) << 8)
+                | premultiply(color.b());
+        }
+    }
+
     pub fn data(&self) -> &[u32] {
         &self.render_target.data
     }
@@ -89,6 +325,32 @@ impl From<(u32, u32, Vec<u32>)> for Image {
     }
 }
 
+// Returns for each pixel of a line scaled to the target length the pixels of the source line it
+// covers, each with the part of the pixel that is covered.
+fn coverage(source: u32, target: u32) -> Vec<Vec<(usize, f64)>> {
+    let ratio = source as f64 / target as f64;
+
+    (0..target)
+        .map(|index| {
+            let start = index as f64 * ratio;
+            let end = start + ratio;
+
+            (start.floor() as usize..(end.ceil() as usize).min(source as usize))
+                .map(|pixel| {
+                    let covered = end.min(pixel as f64 + 1.0) - start.max(pixel as f64);
+                    (pixel, covered / ratio)
+                })
+                .filter(|(_, weight)| *weight > 0.0)
+                .collect()
+        })
+        .collect()
+}
+
+fn svg_tree(data: &[u8]) -> Result<usvg::Tree, String> {
+    usvg::Tree::from_data(data, &usvg::Options::default())
+        .map_err(|e| format!("Could not parse svg image: {}", e))
+}
+
 pub fn os_path(path: String) -> String {
     if cfg!(windows) {
         path.replace("/", "\\")
@@ -112,3 +374,67 @@ impl From<String> for Image {
 }
 
 // --- Conversions ---
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let svg =
+            br##"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2" viewBox="0 0 4 2">
+            <rect width="4" height="2" fill="#ff0000"/>
+        </svg>"##;
+
+        let mut image = Image::from_svg_data(svg).unwrap();
+        assert!(image.is_svg());
+        assert_eq!((image.width(), image.height()), (4.0, 2.0));
+        assert_eq!(image.data()[0], 0xFFFF_0000);
+
+        image.rasterize(8, 4).unwrap();
+        assert_eq!((image.width(), image.height()), (8.0, 4.0));
+        assert_eq!(image.data().len(), 32);
+
+        assert!(Image::from_svg_data(b"no svg").is_err());
+        assert!(!Image::new(1, 1).is_svg());
+    }
+
+    #[test]
+    fn test_resized() {
+        let image = Image::from_data(
+            4,
+            2,
+            vec![
+                0xFF00_0000,
+                0xFF00_0080,
+                0xFFFF_FFFF,
+                0xFFFF_FFFF,
+                0xFF00_0000,
+                0xFF00_0080,
+                0x0000_0000,
+                0x0000_0000,
+            ],
+        )
+        .unwrap();
+
+        let resized = image.resized(2, 1);
+        assert_eq!((resized.width(), resized.height()), (2.0, 1.0));
+        assert_eq!(resized.data(), &[0xFF00_0040, 0x8080_8080]);
+
+        let resized = image.resized(8, 2);
+        assert_eq!(
+            resized.data()[0..4],
+            [0xFF00_0000, 0xFF00_0000, 0xFF00_0080, 0xFF00_0080]
+        );
+
+        assert_eq!(image.resized(0, 0).data().len(), 0);
+    }
+
+    #[test]
+    fn test_tint() {
+        let mut image = Image::from_data(2, 1, vec![0xFF00_0000, 0x8012_3456]).unwrap();
+        image.tint(Color::rgb(255, 255, 255));
+
+        assert_eq!(image.data(), &[0xFFFF_FFFF, 0x8080_8080]);
+    }
+}
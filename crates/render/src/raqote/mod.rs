@@ -6,10 +6,11 @@ use crate::{common::*, utils::*, PipelineTrait, RenderConfig, RenderTarget, Text
 pub use self::font::*;
 pub use self::image::Image;
 
+mod blur;
 mod font;
 mod image;
 
-type StatesOnStack = [(RenderConfig, PathRect, usize); 2];
+type StatesOnStack = [(RenderConfig, PathRect, usize, Matrix); 2];
 
 /// The RenderContext2D trait, provides the rendering ctx. It is used for drawing shapes, text, images, and other objects.
 pub struct RenderContext2D {
@@ -20,6 +21,13 @@ pub struct RenderContext2D {
     fonts: HashMap<String, Font>,
     path_rect: PathRect,
     clips_count: usize,
+    transform: Matrix,
+    // pushed draw targets with the opacity, the clips count and the pixel origin of the layer
+    layers: Vec<(raqote::DrawTarget, f32, usize, (i32, i32))>,
+    // pixel position of the current draw target in the window
+    origin: (i32, i32),
+    size: (f64, f64),
+    scale_factor: f64,
 
     background: Color,
 }
@@ -38,6 +46,11 @@ impl RenderContext2D {
             fonts: HashMap::new(),
             path_rect: PathRect::new(None),
             clips_count: 0,
+            transform: Matrix::identity(),
+            layers: vec![],
+            origin: (0, 0),
+            size: (width, height),
+            scale_factor: 1.0,
             background: Color::default(),
         }
     }
@@ -47,8 +60,31 @@ impl RenderContext2D {
         self.background = background;
     }
 
+    /// Resizes the render context to the given logical size. The pixel size of the render context
+    /// is the logical size multiplied by the scale factor.
     pub fn resize(&mut self, width: f64, height: f64) {
-        self.draw_target = raqote::DrawTarget::new(width as i32, height as i32);
+        self.size = (width, height);
+        self.draw_target = raqote::DrawTarget::new(
+            (width * self.scale_factor).round() as i32,
+            (height * self.scale_factor).round() as i32,
+        );
+        self.apply_transform();
+    }
+
+    /// Sets the scale factor, the number of physical pixels per logical unit e.g. `2.0` on HiDPI
+    /// screens. All drawing operations use logical units and are scaled by this factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor <= 0.0 || (scale_factor - self.scale_factor).abs() < f64::EPSILON {
+            return;
+        }
+
+        self.scale_factor = scale_factor;
+        self.resize(self.size.0, self.size.1);
+    }
+
+    /// Gets the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
     }
 
     /// Registers a new font file.
@@ -105,19 +141,32 @@ impl RenderContext2D {
             return;
         }
 
+        if !self.transform.is_identity() {
+            self.fill_transformed_text(text, x, y, color);
+            return;
+        }
+
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
             let width = self.draw_target.width() as f64;
             let height = self.draw_target.height() as f64;
 
+            // the text is rendered directly in physical pixels to keep it sharp
+            let scale = self.scale_factor;
+            let font_size = self.config.font_config.font_size * scale;
+            let (origin_x, origin_y) = (self.origin.0 as f64, self.origin.1 as f64);
+
             if let Some(rect) = self.path_rect.get_clip() {
                 font.render_text_clipped(
                     text,
                     self.draw_target.get_data_mut(),
                     width,
                     height,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
-                    rect,
+                    (font_size, color, self.config.alpha),
+                    (x * scale - origin_x, y * scale - origin_y),
+                    Rectangle::new(
+                        (rect.x() * scale - origin_x, rect.y() * scale - origin_y),
+                        (rect.width() * scale, rect.height() * scale),
+                    ),
                 );
             } else {
                 font.render_text(
@@ -125,13 +174,52 @@ impl RenderContext2D {
                     self.draw_target.get_data_mut(),
                     width,
                     height,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
+                    (font_size, color, self.config.alpha),
+                    (x * scale - origin_x, y * scale - origin_y),
                 );
             }
         }
     }
 
+    // Renders the text on a temporary target that is drawn as image, so the current transformation and clip are applied.
+    fn fill_transformed_text(&mut self, text: &str, x: f64, y: f64, color: Color) {
+        if let Some(font) = self.fonts.get(&self.config.font_config.family) {
+            // the text is rendered in physical pixels and drawn scaled down by the scale factor
+            let scale = self.scale_factor;
+            let font_size = self.config.font_config.font_size * scale;
+            let (width, height) = font.measure_text(text, font_size);
+            let width = width + 1.0;
+
+            let mut text_target = raqote::DrawTarget::new(width as i32, height as i32);
+
+            font.render_text(
+                text,
+                text_target.get_data_mut(),
+                width,
+                height,
+                (font_size, color, self.config.alpha),
+                (0.0, 0.0),
+            );
+
+            self.set_draw_target_transform(
+                Matrix::scaling(1.0 / scale, 1.0 / scale)
+                    .then(&self.transform)
+                    .then(&self.pixel_transform()),
+            );
+            self.draw_target.draw_image_at(
+                (x * scale) as f32,
+                (y * scale) as f32,
+                &raqote::Image {
+                    data: text_target.get_data(),
+                    width: width as i32,
+                    height: height as i32,
+                },
+                &raqote::DrawOptions::default(),
+            );
+            self.apply_transform();
+        }
+    }
+
     pub fn measure(
         &mut self,
         text: &str,
@@ -313,6 +401,92 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the image scaled to the given width and height, e.g. an image that is rasterized in
+    /// physical pixels for the current scale factor.
+    pub fn draw_image_with_size(&mut self, image: &Image, x: f64, y: f64, width: f64, height: f64) {
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let scale_x = image.width() / width;
+        let scale_y = image.height() / height;
+
+        self.draw_target.fill_rect(
+            x as f32,
+            y as f32,
+            width as f32,
+            height as f32,
+            &raqote::Source::Image(
+                raqote::Image {
+                    data: &image.data(),
+                    width: image.width() as i32,
+                    height: image.height() as i32,
+                },
+                raqote::ExtendMode::Pad,
+                raqote::FilterMode::Bilinear,
+                raqote::Transform::row_major(
+                    scale_x as f32,
+                    0.0,
+                    0.0,
+                    scale_y as f32,
+                    (-x * scale_x) as f32,
+                    (-y * scale_y) as f32,
+                ),
+            ),
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+    }
+
+    /// Draws the given part of the image scaled to the given width and height, e.g. a slice of
+    /// a nine-slice image.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        if width <= 0.0 || height <= 0.0 || clip.width() <= 0.0 || clip.height() <= 0.0 {
+            return;
+        }
+
+        let scale_x = clip.width() / width;
+        let scale_y = clip.height() / height;
+
+        self.draw_target.fill_rect(
+            x as f32,
+            y as f32,
+            width as f32,
+            height as f32,
+            &raqote::Source::Image(
+                raqote::Image {
+                    data: &image.data(),
+                    width: image.width() as i32,
+                    height: image.height() as i32,
+                },
+                raqote::ExtendMode::Pad,
+                raqote::FilterMode::Bilinear,
+                raqote::Transform::row_major(
+                    scale_x as f32,
+                    0.0,
+                    0.0,
+                    scale_y as f32,
+                    (clip.x() - x * scale_x) as f32,
+                    (clip.y() - y * scale_y) as f32,
+                ),
+            ),
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
         let mut y = y as i32;
@@ -356,6 +530,123 @@ impl RenderContext2D {
         self.draw_render_target(&render_target, x, y);
     }
 
+    /// Draws the shadow of a box with the given bounds and corner radius. The shadow is blurred
+    /// with a gaussian blur. Outer shadows are drawn only outside and inset shadows only inside of the box.
+    pub fn draw_box_shadow(
+        &mut self,
+        rect: Rectangle,
+        radius: CornerRadius,
+        box_shadow: &BoxShadow,
+    ) {
+        if !box_shadow.is_visible() || rect.width() <= 0.0 || rect.height() <= 0.0 {
+            return;
+        }
+
+        let inset = box_shadow.inset();
+        let sigma = box_shadow.blur() / 2.0;
+        let extent = (sigma * 3.0).ceil();
+
+        // inset shadows shrink the hole of the shadow by the spread
+        let spread = if inset {
+            -box_shadow.spread()
+        } else {
+            box_shadow.spread()
+        };
+
+        let shape = Rectangle::new(
+            (
+                rect.x() + box_shadow.offset().x() - spread,
+                rect.y() + box_shadow.offset().y() - spread,
+            ),
+            (
+                (rect.width() + 2.0 * spread).max(0.0),
+                (rect.height() + 2.0 * spread).max(0.0),
+            ),
+        );
+        let shape_radius = radius.spread(spread);
+
+        let area = if inset { rect } else { shape };
+        let area = Rectangle::new(
+            (area.x() - extent, area.y() - extent),
+            (area.width() + 2.0 * extent, area.height() + 2.0 * extent),
+        );
+        let width = area.width().ceil() as i32;
+        let height = area.height().ceil() as i32;
+
+        // raqote expects premultiplied colors
+        let color = box_shadow.color();
+        let premultiply = |c: u8| (c as u32 * color.a() as u32 / 255) as u8;
+
+        let mut shadow_target = raqote::DrawTarget::new(width, height);
+        let mut path_builder = raqote::PathBuilder::new();
+
+        if inset {
+            path_builder.rect(0.0, 0.0, width as f32, height as f32);
+        }
+
+        rounded_rect(
+            &mut path_builder,
+            Rectangle::new(
+                (shape.x() - area.x(), shape.y() - area.y()),
+                (shape.width(), shape.height()),
+            ),
+            shape_radius,
+        );
+
+        let mut path = path_builder.finish();
+        path.winding = raqote::Winding::EvenOdd;
+
+        shadow_target.fill(
+            &path,
+            &raqote::Source::Solid(raqote::SolidSource {
+                r: premultiply(color.r()),
+                g: premultiply(color.g()),
+                b: premultiply(color.b()),
+                a: color.a(),
+            }),
+            &raqote::DrawOptions::default(),
+        );
+
+        blur::gaussian_blur(
+            shadow_target.get_data_mut(),
+            width as usize,
+            height as usize,
+            sigma,
+        );
+
+        let mut clip_builder = raqote::PathBuilder::new();
+
+        if !inset {
+            clip_builder.rect(
+                area.x() as f32,
+                area.y() as f32,
+                width as f32,
+                height as f32,
+            );
+        }
+
+        rounded_rect(&mut clip_builder, rect, radius);
+
+        let mut clip = clip_builder.finish();
+        clip.winding = raqote::Winding::EvenOdd;
+
+        self.draw_target.push_clip(&clip);
+        self.draw_target.draw_image_at(
+            area.x() as f32,
+            area.y() as f32,
+            &raqote::Image {
+                data: shadow_target.get_data(),
+                width,
+                height,
+            },
+            &raqote::DrawOptions {
+                alpha: self.config.alpha,
+                ..Default::default()
+            },
+        );
+        self.draw_target.pop_clip();
+    }
+
     /// Creates a clipping path from the current sub-paths. Everything drawn after clip() is called appears inside the clipping path only.
     pub fn clip(&mut self) {
         self.draw_target.push_clip(&self.path);
@@ -409,31 +700,139 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        self.transform = Matrix::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        );
+        self.apply_transform();
+    }
+
+    /// Multiplies the current transformation with the given one.
+    pub fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        self.transform = Matrix::new(
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        )
+        .then(&self.transform);
+        self.apply_transform();
+    }
+
+    // Maps logical window coordinates to the pixels of the current draw target.
+    fn pixel_transform(&self) -> Matrix {
+        Matrix::scaling(self.scale_factor, self.scale_factor).then(&Matrix::translation(
+            -self.origin.0 as f64,
+            -self.origin.1 as f64,
+        ))
+    }
+
+    // Applies the current transformation followed by the scale factor to the draw target.
+    fn apply_transform(&mut self) {
+        self.set_draw_target_transform(self.transform.then(&self.pixel_transform()));
+    }
+
+    fn set_draw_target_transform(&mut self, matrix: Matrix) {
         self.draw_target
             .set_transform(&raqote::Transform::row_major(
-                h_scaling as f32,
-                h_skewing as f32,
-                v_skewing as f32,
-                v_scaling as f32,
-                h_moving as f32,
-                v_moving as f32,
+                matrix.m11 as f32,
+                matrix.m12 as f32,
+                matrix.m21 as f32,
+                matrix.m22 as f32,
+                matrix.dx as f32,
+                matrix.dy as f32,
             ));
     }
 
+    // Layers
+
+    /// Redirects all following drawing operations to an offscreen layer until `pop_layer` is called.
+    /// The layer covers the given rectangle, drawings outside of it are dropped. It is drawn as a
+    /// whole with the given opacity.
+    pub fn push_layer(&mut self, opacity: f32, rect: Rectangle) {
+        // pixel bounds of the transformed rectangle inside of the current draw target
+        let matrix = self.transform.then(&self.pixel_transform());
+        let corners = [
+            matrix.transform_point(Point::new(rect.x(), rect.y())),
+            matrix.transform_point(Point::new(rect.x() + rect.width(), rect.y())),
+            matrix.transform_point(Point::new(rect.x(), rect.y() + rect.height())),
+            matrix.transform_point(Point::new(
+                rect.x() + rect.width(),
+                rect.y() + rect.height(),
+            )),
+        ];
+        let left = corners.iter().map(|c| c.x()).fold(f64::MAX, f64::min);
+        let top = corners.iter().map(|c| c.y()).fold(f64::MAX, f64::min);
+        let right = corners.iter().map(|c| c.x()).fold(f64::MIN, f64::max);
+        let bottom = corners.iter().map(|c| c.y()).fold(f64::MIN, f64::max);
+
+        let left = (left.floor() as i32).max(0);
+        let top = (top.floor() as i32).max(0);
+        let right = (right.ceil() as i32).min(self.draw_target.width());
+        let bottom = (bottom.ceil() as i32).min(self.draw_target.height());
+
+        let layer = raqote::DrawTarget::new((right - left).max(1), (bottom - top).max(1));
+        let parent = std::mem::replace(&mut self.draw_target, layer);
+        self.layers
+            .push((parent, opacity, self.clips_count, self.origin));
+        self.clips_count = 0;
+        self.origin = (self.origin.0 + left, self.origin.1 + top);
+        self.apply_transform();
+    }
+
+    /// Draws the most recently pushed layer on its parent.
+    /// If there is no pushed layer, this method does nothing.
+    pub fn pop_layer(&mut self) {
+        if let Some((parent, opacity, clips_count, origin)) = self.layers.pop() {
+            let layer = std::mem::replace(&mut self.draw_target, parent);
+            let (x, y) = (self.origin.0 - origin.0, self.origin.1 - origin.1);
+            self.clips_count = clips_count;
+            self.origin = origin;
+
+            // the content of the layer is already transformed
+            self.draw_target
+                .set_transform(&raqote::Transform::identity());
+            self.draw_target.draw_image_at(
+                x as f32,
+                y as f32,
+                &raqote::Image {
+                    data: layer.get_data(),
+                    width: layer.width(),
+                    height: layer.height(),
+                },
+                &raqote::DrawOptions {
+                    alpha: opacity,
+                    ..Default::default()
+                },
+            );
+            self.apply_transform();
+        }
+    }
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
     pub fn save(&mut self) {
-        self.saved_states
-            .push((self.config.clone(), self.path_rect, self.clips_count));
+        self.saved_states.push((
+            self.config.clone(),
+            self.path_rect,
+            self.clips_count,
+            self.transform,
+        ));
     }
 
     /// Restores the most recently saved canvas state by popping the top entry in the drawing state stack.
     /// If there is no saved state, this method does nothing.
     pub fn restore(&mut self) {
-        if let Some((config, path_rect, former_clips_count)) = self.saved_states.pop() {
+        if let Some((config, path_rect, former_clips_count, transform)) = self.saved_states.pop() {
             self.config = config;
             self.path_rect = path_rect;
+            self.transform = transform;
+            self.apply_transform();
             for _ in former_clips_count..self.clips_count {
                 self.draw_target.pop_clip();
             }
@@ -479,6 +878,37 @@ impl RenderContext2D {
     pub fn finish(&mut self) {}
 }
 
+// Adds a rectangle with rounded corners to the path, the corners are drawn like the ones of the
+// `RectangleRenderObject`.
+fn rounded_rect(path_builder: &mut raqote::PathBuilder, rect: Rectangle, radius: CornerRadius) {
+    let radius = radius.fit(rect.size());
+    let x = rect.x() as f32;
+    let y = rect.y() as f32;
+    let r = (rect.x() + rect.width()) as f32;
+    let b = (rect.y() + rect.height()) as f32;
+
+    if radius.is_zero() {
+        path_builder.rect(x, y, r - x, b - y);
+        return;
+    }
+
+    let top_left = radius.top_left() as f32;
+    let top_right = radius.top_right() as f32;
+    let bottom_right = radius.bottom_right() as f32;
+    let bottom_left = radius.bottom_left() as f32;
+
+    path_builder.move_to(x + top_left, y);
+    path_builder.line_to(r - top_right, y);
+    path_builder.quad_to(r, y, r, y + top_right);
+    path_builder.line_to(r, b - bottom_right);
+    path_builder.quad_to(r, b, r - bottom_right, b);
+    path_builder.line_to(x + bottom_left, b);
+    path_builder.quad_to(x, b, x, b - bottom_left);
+    path_builder.line_to(x, y + top_left);
+    path_builder.quad_to(x, y, x + top_left, y);
+    path_builder.close();
+}
+
 fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
     match brush {
         Brush::SolidColor(color) => raqote::Source::Solid(raqote::SolidSource {
@@ -488,7 +918,7 @@ fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
             a: color.a(),
         }),
         Brush::Gradient(Gradient {
-            kind: GradientKind::Linear(coords),
+            kind,
             stops,
             repeat,
         }) => {
@@ -496,21 +926,43 @@ fn brush_to_source<'a>(brush: &Brush, frame: Rectangle) -> raqote::Source<'a> {
                 true => raqote::Spread::Repeat,
                 false => raqote::Spread::Pad,
             };
-            match coords {
-                LinearGradientCoords::Ends { start, end } => {
-                    let g_stops =
-                        build_unit_percent_gradient(&stops, end.distance(*start), |p, c| {
-                            raqote::GradientStop {
-                                position: p as f32,
-                                color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
-                            }
-                        });
-                    let start = frame.position() + *start;
-                    let end = frame.position() + *end;
+            let gradient = |length: f64| raqote::Gradient {
+                stops: build_unit_percent_gradient(&stops, length, |p, c| raqote::GradientStop {
+                    position: p as f32,
+                    color: raqote::Color::new(c.a(), c.r(), c.g(), c.b()),
+                }),
+            };
+            let point = |point: Point| {
+                let point = frame.position() + point;
+                raqote::Point::new(point.x() as f32, point.y() as f32)
+            };
+            match kind {
+                GradientKind::Linear(coords) => {
+                    let (start, end) = coords.ends(frame.size());
                     raqote::Source::new_linear_gradient(
-                        raqote::Gradient { stops: g_stops },
-                        raqote::Point::new(start.x() as f32, start.y() as f32),
-                        raqote::Point::new(end.x() as f32, end.y() as f32),
+                        gradient(end.distance(start)),
+                        point(start),
+                        point(end),
+                        spread,
+                    )
+                }
+                GradientKind::Radial(radial) => {
+                    let (center, radius) = radial.center_and_radius(frame.size());
+                    raqote::Source::new_radial_gradient(
+                        gradient(radius),
+                        point(center),
+                        radius as f32,
+                        spread,
+                    )
+                }
+                GradientKind::Conic(conic) => {
+                    // raqote starts the sweep at the positive x axis, css at the top
+                    let start_angle = conic.angle as f32 - 90.0;
+                    raqote::Source::new_sweep_gradient(
+                        gradient(360.0),
+                        point(conic.center.pixels(frame.size())),
+                        start_angle,
+                        start_angle + 360.0,
                         spread,
                     )
                 }
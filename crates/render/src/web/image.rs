@@ -1,9 +1,14 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 use stdweb::{js, unstable::TryInto};
 
+use crate::utils::Color;
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Image {
     pub source: String,
+    // size the image is drawn with, if not set the natural size of the image is used
+    pub size: Option<(u32, u32)>,
+    pub tint: Option<Color>,
 }
 
 impl Image {
@@ -11,9 +16,32 @@ impl Image {
     pub fn new(_: f64, _: f64) -> Self {
         Image {
             source: String::default(),
+            size: None,
+            tint: None,
         }
     }
 
+    /// Creates a new image from the data of a svg document. The browser rasterizes the
+    /// document at the size it is drawn.
+    pub fn from_svg_data(data: &[u8]) -> Result<Self, String> {
+        let mut source = String::from("data:image/svg+xml;charset=utf-8,");
+
+        for byte in data {
+            if byte.is_ascii_alphanumeric() || b" -_.~/=:;\"'<>".contains(byte) {
+                source.push(*byte as char);
+            } else {
+                source.push_str(&format!("%{:02X}", byte));
+            }
+        }
+
+        Self::from_path(source)
+    }
+
+    /// Load a svg image from file path.
+    pub fn from_svg_path<P: std::string::ToString + AsRef<Path>>(path: P) -> Result<Self, String> {
+        Self::from_path(path)
+    }
+
     /// Load an image from file path. Supports BMP and PNG extensions.
     pub fn from_path<P: std::string::ToString + AsRef<Path>>(path: P) -> Result<Self, String> {
         let source = path.to_string();
@@ -56,7 +84,49 @@ impl Image {
             document.image_store.load_image(@{&source});
         );
 
-        Ok(Image { source })
+        Ok(Image {
+            source,
+            size: None,
+            tint: None,
+        })
+    }
+
+    /// Loads the frames of an image together with the time each frame is shown. The browser
+    /// plays animated images by itself, so the image is loaded as a single frame.
+    pub fn frames_from_path<P: std::string::ToString + AsRef<Path>>(
+        path: P,
+    ) -> Result<Vec<(Self, Duration)>, String> {
+        Ok(vec![(Self::from_path(path)?, Duration::default())])
+    }
+
+    /// Checks if the image is a svg document.
+    pub fn is_svg(&self) -> bool {
+        self.source.starts_with("data:image/svg+xml")
+            || self.source.to_lowercase().ends_with(".svg")
+    }
+
+    /// Sets the size the svg image is drawn with. The browser rasterizes svg images at the size
+    /// they are drawn, so only the size is stored. Images that are not svg documents are not changed.
+    pub fn rasterize(&mut self, width: u32, height: u32) -> Result<(), String> {
+        if self.is_svg() {
+            self.size = Some((width, height));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a copy of the image that is drawn with the given size. The browser scales the
+    /// image with high quality smoothing.
+    pub fn resized(&self, width: u32, height: u32) -> Self {
+        let mut image = self.clone();
+        image.size = Some((width, height));
+        image
+    }
+
+    /// Replaces the color of each pixel by the given color and keeps its alpha, e.g. to tint a
+    /// monochrome icon.
+    pub fn tint(&mut self, color: Color) {
+        self.tint = Some(color);
     }
 
     /// Draws a u32 slice into the image.
@@ -66,6 +136,10 @@ impl Image {
 
     /// Gets the width.
     pub fn width(&self) -> f64 {
+        if let Some((width, _)) = self.size {
+            return width as f64;
+        }
+
         let width: u64 = js!(
             var image = document.image_store.image(@{&self.source});
 
@@ -82,6 +156,10 @@ impl Image {
 
     /// Gets the height.
     pub fn height(&self) -> f64 {
+        if let Some((_, height)) = self.size {
+            return height as f64;
+        }
+
         let height: u64 = js!(
             var image = document.image_store.image(@{&self.source});
 
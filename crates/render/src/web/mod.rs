@@ -26,6 +26,9 @@ pub struct RenderContext2D {
     saved_state: SmallVec<StatesOnStack>,
     path_rect: PathRect,
     export_data: Vec<u32>,
+    // pushed contexts with the opacity and the pixel origin of the layer
+    layers: Vec<(CanvasRenderingContext2d, f32, (f64, f64))>,
+    scale_factor: f64,
     background: Color,
 }
 
@@ -52,6 +55,8 @@ impl RenderContext2D {
             font_config: FontConfig::default(),
             path_rect: PathRect::new(None),
             export_data,
+            layers: vec![],
+            scale_factor: 1.0,
             background: Color::default(),
         }
     }
@@ -61,6 +66,29 @@ impl RenderContext2D {
         self.background = background;
     }
 
+    /// Sets the scale factor, the number of physical pixels per logical unit e.g. the device pixel
+    /// ratio of the browser. All drawing operations use logical units and are scaled by this factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        if scale_factor <= 0.0 {
+            return;
+        }
+
+        self.scale_factor = scale_factor;
+        self.canvas_render_context_2_d.set_transform(
+            scale_factor,
+            0.0,
+            0.0,
+            scale_factor,
+            0.0,
+            0.0,
+        );
+    }
+
+    /// Gets the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
     /// Creates a new render ctx 2d.
     pub fn from_context(canvas_render_context_2_d: CanvasRenderingContext2d) -> Self {
         let export_data = vec![
@@ -77,6 +105,8 @@ impl RenderContext2D {
             font_config: FontConfig::default(),
             path_rect: PathRect::new(None),
             export_data,
+            layers: vec![],
+            scale_factor: 1.0,
             background: Color::default(),
         }
     }
@@ -205,6 +235,123 @@ impl RenderContext2D {
             .record_bezier_curve_to(cp1x, cp1y, cp2x, cp2y, x, y);
     }
 
+    /// Draws the shadow of a box with the given bounds and corner radius. The canvas shadow of a
+    /// shape outside of the visible area is used to draw the blurred shadow.
+    pub fn draw_box_shadow(
+        &mut self,
+        rect: Rectangle,
+        radius: CornerRadius,
+        box_shadow: &BoxShadow,
+    ) {
+        if !box_shadow.is_visible() || rect.width() <= 0.0 || rect.height() <= 0.0 {
+            return;
+        }
+
+        // distance between the shape and its shadow
+        const SHADOW_SHIFT: f64 = 100_000.0;
+
+        let inset = box_shadow.inset();
+        let blur = box_shadow.blur();
+        let spread = if inset {
+            -box_shadow.spread()
+        } else {
+            box_shadow.spread()
+        };
+
+        let shape = Rectangle::new(
+            (
+                rect.x() + box_shadow.offset().x() - spread,
+                rect.y() + box_shadow.offset().y() - spread,
+            ),
+            (
+                (rect.width() + 2.0 * spread).max(0.0),
+                (rect.height() + 2.0 * spread).max(0.0),
+            ),
+        );
+        let shape_radius = radius.spread(spread);
+
+        let mut area = if inset { rect } else { shape };
+        area = Rectangle::new(
+            (area.x() - 2.0 * blur, area.y() - 2.0 * blur),
+            (area.width() + 4.0 * blur, area.height() + 4.0 * blur),
+        );
+        area.join_with_rectangle(&rect);
+        area.join_with_rectangle(&shape);
+
+        self.canvas_render_context_2_d.save();
+
+        // outer shadows are only visible outside and inset shadows only inside of the box
+        self.canvas_render_context_2_d.begin_path();
+        if !inset {
+            self.canvas_render_context_2_d
+                .rect(area.x(), area.y(), area.width(), area.height());
+        }
+        self.rounded_rect(rect, radius);
+        self.canvas_render_context_2_d.clip(FillRule::EvenOdd);
+
+        self.canvas_render_context_2_d.begin_path();
+        if inset {
+            self.canvas_render_context_2_d.rect(
+                area.x() - SHADOW_SHIFT,
+                area.y(),
+                area.width(),
+                area.height(),
+            );
+        }
+        self.rounded_rect(
+            Rectangle::new((shape.x() - SHADOW_SHIFT, shape.y()), shape.size()),
+            shape_radius,
+        );
+
+        let color = box_shadow.color();
+        let color = format!(
+            "rgba({}, {}, {}, {})",
+            color.r(),
+            color.g(),
+            color.b(),
+            color.a() as f64 / 255.0
+        );
+
+        // the shadow offset and blur are not transformed by the canvas
+        js!(
+            var ctx = @{&self.canvas_render_context_2_d};
+            var t = ctx.getTransform();
+            ctx.shadowColor = @{&color};
+            ctx.shadowBlur = @{blur} * Math.sqrt(t.a * t.a + t.b * t.b);
+            ctx.shadowOffsetX = @{SHADOW_SHIFT} * t.a;
+            ctx.shadowOffsetY = @{SHADOW_SHIFT} * t.b;
+            ctx.fillStyle = "#000000";
+        );
+        self.canvas_render_context_2_d.fill(FillRule::EvenOdd);
+
+        self.canvas_render_context_2_d.restore();
+        self.canvas_render_context_2_d.begin_path();
+    }
+
+    // Adds a rectangle with rounded corners to the current path.
+    fn rounded_rect(&self, rect: Rectangle, radius: CornerRadius) {
+        let ctx = &self.canvas_render_context_2_d;
+        let radius = radius.fit(rect.size());
+        let (x, y) = (rect.x(), rect.y());
+        let (r, b) = (rect.x() + rect.width(), rect.y() + rect.height());
+
+        if radius.is_zero() {
+            ctx.rect(x, y, rect.width(), rect.height());
+            return;
+        }
+
+        ctx.move_to(x + radius.top_left(), y);
+        ctx.line_to(r - radius.top_right(), y);
+        ctx.quadratic_curve_to(r, y, r, y + radius.top_right());
+        ctx.line_to(r, b - radius.bottom_right());
+        ctx.quadratic_curve_to(r, b, r - radius.bottom_right(), b);
+        ctx.line_to(x + radius.bottom_left(), b);
+        ctx.quadratic_curve_to(x, b, x, b - radius.bottom_left());
+        ctx.line_to(x, y + radius.top_left());
+        ctx.quadratic_curve_to(x, y, x + radius.top_left(), y);
+        ctx.close_path();
+    }
+
     // Draw image
 
     /// Draws a render target.
@@ -214,22 +361,66 @@ impl RenderContext2D {
 
     /// Draws the image.
     pub fn draw_image(&mut self, image: &Image, x: f64, y: f64) {
+        let (width, height) = image.size.unwrap_or((0, 0));
+        let tint = image
+            .tint
+            .map(|c| {
+                format!(
+                    "rgba({}, {}, {}, {})",
+                    c.r(),
+                    c.g(),
+                    c.b(),
+                    c.a() as f64 / 255.0
+                )
+            })
+            .unwrap_or_default();
+
         js!(
+            var ctx = @{&self.canvas_render_context_2_d};
+            var width = @{width};
+            var height = @{height};
+            var tint = @{&tint};
+            var scale = @{self.scale_factor};
+            ctx.imageSmoothingQuality = "high";
+
+            var draw = function(i) {
+                var w = width > 0 ? width : i.width;
+                var h = height > 0 ? height : i.height;
+
+                if(tint == "") {
+                    ctx.drawImage(i, @{&x}, @{&y}, w, h);
+                    return;
+                }
+
+                // the image is tinted on an offscreen canvas in physical pixels, only its alpha is kept
+                var canvas = document.createElement("canvas");
+                canvas.width = Math.ceil(w * scale);
+                canvas.height = Math.ceil(h * scale);
+                var tinted = canvas.getContext("2d");
+                tinted.drawImage(i, 0, 0, canvas.width, canvas.height);
+                tinted.globalCompositeOperation = "source-in";
+                tinted.fillStyle = tint;
+                tinted.fillRect(0, 0, canvas.width, canvas.height);
+                ctx.drawImage(canvas, @{&x}, @{&y}, w, h);
+            };
+
             var img = document.image_store.image(@{&image.source});
 
             if(img == null) {
-                img = document.image_store.load_image(@{&image.source});
-                img.then(
-                    function(i) {
-                         @{&self.canvas_render_context_2_d}.drawImage(i, @{&x}, @{&y});
-                    }
-                )
+                document.image_store.load_image(@{&image.source}).then(draw);
             } else {
-                @{&self.canvas_render_context_2_d}.drawImage(img, @{&x}, @{&y});
+                draw(img);
             }
         );
     }
 
+    /// Draws the image scaled to the given width and height.
+    pub fn draw_image_with_size(&mut self, image: &Image, x: f64, y: f64, width: f64, height: f64) {
+        let mut image = image.clone();
+        image.size = Some((width.round() as u32, height.round() as u32));
+        self.draw_image(&image, x, y);
+    }
+
     /// Draws the given part of the image.
     pub fn draw_image_with_clip(&mut self, image: &Image, clip: Rectangle, x: f64, y: f64) {
         js!(
@@ -248,6 +439,34 @@ impl RenderContext2D {
         );
     }
 
+    /// Draws the given part of the image scaled to the given width and height.
+    pub fn draw_image_with_clip_and_size(
+        &mut self,
+        image: &Image,
+        clip: Rectangle,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        js!(
+            var ctx = @{&self.canvas_render_context_2_d};
+
+            var draw = function(i) {
+                ctx.imageSmoothingQuality = "high";
+                ctx.drawImage(i, @{&clip.x()}, @{&clip.y()}, @{&clip.width()}, @{&clip.height()}, @{&x}, @{&y}, @{&width}, @{&height});
+            };
+
+            var img = document.image_store.image(@{&image.source});
+
+            if(img == null) {
+                document.image_store.load_image(@{&image.source}).then(draw);
+            } else {
+                draw(img);
+            }
+        );
+    }
+
     pub fn draw_pipeline(
         &mut self,
         x: f64,
@@ -364,11 +583,100 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        let scale = self.scale_factor;
         self.canvas_render_context_2_d.set_transform(
+            h_scaling * scale,
+            h_skewing * scale,
+            v_skewing * scale,
+            v_scaling * scale,
+            h_moving * scale,
+            v_moving * scale,
+        );
+    }
+
+    /// Multiplies the current transformation with the given one.
+    pub fn transform(
+        &mut self,
+        h_scaling: f64,
+        h_skewing: f64,
+        v_skewing: f64,
+        v_scaling: f64,
+        h_moving: f64,
+        v_moving: f64,
+    ) {
+        self.canvas_render_context_2_d.transform(
             h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
         );
     }
 
+    // Layers
+
+    /// Redirects all following drawing operations to an offscreen layer until `pop_layer` is called.
+    /// The layer covers the given rectangle, drawings outside of it are dropped. It is drawn as a
+    /// whole with the given opacity.
+    pub fn push_layer(&mut self, opacity: f32, rect: Rectangle) {
+        let canvas: CanvasElement = document()
+            .create_element("canvas")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
+
+        // the layer covers the pixel bounds of the transformed rectangle inside of the parent
+        let origin: Vec<f64> = js!(
+            var parent = @{&self.canvas_render_context_2_d};
+            var layer = @{&ctx};
+            var m = parent.getTransform();
+            var corners = [
+                m.transformPoint(new DOMPoint(@{rect.x()}, @{rect.y()})),
+                m.transformPoint(new DOMPoint(@{rect.x() + rect.width()}, @{rect.y()})),
+                m.transformPoint(new DOMPoint(@{rect.x()}, @{rect.y() + rect.height()})),
+                m.transformPoint(new DOMPoint(@{rect.x() + rect.width()}, @{rect.y() + rect.height()}))
+            ];
+            var xs = corners.map(function(c) { return c.x; });
+            var ys = corners.map(function(c) { return c.y; });
+            var left = Math.max(Math.floor(Math.min.apply(null, xs)), 0);
+            var top = Math.max(Math.floor(Math.min.apply(null, ys)), 0);
+            var right = Math.min(Math.ceil(Math.max.apply(null, xs)), parent.canvas.width);
+            var bottom = Math.min(Math.ceil(Math.max.apply(null, ys)), parent.canvas.height);
+            layer.canvas.width = Math.max(right - left, 1);
+            layer.canvas.height = Math.max(bottom - top, 1);
+            layer.setTransform(m.a, m.b, m.c, m.d, m.e - left, m.f - top);
+            layer.font = parent.font;
+            layer.textBaseline = parent.textBaseline;
+            layer.lineWidth = parent.lineWidth;
+            return [left, top];
+        )
+        .try_into()
+        .unwrap_or_default();
+
+        let origin = match origin[..] {
+            [x, y] => (x, y),
+            _ => (0.0, 0.0),
+        };
+        let parent = std::mem::replace(&mut self.canvas_render_context_2_d, ctx);
+        self.layers.push((parent, opacity, origin));
+    }
+
+    /// Draws the most recently pushed layer on its parent.
+    /// If there is no pushed layer, this method does nothing.
+    pub fn pop_layer(&mut self) {
+        if let Some((parent, opacity, origin)) = self.layers.pop() {
+            let layer = std::mem::replace(&mut self.canvas_render_context_2_d, parent);
+
+            // the content of the layer is already transformed
+            js!(
+                var ctx = @{&self.canvas_render_context_2_d};
+                ctx.save();
+                ctx.setTransform(1, 0, 0, 1, 0, 0);
+                ctx.globalAlpha = @{opacity as f64};
+                ctx.drawImage(@{&layer}.canvas, @{origin.0}, @{origin.1});
+                ctx.restore();
+            );
+        }
+    }
+
     // Canvas states
 
     /// Saves the entire state of the canvas by pushing the current state onto a stack.
@@ -411,6 +719,7 @@ impl RenderContext2D {
         canvas_render_context_2_d: CanvasRenderingContext2d,
     ) {
         self.canvas_render_context_2_d = canvas_render_context_2_d;
+        self.set_scale_factor(self.scale_factor);
     }
 
     pub fn data(&mut self) -> &[u32] {
@@ -486,13 +795,9 @@ impl RenderContext2D {
                     Some(frame) => frame,
                     None => return, // There are no path to fill
                 };
-                match gradient.kind {
-                    GradientKind::Linear(coords) => {
-                        let web_gradient = self.linear_gradient(&coords, &gradient.stops, frame);
-                        self.canvas_render_context_2_d
-                            .set_fill_style_gradient(&web_gradient);
-                    }
-                }
+                let web_gradient = self.gradient(gradient, frame);
+                self.canvas_render_context_2_d
+                    .set_fill_style_gradient(&web_gradient);
             }
         }
     }
@@ -508,44 +813,73 @@ impl RenderContext2D {
                     Some(frame) => frame,
                     None => return, // There are no path to fill
                 };
-                match gradient.kind {
-                    GradientKind::Linear(coords) => {
-                        let web_gradient = self.linear_gradient(&coords, &gradient.stops, frame);
-                        self.canvas_render_context_2_d
-                            .set_stroke_style_gradient(&web_gradient);
-                    }
-                }
+                let web_gradient = self.gradient(gradient, frame);
+                self.canvas_render_context_2_d
+                    .set_stroke_style_gradient(&web_gradient);
             }
         }
     }
 
-    fn linear_gradient(
-        &self,
-        coords: &LinearGradientCoords,
-        stops: &[GradientStop],
-        frame: Rectangle,
-    ) -> CanvasGradient {
-        match coords {
-            LinearGradientCoords::Ends { start, end } => {
-                let start = frame.position() + *start;
-                let end = frame.position() + *end;
-
-                let web_gradient = self.canvas_render_context_2_d.create_linear_gradient(
-                    start.x(),
-                    start.y(),
-                    end.x(),
-                    end.y(),
-                );
-
-                build_unit_percent_gradient(stops, end.distance(start), |p, c| {
-                    web_gradient
-                        .add_color_stop(p, c.to_string().as_str())
-                        .unwrap();
-                });
-
-                web_gradient
+    fn gradient(&self, gradient: &Gradient, frame: Rectangle) -> CanvasGradient {
+        let (web_gradient, length): (CanvasGradient, f64) = match gradient.kind {
+            GradientKind::Linear(coords) => {
+                let (start, end) = coords.ends(frame.size());
+                let start = frame.position() + start;
+                let end = frame.position() + end;
+
+                (
+                    self.canvas_render_context_2_d.create_linear_gradient(
+                        start.x(),
+                        start.y(),
+                        end.x(),
+                        end.y(),
+                    ),
+                    end.distance(start),
+                )
             }
-        }
+            GradientKind::Radial(radial) => {
+                let (center, radius) = radial.center_and_radius(frame.size());
+                let center = frame.position() + center;
+
+                (
+                    self.canvas_render_context_2_d
+                        .create_radial_gradient(
+                            center.x(),
+                            center.y(),
+                            0.0,
+                            center.x(),
+                            center.y(),
+                            radius,
+                        )
+                        .unwrap(),
+                    radius,
+                )
+            }
+            GradientKind::Conic(conic) => {
+                let center = frame.position() + conic.center.pixels(frame.size());
+
+                // the canvas starts the sweep at the positive x axis, css at the top
+                let start_angle = (conic.angle - 90.0).to_radians();
+
+                (
+                    js!(
+                        return @{&self.canvas_render_context_2_d}
+                            .createConicGradient(@{start_angle}, @{center.x()}, @{center.y()});
+                    )
+                    .try_into()
+                    .unwrap(),
+                    360.0,
+                )
+            }
+        };
+
+        build_unit_percent_gradient(&gradient.stops, length, |p, c| {
+            web_gradient
+                .add_color_stop(p, c.to_string().as_str())
+                .unwrap();
+        });
+
+        web_gradient
     }
 }
 
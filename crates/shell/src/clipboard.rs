@@ -0,0 +1,345 @@
+//! This module contains the content of the system clipboard and the primary selection.
+
+use std::path::{Path, PathBuf};
+
+use crate::render::Image;
+
+/// Mime type of plain text.
+pub const MIME_TEXT: &str = "text/plain;charset=utf-8";
+
+/// Mime type of html.
+pub const MIME_HTML: &str = "text/html";
+
+/// Mime type of png images.
+pub const MIME_PNG: &str = "image/png";
+
+/// Mime type of a list of file uris.
+pub const MIME_URI_LIST: &str = "text/uri-list";
+
+/// Describes which clipboard of the system is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardKind {
+    /// The clipboard that is used by copy and paste.
+    Clipboard,
+
+    /// The primary selection of X11 and Wayland. It contains the last selected text and is
+    /// pasted by the middle mouse button.
+    Selection,
+}
+
+impl Default for ClipboardKind {
+    fn default() -> Self {
+        ClipboardKind::Clipboard
+    }
+}
+
+/// The content of a clipboard. It could contain the same data in different formats, e.g. the
+/// text and html of a formatted text.
+///
+/// # Example
+///
+/// ```rust
+/// let content = ClipboardContent::default()
+///     .text("OrbTk")
+///     .html("<b>OrbTk</b>");
+/// assert_eq!(content.mime_types(), vec![MIME_TEXT, MIME_HTML]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClipboardContent {
+    /// Plain text.
+    pub text: Option<String>,
+
+    /// Formatted text as html.
+    pub html: Option<String>,
+
+    /// An image, it is exchanged with other applications as png.
+    pub image: Option<Image>,
+
+    /// Paths of files e.g. copied by a file manager.
+    pub files: Vec<PathBuf>,
+}
+
+impl ClipboardContent {
+    /// Creates a new content with the given text.
+    pub fn from_text(text: impl Into<String>) -> Self {
+        ClipboardContent::default().text(text)
+    }
+
+    /// Sets the plain text.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the html.
+    pub fn html(mut self, html: impl Into<String>) -> Self {
+        self.html = Some(html.into());
+        self
+    }
+
+    /// Sets the image.
+    pub fn image(mut self, image: Image) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Sets the file paths.
+    pub fn files(mut self, files: Vec<PathBuf>) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Check if the content contains no format.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none() && self.html.is_none() && self.image.is_none() && self.files.is_empty()
+    }
+
+    /// Gets the mime types of the available formats.
+    pub fn mime_types(&self) -> Vec<&'static str> {
+        let mut mime_types = vec![];
+
+        if self.text.is_some() {
+            mime_types.push(MIME_TEXT);
+        }
+
+        if self.html.is_some() {
+            mime_types.push(MIME_HTML);
+        }
+
+        if self.image.is_some() {
+            mime_types.push(MIME_PNG);
+        }
+
+        if !self.files.is_empty() {
+            mime_types.push(MIME_URI_LIST);
+        }
+
+        mime_types
+    }
+
+    /// Gets the format with the given mime type as bytes. Returns `None` if the format is not
+    /// available.
+    pub fn to_mime(&self, mime_type: &str) -> Option<Vec<u8>> {
+        match mime_type {
+            MIME_TEXT | "text/plain" | "UTF8_STRING" | "STRING" => {
+                self.text.as_ref().map(|text| text.as_bytes().to_vec())
+            }
+            MIME_HTML => self.html.as_ref().map(|html| html.as_bytes().to_vec()),
+            MIME_PNG => self.image.as_ref().and_then(encode_png),
+            MIME_URI_LIST if !self.files.is_empty() => Some(
+                self.files
+                    .iter()
+                    .map(|file| path_to_file_uri(file))
+                    .collect::<Vec<String>>()
+                    .join("\r\n")
+                    .into_bytes(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Sets the format with the given mime type from bytes. Returns `false` if the mime type is
+    /// not supported or the data is invalid.
+    pub fn set_mime(&mut self, mime_type: &str, data: &[u8]) -> bool {
+        match mime_type {
+            MIME_TEXT | "text/plain" | "UTF8_STRING" | "STRING" => {
+                self.text = Some(String::from_utf8_lossy(data).to_string());
+            }
+            MIME_HTML => self.html = Some(String::from_utf8_lossy(data).to_string()),
+            MIME_PNG => match decode_png(data) {
+                Some(image) => self.image = Some(image),
+                None => return false,
+            },
+            MIME_URI_LIST => {
+                self.files = String::from_utf8_lossy(data)
+                    .lines()
+                    .map(|line| line.trim())
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .filter_map(file_uri_to_path)
+                    .collect();
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "glupath")))]
+fn encode_png(image: &Image) -> Option<Vec<u8>> {
+    let mut rgba = Vec::with_capacity(image.data().len() * 4);
+
+    for pixel in image.data() {
+        rgba.extend_from_slice(&[
+            (pixel >> 16) as u8,
+            (pixel >> 8) as u8,
+            *pixel as u8,
+            (pixel >> 24) as u8,
+        ]);
+    }
+
+    let mut png = vec![];
+    image::png::PngEncoder::new(&mut png)
+        .encode(
+            &rgba,
+            image.width() as u32,
+            image.height() as u32,
+            image::ColorType::Rgba8,
+        )
+        .ok()?;
+
+    Some(png)
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "glupath")))]
+fn decode_png(data: &[u8]) -> Option<Image> {
+    let image = image::load_from_memory_with_format(data, image::ImageFormat::Png).ok()?;
+    Image::from_rgba_image(image.to_rgba()).ok()
+}
+
+// images are only exchanged as png if the render backend provides the pixels
+#[cfg(any(target_arch = "wasm32", feature = "glupath"))]
+fn encode_png(_: &Image) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(any(target_arch = "wasm32", feature = "glupath"))]
+fn decode_png(_: &[u8]) -> Option<Image> {
+    None
+}
+
+/// Converts a `file://` uri with percent encoded bytes to a path. Returns `None` if it is not a
+/// valid file uri.
+pub fn file_uri_to_path(uri: &str) -> Option<PathBuf> {
+    let path = uri.strip_prefix("file://")?;
+
+    // skips the host e.g. `file://localhost/tmp`
+    let path = &path[path.find('/')?..];
+    let path = path.as_bytes();
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut i = 0;
+
+    while i < path.len() {
+        if path[i] == b'%' {
+            let hex = std::str::from_utf8(path.get(i + 1..i + 3)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            bytes.push(path[i]);
+            i += 1;
+        }
+    }
+
+    #[cfg(unix)]
+    {
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+        Some(PathBuf::from(OsString::from_vec(bytes)))
+    }
+
+    #[cfg(not(unix))]
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+/// Converts an absolute path to a `file://` uri. Bytes that are not allowed in an uri are
+/// percent encoded.
+pub fn path_to_file_uri(path: &Path) -> String {
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStrExt;
+        path.as_os_str().as_bytes().to_vec()
+    };
+
+    #[cfg(not(unix))]
+    let bytes = path.to_string_lossy().replace('\\', "/").into_bytes();
+
+    let mut uri = String::from("file://");
+
+    if bytes.first() != Some(&b'/') {
+        uri.push('/');
+    }
+
+    for byte in bytes {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    uri
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_uri_to_path() {
+        assert_eq!(
+            file_uri_to_path("file:///home/user/My%20Photos/caf%C3%A9.png"),
+            Some(PathBuf::from("/home/user/My Photos/café.png"))
+        );
+        assert_eq!(
+            file_uri_to_path("file:///tmp/notes.txt"),
+            Some(PathBuf::from("/tmp/notes.txt"))
+        );
+        assert_eq!(
+            file_uri_to_path("file://localhost/tmp/notes.txt"),
+            Some(PathBuf::from("/tmp/notes.txt"))
+        );
+        assert_eq!(file_uri_to_path("https://example.com/notes.txt"), None);
+        assert_eq!(file_uri_to_path("file:///tmp/broken%2"), None);
+    }
+
+    #[test]
+    fn test_path_to_file_uri() {
+        let path = PathBuf::from("/home/user/My Photos/café.png");
+        let uri = path_to_file_uri(&path);
+
+        assert_eq!(uri, "file:///home/user/My%20Photos/caf%C3%A9.png");
+        assert_eq!(file_uri_to_path(&uri), Some(path));
+    }
+
+    #[test]
+    fn test_mime() {
+        let content = ClipboardContent::from_text("OrbTk")
+            .html("<b>OrbTk</b>")
+            .files(vec![PathBuf::from("/tmp/a.txt"), PathBuf::from("/tmp/b c")]);
+
+        assert_eq!(
+            content.mime_types(),
+            vec![MIME_TEXT, MIME_HTML, MIME_URI_LIST]
+        );
+        assert_eq!(content.to_mime("text/plain"), Some(b"OrbTk".to_vec()));
+        assert_eq!(content.to_mime(MIME_PNG), None);
+
+        let mut pasted = ClipboardContent::default();
+        assert!(pasted.is_empty());
+
+        for mime_type in content.mime_types() {
+            assert!(pasted.set_mime(mime_type, &content.to_mime(mime_type).unwrap()));
+        }
+
+        assert!(!pasted.set_mime("application/x-unknown", b""));
+        assert_eq!(pasted.text, content.text);
+        assert_eq!(pasted.html, content.html);
+        assert_eq!(pasted.files, content.files);
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "glupath")))]
+    #[test]
+    fn test_png() {
+        let image = Image::from_data(2, 1, vec![0xFF_FF_00_00, 0x80_00_00_FF]).unwrap();
+        let content = ClipboardContent::default().image(image);
+        let png = content.to_mime(MIME_PNG).unwrap();
+
+        let mut pasted = ClipboardContent::default();
+        assert!(pasted.set_mime(MIME_PNG, &png));
+
+        let pasted = pasted.image.unwrap();
+        assert_eq!(pasted.width(), 2.0);
+        assert_eq!(pasted.data(), &[0xFF_FF_00_00, 0x80_00_00_FF]);
+        assert!(!ClipboardContent::default().set_mime(MIME_PNG, b"no png"));
+    }
+}
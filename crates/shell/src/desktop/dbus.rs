@@ -0,0 +1,597 @@
+use std::{
+    collections::HashSet,
+    process,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use dbus::{
+    arg::{PropMap, RefArg, Variant},
+    blocking::Connection,
+    channel::{Channel, Sender},
+    message::MatchRule,
+    Message, Path,
+};
+use dbus_crossroads::{Crossroads, IfaceBuilder, MethodErr};
+
+use super::*;
+
+const NOTIFICATIONS_NAME: &str = "org.freedesktop.Notifications";
+const NOTIFICATIONS_PATH: &str = "/org/freedesktop/Notifications";
+const ITEM_INTERFACE: &str = "org.kde.StatusNotifierItem";
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const WATCHER_NAME: &str = "org.kde.StatusNotifierWatcher";
+const WATCHER_PATH: &str = "/StatusNotifierWatcher";
+const MENU_INTERFACE: &str = "com.canonical.dbusmenu";
+const MENU_PATH: &str = "/MenuBar";
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+type Reply<T> = mpsc::Sender<Result<T, DesktopError>>;
+
+enum Request {
+    Notify(Notification, Reply<NotificationId>),
+    CloseNotification(NotificationId, Reply<()>),
+    SetTrayIcon(Option<TrayIcon>, Reply<()>),
+}
+
+/// The `DBusBackend` speaks the freedesktop `org.freedesktop.Notifications` and
+/// `StatusNotifierItem` (with `com.canonical.dbusmenu` for the menu) interfaces. The connection is
+/// served by a background thread.
+pub struct DBusBackend {
+    requests: mpsc::Sender<Request>,
+    events: mpsc::Receiver<DesktopEvent>,
+}
+
+impl DBusBackend {
+    /// Connects to the session bus.
+    pub fn session() -> Result<Self, DesktopError> {
+        let connection =
+            Connection::new_session().map_err(|e| DesktopError::Connection(e.to_string()))?;
+        Ok(DBusBackend::spawn(connection))
+    }
+
+    /// Connects to the bus with the given address e.g. a private bus of a test.
+    pub fn open(address: &str) -> Result<Self, DesktopError> {
+        let mut channel =
+            Channel::open_private(address).map_err(|e| DesktopError::Connection(e.to_string()))?;
+        channel
+            .register()
+            .map_err(|e| DesktopError::Connection(e.to_string()))?;
+        Ok(DBusBackend::spawn(Connection::from(channel)))
+    }
+
+    fn spawn(connection: Connection) -> Self {
+        let (request_sender, request_receiver) = mpsc::channel();
+        let (event_sender, event_receiver) = mpsc::channel();
+
+        thread::spawn(move || Worker::new(connection, event_sender).run(request_receiver));
+
+        DBusBackend {
+            requests: request_sender,
+            events: event_receiver,
+        }
+    }
+
+    fn request<T>(&self, request: impl FnOnce(Reply<T>) -> Request) -> Result<T, DesktopError> {
+        let (sender, receiver) = mpsc::channel();
+        let disconnected = || DesktopError::Connection(String::from("connection closed"));
+
+        self.requests
+            .send(request(sender))
+            .map_err(|_| disconnected())?;
+        receiver.recv().map_err(|_| disconnected())?
+    }
+}
+
+impl DesktopBackend for DBusBackend {
+    fn notify(&mut self, notification: &Notification) -> Result<NotificationId, DesktopError> {
+        let notification = notification.clone();
+        self.request(|reply| Request::Notify(notification, reply))
+    }
+
+    fn close_notification(&mut self, id: NotificationId) -> Result<(), DesktopError> {
+        self.request(|reply| Request::CloseNotification(id, reply))
+    }
+
+    fn set_tray_icon(&mut self, tray_icon: Option<&TrayIcon>) -> Result<(), DesktopError> {
+        let tray_icon = tray_icon.cloned();
+        self.request(|reply| Request::SetTrayIcon(tray_icon, reply))
+    }
+
+    fn poll_events(&mut self, events: &mut Vec<DesktopEvent>) {
+        events.extend(self.events.try_iter());
+    }
+}
+
+fn request_error(error: dbus::Error) -> DesktopError {
+    DesktopError::Request(error.to_string())
+}
+
+// The tray icon shared between the worker and the exported objects.
+#[derive(Default)]
+struct TrayState {
+    tray_icon: Option<TrayIcon>,
+    revision: u32,
+}
+
+#[derive(Clone)]
+struct ItemData {
+    state: Arc<Mutex<TrayState>>,
+    events: mpsc::Sender<DesktopEvent>,
+}
+
+impl ItemData {
+    fn get<R>(&self, f: impl FnOnce(&TrayIcon) -> R) -> Result<R, MethodErr> {
+        match &self.state.lock().unwrap().tray_icon {
+            Some(tray_icon) => Ok(f(tray_icon)),
+            None => Err(MethodErr::failed("no tray icon")),
+        }
+    }
+
+    fn send(&self, event: DesktopEvent) {
+        self.events.send(event).ok();
+    }
+}
+
+type IconPixmap = Vec<(i32, i32, Vec<u8>)>;
+
+// The icon data of the StatusNotifierItem is ARGB32 in network byte order.
+fn icon_pixmap(tray_icon: &TrayIcon) -> IconPixmap {
+    tray_icon
+        .icon_pixmap
+        .iter()
+        .map(|pixmap| {
+            (
+                pixmap.width as i32,
+                pixmap.height as i32,
+                pixmap
+                    .data
+                    .iter()
+                    .flat_map(|p| p.to_be_bytes().to_vec())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn status(status: TrayStatus) -> String {
+    match status {
+        TrayStatus::Passive => "Passive",
+        TrayStatus::Active => "Active",
+        TrayStatus::NeedsAttention => "NeedsAttention",
+    }
+    .to_string()
+}
+
+// The dbusmenu ids of the items are their index + 1, 0 is the root.
+fn menu_item_properties(item: Option<&TrayMenuItem>) -> PropMap {
+    let mut properties = PropMap::new();
+
+    match item {
+        None => {
+            properties.insert(
+                "children-display".into(),
+                Variant(Box::new(String::from("submenu"))),
+            );
+        }
+        Some(TrayMenuItem::Item { label, enabled, .. }) => {
+            properties.insert("label".into(), Variant(Box::new(label.clone())));
+            properties.insert("enabled".into(), Variant(Box::new(*enabled)));
+        }
+        Some(TrayMenuItem::Separator) => {
+            properties.insert("type".into(), Variant(Box::new(String::from("separator"))));
+        }
+    }
+
+    properties
+}
+
+type MenuLayout = (i32, PropMap, Vec<Variant<Box<dyn RefArg>>>);
+
+fn menu_layout(menu: &[TrayMenuItem], parent: i32) -> MenuLayout {
+    if parent > 0 {
+        return (
+            parent,
+            menu_item_properties(menu.get(parent as usize - 1)),
+            vec![],
+        );
+    }
+
+    let children = menu
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let child: MenuLayout = (index as i32 + 1, menu_item_properties(Some(item)), vec![]);
+            Variant(Box::new(child) as Box<dyn RefArg>)
+        })
+        .collect();
+
+    (0, menu_item_properties(None), children)
+}
+
+fn register_item(builder: &mut IfaceBuilder<ItemData>) {
+    builder
+        .property("Category")
+        .get(|_, _| Ok(String::from("ApplicationStatus")));
+    builder
+        .property("Id")
+        .get(|_, data| data.get(|t| t.id.clone()));
+    builder
+        .property("Title")
+        .get(|_, data| data.get(|t| t.title.clone()));
+    builder
+        .property("Status")
+        .get(|_, data| data.get(|t| status(t.status)));
+    builder
+        .property("IconName")
+        .get(|_, data| data.get(|t| t.icon_name.clone()));
+    builder
+        .property("IconPixmap")
+        .get(|_, data| data.get(icon_pixmap));
+    builder.property("ToolTip").get(|_, data| {
+        data.get(|t| {
+            (
+                String::new(),
+                IconPixmap::new(),
+                t.tooltip.clone(),
+                String::new(),
+            )
+        })
+    });
+    builder.property("ItemIsMenu").get(|_, _| Ok(false));
+    builder
+        .property("Menu")
+        .get(|_, _| Ok(Path::from(MENU_PATH)));
+
+    builder.method("Activate", ("x", "y"), (), |_, data, (x, y): (i32, i32)| {
+        data.send(DesktopEvent::TrayActivated { x, y });
+        Ok(())
+    });
+    builder.method(
+        "SecondaryActivate",
+        ("x", "y"),
+        (),
+        |_, data, (x, y): (i32, i32)| {
+            data.send(DesktopEvent::TraySecondaryActivated { x, y });
+            Ok(())
+        },
+    );
+    // the host shows the menu itself
+    builder.method("ContextMenu", ("x", "y"), (), |_, _, _: (i32, i32)| Ok(()));
+    builder.method(
+        "Scroll",
+        ("delta", "orientation"),
+        (),
+        |_, _, _: (i32, String)| Ok(()),
+    );
+
+    builder.signal::<(), _>("NewTitle", ());
+    builder.signal::<(), _>("NewIcon", ());
+    builder.signal::<(), _>("NewToolTip", ());
+    builder.signal::<(String,), _>("NewStatus", ("status",));
+}
+
+fn register_menu(builder: &mut IfaceBuilder<ItemData>) {
+    builder.property("Version").get(|_, _| Ok(3_u32));
+    builder
+        .property("TextDirection")
+        .get(|_, _| Ok(String::from("ltr")));
+    builder
+        .property("Status")
+        .get(|_, _| Ok(String::from("normal")));
+    builder
+        .property("IconThemePath")
+        .get(|_, _| Ok(Vec::<String>::new()));
+
+    builder.method(
+        "GetLayout",
+        ("parentId", "recursionDepth", "propertyNames"),
+        ("revision", "layout"),
+        |_, data, (parent, _, _): (i32, i32, Vec<String>)| {
+            let state = data.state.lock().unwrap();
+            let menu = state
+                .tray_icon
+                .as_ref()
+                .map(|t| t.menu.as_slice())
+                .unwrap_or(&[]);
+            Ok((state.revision, menu_layout(menu, parent)))
+        },
+    );
+    builder.method(
+        "GetGroupProperties",
+        ("ids", "propertyNames"),
+        ("properties",),
+        |_, data, (ids, _): (Vec<i32>, Vec<String>)| {
+            data.get(|t| {
+                let properties: Vec<(i32, PropMap)> = ids
+                    .iter()
+                    .filter(|id| **id >= 0 && **id as usize <= t.menu.len())
+                    .map(|id| {
+                        let item = if *id == 0 {
+                            None
+                        } else {
+                            t.menu.get(*id as usize - 1)
+                        };
+                        (*id, menu_item_properties(item))
+                    })
+                    .collect();
+                (properties,)
+            })
+        },
+    );
+    builder.method(
+        "Event",
+        ("id", "eventId", "data", "timestamp"),
+        (),
+        |_, data, (id, event_id, _, _): (i32, String, Variant<Box<dyn RefArg>>, u32)| {
+            if event_id != "clicked" || id <= 0 {
+                return Ok(());
+            }
+
+            let clicked = data.get(|t| match t.menu.get(id as usize - 1) {
+                Some(TrayMenuItem::Item {
+                    id, enabled: true, ..
+                }) => Some(id.clone()),
+                _ => None,
+            })?;
+
+            if let Some(clicked) = clicked {
+                data.send(DesktopEvent::TrayMenuItemClicked(clicked));
+            }
+
+            Ok(())
+        },
+    );
+    builder.method(
+        "AboutToShow",
+        ("id",),
+        ("needUpdate",),
+        |_, _, _: (i32,)| Ok((false,)),
+    );
+
+    builder.signal::<(u32, i32), _>("LayoutUpdated", ("revision", "parent"));
+}
+
+struct Worker {
+    connection: Connection,
+    item: ItemData,
+    notifications: Arc<Mutex<HashSet<u32>>>,
+    item_name: Option<String>,
+}
+
+impl Worker {
+    fn new(connection: Connection, events: mpsc::Sender<DesktopEvent>) -> Self {
+        Worker {
+            connection,
+            item: ItemData {
+                state: Arc::new(Mutex::new(TrayState::default())),
+                events,
+            },
+            notifications: Arc::new(Mutex::new(HashSet::new())),
+            item_name: None,
+        }
+    }
+
+    fn run(mut self, requests: mpsc::Receiver<Request>) {
+        self.listen_notifications();
+        self.export_tray_icon();
+
+        loop {
+            if self.connection.process(Duration::from_millis(50)).is_err() {
+                return;
+            }
+
+            loop {
+                match requests.try_recv() {
+                    Ok(Request::Notify(notification, reply)) => {
+                        reply.send(self.notify(&notification)).ok();
+                    }
+                    Ok(Request::CloseNotification(id, reply)) => {
+                        reply.send(self.close_notification(id)).ok();
+                    }
+                    Ok(Request::SetTrayIcon(tray_icon, reply)) => {
+                        reply.send(self.set_tray_icon(tray_icon)).ok();
+                    }
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    // the backend is dropped
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        self.set_tray_icon(None).ok();
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    // The signals of the notification server are broadcasts, only the notifications posted by
+    // this backend are reported.
+    fn listen_notifications(&self) {
+        let notifications = self.notifications.clone();
+        let events = self.item.events.clone();
+        self.connection
+            .add_match(
+                MatchRule::new_signal(NOTIFICATIONS_NAME, "NotificationClosed"),
+                move |(id, reason): (u32, u32), _, _| {
+                    if notifications.lock().unwrap().remove(&id) {
+                        events
+                            .send(DesktopEvent::NotificationClosed {
+                                id: NotificationId(id),
+                                reason: reason.into(),
+                            })
+                            .ok();
+                    }
+                    true
+                },
+            )
+            .ok();
+
+        let notifications = self.notifications.clone();
+        let events = self.item.events.clone();
+        self.connection
+            .add_match(
+                MatchRule::new_signal(NOTIFICATIONS_NAME, "ActionInvoked"),
+                move |(id, action): (u32, String), _, _| {
+                    if notifications.lock().unwrap().contains(&id) {
+                        events
+                            .send(DesktopEvent::NotificationAction {
+                                id: NotificationId(id),
+                                action,
+                            })
+                            .ok();
+                    }
+                    true
+                },
+            )
+            .ok();
+    }
+
+    fn export_tray_icon(&self) {
+        let mut crossroads = Crossroads::new();
+        let item = crossroads.register(ITEM_INTERFACE, register_item);
+        let menu = crossroads.register(MENU_INTERFACE, register_menu);
+        crossroads.insert(ITEM_PATH, &[item], self.item.clone());
+        crossroads.insert(MENU_PATH, &[menu], self.item.clone());
+
+        self.connection.start_receive(
+            MatchRule::new_method_call(),
+            Box::new(move |message, connection| {
+                crossroads.handle_message(message, connection).ok();
+                true
+            }),
+        );
+    }
+
+    fn notify(&self, notification: &Notification) -> Result<NotificationId, DesktopError> {
+        let proxy = self
+            .connection
+            .with_proxy(NOTIFICATIONS_NAME, NOTIFICATIONS_PATH, TIMEOUT);
+
+        let actions: Vec<String> = notification
+            .actions
+            .iter()
+            .flat_map(|(key, label)| vec![key.clone(), label.clone()])
+            .collect();
+
+        let urgency: u8 = match notification.urgency {
+            Urgency::Low => 0,
+            Urgency::Normal => 1,
+            Urgency::Critical => 2,
+        };
+        let mut hints = PropMap::new();
+        hints.insert("urgency".into(), Variant(Box::new(urgency)));
+
+        let timeout = notification
+            .timeout
+            .map(|timeout| timeout.as_millis() as i32)
+            .unwrap_or(-1);
+
+        let (id,): (u32,) = proxy
+            .method_call(
+                NOTIFICATIONS_NAME,
+                "Notify",
+                (
+                    process_name(),
+                    notification.replaces.map(|id| id.0).unwrap_or(0),
+                    notification.icon.as_str(),
+                    notification.summary.as_str(),
+                    notification.body.as_str(),
+                    actions,
+                    hints,
+                    timeout,
+                ),
+            )
+            .map_err(request_error)?;
+
+        self.notifications.lock().unwrap().insert(id);
+
+        Ok(NotificationId(id))
+    }
+
+    fn close_notification(&self, id: NotificationId) -> Result<(), DesktopError> {
+        self.connection
+            .with_proxy(NOTIFICATIONS_NAME, NOTIFICATIONS_PATH, TIMEOUT)
+            .method_call(NOTIFICATIONS_NAME, "CloseNotification", (id.0,))
+            .map_err(request_error)
+    }
+
+    fn set_tray_icon(&mut self, tray_icon: Option<TrayIcon>) -> Result<(), DesktopError> {
+        let visible = tray_icon.is_some();
+        let revision = {
+            let mut state = self.item.state.lock().unwrap();
+            state.tray_icon = tray_icon;
+            state.revision += 1;
+            state.revision
+        };
+
+        match (visible, self.item_name.is_some()) {
+            (true, true) => self.emit_changed(revision),
+            (true, false) => self.register_item(),
+            // the watcher removes the item if its name vanishes
+            (false, true) => {
+                let name = self.item_name.take().unwrap();
+                self.connection
+                    .release_name(name)
+                    .map(|_| ())
+                    .map_err(request_error)
+            }
+            (false, false) => Ok(()),
+        }
+    }
+
+    fn register_item(&mut self) -> Result<(), DesktopError> {
+        let name = format!("org.kde.StatusNotifierItem-{}-1", process::id());
+        self.connection
+            .request_name(name.as_str(), false, true, false)
+            .map_err(request_error)?;
+
+        let result = self
+            .connection
+            .with_proxy(WATCHER_NAME, WATCHER_PATH, TIMEOUT)
+            .method_call(WATCHER_NAME, "RegisterStatusNotifierItem", (name.as_str(),))
+            .map_err(request_error);
+
+        self.item_name = Some(name);
+        result
+    }
+
+    fn emit_changed(&self, revision: u32) -> Result<(), DesktopError> {
+        let status = self
+            .item
+            .state
+            .lock()
+            .unwrap()
+            .tray_icon
+            .as_ref()
+            .map(|t| status(t.status))
+            .unwrap_or_default();
+
+        let mut signals = vec![];
+        for name in &["NewTitle", "NewIcon", "NewToolTip"] {
+            signals.push(Message::new_signal(ITEM_PATH, ITEM_INTERFACE, *name));
+        }
+        signals.push(
+            Message::new_signal(ITEM_PATH, ITEM_INTERFACE, "NewStatus").map(|m| m.append1(status)),
+        );
+        signals.push(
+            Message::new_signal(MENU_PATH, MENU_INTERFACE, "LayoutUpdated")
+                .map(|m| m.append2(revision, 0_i32)),
+        );
+
+        for signal in signals {
+            let signal = signal.map_err(DesktopError::Request)?;
+            self.connection
+                .send(signal)
+                .map_err(|_| DesktopError::Connection(String::from("could not send signal")))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn process_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| path.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .unwrap_or_default()
+}
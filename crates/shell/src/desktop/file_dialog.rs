@@ -0,0 +1,227 @@
+use std::path::{Path, PathBuf};
+
+use super::DesktopError;
+
+/// Describes what is picked by a file dialog.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileDialogKind {
+    /// Picks an existing file.
+    Open,
+
+    /// Picks one or more existing files.
+    OpenMultiple,
+
+    /// Picks the path of a file to save.
+    Save,
+
+    /// Picks a folder.
+    Folder,
+}
+
+impl Default for FileDialogKind {
+    fn default() -> Self {
+        FileDialogKind::Open
+    }
+}
+
+/// Filters the files displayed by a file dialog by their names e.g. `*.png`.
+///
+/// # Example
+///
+/// ```rust
+/// let filter = FileFilter::new("Images").pattern("*.png").pattern("*.jpg");
+/// assert!(filter.matches("photo.JPG"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileFilter {
+    /// The name that is displayed.
+    pub name: String,
+
+    /// Glob patterns with `*` and `?`. They are matched case insensitive.
+    pub patterns: Vec<String>,
+}
+
+impl FileFilter {
+    /// Creates a new filter with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        FileFilter {
+            name: name.into(),
+            patterns: vec![],
+        }
+    }
+
+    /// Adds a glob pattern.
+    pub fn pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.patterns.push(pattern.into());
+        self
+    }
+
+    /// Check if the given file name matches one of the patterns. A filter without patterns
+    /// matches all files.
+    pub fn matches(&self, file_name: &str) -> bool {
+        let file_name: Vec<char> = file_name.to_lowercase().chars().collect();
+
+        self.patterns.is_empty()
+            || self.patterns.iter().any(|pattern| {
+                let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+                glob_match(&pattern, &file_name)
+            })
+    }
+}
+
+// Matches `*` (any sequence) and `?` (any char).
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|i| glob_match(rest, &text[i..])),
+        Some((c, rest)) => match text.split_first() {
+            Some((t, text)) if *c == '?' || c == t => glob_match(rest, text),
+            _ => false,
+        },
+    }
+}
+
+/// Describes a file dialog that is shown by a `FileDialogBackend`.
+///
+/// # Example
+///
+/// ```rust
+/// let request = FileDialogRequest::open()
+///     .title("Open image")
+///     .filter(FileFilter::new("Images").pattern("*.png").pattern("*.jpg"))
+///     .filter(FileFilter::new("All files").pattern("*"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileDialogRequest {
+    /// What is picked.
+    pub kind: FileDialogKind,
+
+    /// The title of the dialog. If it is empty the title depends on the kind.
+    pub title: String,
+
+    /// The filters that could be selected. The first one is selected initially.
+    pub filters: Vec<FileFilter>,
+
+    /// The initial folder.
+    pub folder: Option<PathBuf>,
+
+    /// The suggested file name of a save dialog.
+    pub file_name: String,
+}
+
+impl FileDialogRequest {
+    /// Creates a new request to pick an existing file.
+    pub fn open() -> Self {
+        FileDialogRequest::default()
+    }
+
+    /// Creates a new request to pick one or more existing files.
+    pub fn open_multiple() -> Self {
+        FileDialogRequest {
+            kind: FileDialogKind::OpenMultiple,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new request to pick the path of a file to save.
+    pub fn save() -> Self {
+        FileDialogRequest {
+            kind: FileDialogKind::Save,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new request to pick a folder.
+    pub fn folder() -> Self {
+        FileDialogRequest {
+            kind: FileDialogKind::Folder,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Adds a filter.
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Sets the initial folder.
+    pub fn in_folder(mut self, folder: impl AsRef<Path>) -> Self {
+        self.folder = Some(folder.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the suggested file name of a save dialog.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = file_name.into();
+        self
+    }
+
+    /// Gets the title or if it is empty the default title of the kind.
+    pub fn display_title(&self) -> &str {
+        if !self.title.is_empty() {
+            return &self.title;
+        }
+
+        match self.kind {
+            FileDialogKind::Open => "Open File",
+            FileDialogKind::OpenMultiple => "Open Files",
+            FileDialogKind::Save => "Save File",
+            FileDialogKind::Folder => "Select Folder",
+        }
+    }
+}
+
+/// Called with the picked paths after a file dialog is closed. The paths are empty if the dialog
+/// was canceled. It could be called from another thread.
+pub type FileDialogReply = Box<dyn FnOnce(Vec<PathBuf>) + Send>;
+
+/// Implement this trait to show the native file dialogs of a platform.
+pub trait FileDialogBackend: Send {
+    /// Shows the file dialog without blocking. Returns an error if the dialog could not be shown,
+    /// then `reply` is not called.
+    fn show(
+        &mut self,
+        request: &FileDialogRequest,
+        reply: FileDialogReply,
+    ) -> Result<(), DesktopError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_matches() {
+        let images = FileFilter::new("Images").pattern("*.png").pattern("*.jp?g");
+
+        assert!(images.matches("photo.png"));
+        assert!(images.matches("Photo.PNG"));
+        assert!(images.matches("photo.jpeg"));
+        assert!(images.matches(".png"));
+        assert!(!images.matches("photo.jpg"));
+        assert!(!images.matches("photo.png.txt"));
+        assert!(!images.matches("png"));
+
+        assert!(FileFilter::new("All").matches("notes.txt"));
+        assert!(FileFilter::new("All").pattern("*").matches("notes.txt"));
+        assert!(FileFilter::new("Readme")
+            .pattern("readme*")
+            .matches("README.md"));
+    }
+
+    #[test]
+    fn test_display_title() {
+        assert_eq!(FileDialogRequest::save().display_title(), "Save File");
+        assert_eq!(
+            FileDialogRequest::folder().title("Export").display_title(),
+            "Export"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::{Arc, Mutex};
+
+use super::*;
+
+#[derive(Default)]
+struct MockState {
+    next_id: u32,
+    notifications: Vec<(NotificationId, Notification)>,
+    tray_icon: Option<TrayIcon>,
+    events: Vec<DesktopEvent>,
+}
+
+/// The `MockBackend` is an in-process stand-in for the desktop. It stores the posted notifications
+/// and the tray icon and could simulate user interaction. Clones share the same state, keep a
+/// clone to inspect the backend after it is moved into a `DesktopService`.
+#[derive(Clone, Default)]
+pub struct MockBackend {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockBackend {
+    /// Creates a new mock backend without notifications and tray icon.
+    pub fn new() -> Self {
+        MockBackend::default()
+    }
+
+    /// Gets the open notifications.
+    pub fn notifications(&self) -> Vec<(NotificationId, Notification)> {
+        self.state.lock().unwrap().notifications.clone()
+    }
+
+    /// Gets the open notification with the given id.
+    pub fn notification(&self, id: NotificationId) -> Option<Notification> {
+        self.state
+            .lock()
+            .unwrap()
+            .notifications
+            .iter()
+            .find(|(i, _)| *i == id)
+            .map(|(_, n)| n.clone())
+    }
+
+    /// Gets the shown tray icon.
+    pub fn tray_icon(&self) -> Option<TrayIcon> {
+        self.state.lock().unwrap().tray_icon.clone()
+    }
+
+    /// Raises the given event.
+    pub fn emit(&self, event: DesktopEvent) {
+        self.state.lock().unwrap().events.push(event);
+    }
+
+    /// Simulates that the user invokes an action of an open notification. Returns `false` if
+    /// the notification or the action does not exist.
+    pub fn invoke_action(&self, id: NotificationId, action: &str) -> bool {
+        let mut state = self.state.lock().unwrap();
+
+        let found = state
+            .notifications
+            .iter()
+            .any(|(i, n)| *i == id && n.actions.iter().any(|(key, _)| key == action));
+
+        if found {
+            state.events.push(DesktopEvent::NotificationAction {
+                id,
+                action: action.to_string(),
+            });
+        }
+
+        found
+    }
+
+    /// Simulates that the user clicks an item of the tray menu. Returns `false` if there is no
+    /// enabled item with the given id.
+    pub fn click_menu_item(&self, id: &str) -> bool {
+        let mut state = self.state.lock().unwrap();
+
+        let found = state
+            .tray_icon
+            .iter()
+            .flat_map(|tray_icon| tray_icon.menu.iter())
+            .any(|item| match item {
+                TrayMenuItem::Item {
+                    id: item_id,
+                    enabled,
+                    ..
+                } => item_id == id && *enabled,
+                TrayMenuItem::Separator => false,
+            });
+
+        if found {
+            state
+                .events
+                .push(DesktopEvent::TrayMenuItemClicked(id.to_string()));
+        }
+
+        found
+    }
+}
+
+impl DesktopBackend for MockBackend {
+    fn notify(&mut self, notification: &Notification) -> Result<NotificationId, DesktopError> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(id) = notification.replaces {
+            if let Some(entry) = state.notifications.iter_mut().find(|(i, _)| *i == id) {
+                entry.1 = notification.clone();
+                return Ok(id);
+            }
+        }
+
+        state.next_id += 1;
+        let id = NotificationId(state.next_id);
+        state.notifications.push((id, notification.clone()));
+
+        Ok(id)
+    }
+
+    fn close_notification(&mut self, id: NotificationId) -> Result<(), DesktopError> {
+        let mut state = self.state.lock().unwrap();
+
+        let index = state
+            .notifications
+            .iter()
+            .position(|(i, _)| *i == id)
+            .ok_or_else(|| DesktopError::Request(format!("unknown notification {}", id.0)))?;
+        state.notifications.remove(index);
+        state.events.push(DesktopEvent::NotificationClosed {
+            id,
+            reason: CloseReason::Closed,
+        });
+
+        Ok(())
+    }
+
+    fn set_tray_icon(&mut self, tray_icon: Option<&TrayIcon>) -> Result<(), DesktopError> {
+        self.state.lock().unwrap().tray_icon = tray_icon.cloned();
+        Ok(())
+    }
+
+    fn poll_events(&mut self, events: &mut Vec<DesktopEvent>) {
+        events.append(&mut self.state.lock().unwrap().events);
+    }
+}
@@ -0,0 +1,549 @@
+//! This module contains the desktop service that shows a tray icon with a menu and posts desktop
+//! notifications. On Linux it speaks the freedesktop `StatusNotifierItem` and
+//! `org.freedesktop.Notifications` D-Bus interfaces (feature `desktop`). The backend could be
+//! replaced e.g. by the in-process `MockBackend` for tests.
+//!
+//! It contains also the native file dialogs, on Linux by the XDG desktop portal.
+
+use std::{fmt, time::Duration};
+
+pub use self::file_dialog::*;
+pub use self::mock::*;
+
+#[cfg(all(target_os = "linux", feature = "desktop"))]
+pub use self::dbus::*;
+
+#[cfg(all(target_os = "linux", feature = "desktop"))]
+pub use self::portal::*;
+
+mod file_dialog;
+mod mock;
+
+#[cfg(all(target_os = "linux", feature = "desktop"))]
+mod dbus;
+
+#[cfg(all(target_os = "linux", feature = "desktop"))]
+mod portal;
+
+/// Identifies a posted notification.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NotificationId(pub u32);
+
+/// Describes the urgency of a notification.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Urgency {
+    Low,
+    Normal,
+    Critical,
+}
+
+impl Default for Urgency {
+    fn default() -> Self {
+        Urgency::Normal
+    }
+}
+
+/// A desktop notification.
+///
+/// # Example
+///
+/// ```rust
+/// let notification = Notification::new("Download finished")
+///     .body("orbtk.tar.gz was saved to Downloads")
+///     .icon("folder-download")
+///     .action("open", "Open folder");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Notification {
+    /// The single line summary.
+    pub summary: String,
+
+    /// The optional multi line body.
+    pub body: String,
+
+    /// The name of an icon of the icon theme or the path of an image file.
+    pub icon: String,
+
+    /// The urgency level.
+    pub urgency: Urgency,
+
+    /// The time after that the notification is closed. If it is `None` the server decides.
+    pub timeout: Option<Duration>,
+
+    /// The actions as pairs of action key and label that are displayed as buttons.
+    pub actions: Vec<(String, String)>,
+
+    /// A posted notification that is replaced by this notification.
+    pub replaces: Option<NotificationId>,
+}
+
+impl Notification {
+    /// Creates a new notification with the given summary.
+    pub fn new(summary: impl Into<String>) -> Self {
+        Notification {
+            summary: summary.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Sets the icon.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Sets the urgency.
+    pub fn urgency(mut self, urgency: Urgency) -> Self {
+        self.urgency = urgency;
+        self
+    }
+
+    /// Sets the timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds an action. If it is invoked `DesktopEvent::NotificationAction` is raised.
+    pub fn action(mut self, key: impl Into<String>, label: impl Into<String>) -> Self {
+        self.actions.push((key.into(), label.into()));
+        self
+    }
+
+    /// Replaces the given posted notification.
+    pub fn replaces(mut self, id: NotificationId) -> Self {
+        self.replaces = Some(id);
+        self
+    }
+}
+
+/// An icon image with pixels in ARGB format.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Pixmap {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u32>,
+}
+
+/// An entry of the menu of a tray icon.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrayMenuItem {
+    /// A clickable item. If it is clicked `DesktopEvent::TrayMenuItemClicked` is raised with its
+    /// id.
+    Item {
+        id: String,
+        label: String,
+        enabled: bool,
+    },
+
+    /// A line between groups of items.
+    Separator,
+}
+
+impl TrayMenuItem {
+    /// Creates a new enabled item.
+    pub fn item(id: impl Into<String>, label: impl Into<String>) -> Self {
+        TrayMenuItem::Item {
+            id: id.into(),
+            label: label.into(),
+            enabled: true,
+        }
+    }
+
+    /// Creates a new disabled item.
+    pub fn disabled(id: impl Into<String>, label: impl Into<String>) -> Self {
+        TrayMenuItem::Item {
+            id: id.into(),
+            label: label.into(),
+            enabled: false,
+        }
+    }
+}
+
+/// Describes if the tray icon needs the attention of the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrayStatus {
+    Passive,
+    Active,
+    NeedsAttention,
+}
+
+impl Default for TrayStatus {
+    fn default() -> Self {
+        TrayStatus::Active
+    }
+}
+
+/// The icon of the application in the system tray with a tooltip and a menu.
+///
+/// # Example
+///
+/// ```rust
+/// let tray_icon = TrayIcon::new("my_app")
+///     .title("My App")
+///     .icon_name("mail-unread")
+///     .item(TrayMenuItem::item("show", "Show window"))
+///     .item(TrayMenuItem::Separator)
+///     .item(TrayMenuItem::item("quit", "Quit"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrayIcon {
+    /// The unique id of the application.
+    pub id: String,
+
+    /// The title of the icon.
+    pub title: String,
+
+    /// The name of an icon of the icon theme.
+    pub icon_name: String,
+
+    /// The icon image. It's used if the icon theme has no icon with the icon name.
+    pub icon_pixmap: Option<Pixmap>,
+
+    /// The tooltip text.
+    pub tooltip: String,
+
+    /// The status of the icon.
+    pub status: TrayStatus,
+
+    /// The items of the menu.
+    pub menu: Vec<TrayMenuItem>,
+}
+
+impl TrayIcon {
+    /// Creates a new tray icon with the given id.
+    pub fn new(id: impl Into<String>) -> Self {
+        TrayIcon {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the icon name.
+    pub fn icon_name(mut self, icon_name: impl Into<String>) -> Self {
+        self.icon_name = icon_name.into();
+        self
+    }
+
+    /// Sets the icon image.
+    pub fn icon_pixmap(mut self, icon_pixmap: Pixmap) -> Self {
+        self.icon_pixmap = Some(icon_pixmap);
+        self
+    }
+
+    /// Sets the tooltip.
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = tooltip.into();
+        self
+    }
+
+    /// Sets the status.
+    pub fn status(mut self, status: TrayStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Adds an item to the menu.
+    pub fn item(mut self, item: TrayMenuItem) -> Self {
+        self.menu.push(item);
+        self
+    }
+}
+
+/// Describes why a notification was closed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CloseReason {
+    Expired,
+    Dismissed,
+    Closed,
+    Undefined,
+}
+
+impl From<u32> for CloseReason {
+    fn from(reason: u32) -> Self {
+        match reason {
+            1 => CloseReason::Expired,
+            2 => CloseReason::Dismissed,
+            3 => CloseReason::Closed,
+            _ => CloseReason::Undefined,
+        }
+    }
+}
+
+/// Events raised by the tray icon and the notifications.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DesktopEvent {
+    /// The tray icon was activated e.g. by a left click at the given screen position.
+    TrayActivated { x: i32, y: i32 },
+
+    /// The tray icon was activated by a middle click at the given screen position.
+    TraySecondaryActivated { x: i32, y: i32 },
+
+    /// The menu item with the given id was clicked.
+    TrayMenuItemClicked(String),
+
+    /// An action of a notification was invoked.
+    NotificationAction { id: NotificationId, action: String },
+
+    /// A notification was closed.
+    NotificationClosed {
+        id: NotificationId,
+        reason: CloseReason,
+    },
+}
+
+/// Describes why a request to the desktop failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DesktopError {
+    /// The platform has no tray icons or notifications.
+    Unsupported,
+
+    /// There is no connection to the desktop e.g. to the D-Bus session bus.
+    Connection(String),
+
+    /// The desktop rejected the request.
+    Request(String),
+}
+
+impl fmt::Display for DesktopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DesktopError::Unsupported => {
+                write!(f, "tray icons and notifications are not supported")
+            }
+            DesktopError::Connection(message) => write!(f, "no connection to desktop: {}", message),
+            DesktopError::Request(message) => write!(f, "desktop request failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for DesktopError {}
+
+/// Implement this trait to provide tray icons and notifications for a platform.
+pub trait DesktopBackend: Send {
+    /// Posts a notification and returns its id.
+    fn notify(&mut self, notification: &Notification) -> Result<NotificationId, DesktopError>;
+
+    /// Closes a posted notification.
+    fn close_notification(&mut self, id: NotificationId) -> Result<(), DesktopError>;
+
+    /// Shows, updates or with `None` removes the tray icon.
+    fn set_tray_icon(&mut self, tray_icon: Option<&TrayIcon>) -> Result<(), DesktopError>;
+
+    /// Moves the events that are raised since the last call to `events`. Must not block.
+    fn poll_events(&mut self, events: &mut Vec<DesktopEvent>);
+}
+
+// Used on platforms without tray icons and notifications.
+struct UnsupportedBackend;
+
+impl DesktopBackend for UnsupportedBackend {
+    fn notify(&mut self, _: &Notification) -> Result<NotificationId, DesktopError> {
+        Err(DesktopError::Unsupported)
+    }
+
+    fn close_notification(&mut self, _: NotificationId) -> Result<(), DesktopError> {
+        Err(DesktopError::Unsupported)
+    }
+
+    fn set_tray_icon(&mut self, _: Option<&TrayIcon>) -> Result<(), DesktopError> {
+        Err(DesktopError::Unsupported)
+    }
+
+    fn poll_events(&mut self, _: &mut Vec<DesktopEvent>) {}
+}
+
+/// The `DesktopService` shows the tray icon of the application and posts desktop notifications.
+/// Its events are polled e.g. by a timer of the ui.
+///
+/// # Example
+///
+/// ```rust
+/// let mut desktop = DesktopService::new();
+///
+/// desktop.show_tray_icon(
+///     &TrayIcon::new("my_app")
+///         .icon_name("mail-unread")
+///         .item(TrayMenuItem::item("quit", "Quit")),
+/// )?;
+/// desktop.notify(&Notification::new("New mail").body("You have 3 unread mails"))?;
+///
+/// for event in desktop.events() {
+///     if event == DesktopEvent::TrayMenuItemClicked("quit".to_string()) {
+///         // quit the application
+///     }
+/// }
+/// ```
+pub struct DesktopService {
+    backend: Box<dyn DesktopBackend>,
+    tray_icon: Option<TrayIcon>,
+}
+
+impl Default for DesktopService {
+    fn default() -> Self {
+        DesktopService::new()
+    }
+}
+
+impl DesktopService {
+    /// Creates a new service with the backend of the current platform. On Linux it connects to
+    /// the D-Bus session bus. If the platform is not supported or there is no connection all
+    /// requests fail with `DesktopError::Unsupported`.
+    pub fn new() -> Self {
+        #[cfg(all(target_os = "linux", feature = "desktop"))]
+        {
+            if let Ok(backend) = DBusBackend::session() {
+                return DesktopService::from_backend(backend);
+            }
+        }
+
+        DesktopService::from_backend(UnsupportedBackend)
+    }
+
+    /// Creates a new service with the given backend.
+    pub fn from_backend(backend: impl DesktopBackend + 'static) -> Self {
+        DesktopService {
+            backend: Box::new(backend),
+            tray_icon: None,
+        }
+    }
+
+    /// Posts a notification and returns its id.
+    pub fn notify(&mut self, notification: &Notification) -> Result<NotificationId, DesktopError> {
+        self.backend.notify(notification)
+    }
+
+    /// Closes a posted notification.
+    pub fn close_notification(&mut self, id: NotificationId) -> Result<(), DesktopError> {
+        self.backend.close_notification(id)
+    }
+
+    /// Shows the tray icon or updates it if it is already shown.
+    pub fn show_tray_icon(&mut self, tray_icon: &TrayIcon) -> Result<(), DesktopError> {
+        self.backend.set_tray_icon(Some(tray_icon))?;
+        self.tray_icon = Some(tray_icon.clone());
+        Ok(())
+    }
+
+    /// Removes the tray icon.
+    pub fn hide_tray_icon(&mut self) -> Result<(), DesktopError> {
+        if self.tray_icon.is_none() {
+            return Ok(());
+        }
+
+        self.backend.set_tray_icon(None)?;
+        self.tray_icon = None;
+        Ok(())
+    }
+
+    /// Gets the shown tray icon.
+    pub fn tray_icon(&self) -> Option<&TrayIcon> {
+        self.tray_icon.as_ref()
+    }
+
+    /// Gets the events that are raised since the last call.
+    pub fn events(&mut self) -> Vec<DesktopEvent> {
+        let mut events = vec![];
+        self.backend.poll_events(&mut events);
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notify() {
+        let backend = MockBackend::new();
+        let mut desktop = DesktopService::from_backend(backend.clone());
+
+        let first = desktop
+            .notify(&Notification::new("Saved").action("open", "Open"))
+            .unwrap();
+        let second = desktop.notify(&Notification::new("Failed")).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(backend.notifications().len(), 2);
+
+        // the replacing notification keeps the id
+        let replaced = desktop
+            .notify(
+                &Notification::new("Saved again")
+                    .action("open", "Open")
+                    .replaces(first),
+            )
+            .unwrap();
+        assert_eq!(replaced, first);
+        assert_eq!(backend.notification(first).unwrap().summary, "Saved again");
+
+        assert!(backend.invoke_action(first, "open"));
+        assert!(!backend.invoke_action(second, "open"));
+        desktop.close_notification(second).unwrap();
+
+        assert_eq!(
+            desktop.events(),
+            vec![
+                DesktopEvent::NotificationAction {
+                    id: first,
+                    action: String::from("open")
+                },
+                DesktopEvent::NotificationClosed {
+                    id: second,
+                    reason: CloseReason::Closed
+                }
+            ]
+        );
+        assert!(desktop.events().is_empty());
+        assert!(desktop.close_notification(second).is_err());
+    }
+
+    #[test]
+    fn test_tray_icon() {
+        let backend = MockBackend::new();
+        let mut desktop = DesktopService::from_backend(backend.clone());
+
+        let tray_icon = TrayIcon::new("test")
+            .item(TrayMenuItem::item("show", "Show"))
+            .item(TrayMenuItem::Separator)
+            .item(TrayMenuItem::disabled("quit", "Quit"));
+
+        desktop.show_tray_icon(&tray_icon).unwrap();
+        assert_eq!(backend.tray_icon(), Some(tray_icon));
+
+        // disabled items could not be clicked
+        assert!(backend.click_menu_item("show"));
+        assert!(!backend.click_menu_item("quit"));
+        assert_eq!(
+            desktop.events(),
+            vec![DesktopEvent::TrayMenuItemClicked(String::from("show"))]
+        );
+
+        desktop.hide_tray_icon().unwrap();
+        assert!(backend.tray_icon().is_none());
+        assert!(!backend.click_menu_item("show"));
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let mut desktop = DesktopService::from_backend(UnsupportedBackend);
+
+        assert_eq!(
+            desktop.notify(&Notification::new("Hello")),
+            Err(DesktopError::Unsupported)
+        );
+        assert!(desktop.show_tray_icon(&TrayIcon::new("test")).is_err());
+        assert!(desktop.tray_icon().is_none());
+    }
+}
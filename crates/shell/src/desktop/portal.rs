@@ -0,0 +1,162 @@
+use std::{
+    os::unix::ffi::OsStrExt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
+
+use dbus::{
+    arg::{prop_cast, PropMap, Variant},
+    blocking::Connection,
+    message::MatchRule,
+    Path,
+};
+
+use super::*;
+use crate::clipboard::file_uri_to_path;
+
+const PORTAL_NAME: &str = "org.freedesktop.portal.Desktop";
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+const FILE_CHOOSER_INTERFACE: &str = "org.freedesktop.portal.FileChooser";
+const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+static NEXT_TOKEN: AtomicUsize = AtomicUsize::new(0);
+
+type PortalFilter = (String, Vec<(u32, String)>);
+
+/// The `PortalFileDialog` shows the file dialogs of the desktop by the XDG desktop portal
+/// `org.freedesktop.portal.FileChooser`. It works also inside of a sandbox like Flatpak.
+#[derive(Default)]
+pub struct PortalFileDialog;
+
+impl PortalFileDialog {
+    /// Creates a new portal file dialog backend.
+    pub fn new() -> Self {
+        PortalFileDialog
+    }
+}
+
+impl FileDialogBackend for PortalFileDialog {
+    fn show(
+        &mut self,
+        request: &FileDialogRequest,
+        reply: FileDialogReply,
+    ) -> Result<(), DesktopError> {
+        let connection =
+            Connection::new_session().map_err(|e| DesktopError::Connection(e.to_string()))?;
+
+        // the response is subscribed before the call, the handle of the request object is
+        // predictable by the token
+        let token = format!("orbtk{}", NEXT_TOKEN.fetch_add(1, Ordering::Relaxed));
+        let sender = connection
+            .unique_name()
+            .trim_start_matches(':')
+            .replace('.', "_");
+        let handle = format!("{}/request/{}/{}", PORTAL_PATH, sender, token);
+
+        let (result_sender, result_receiver) = mpsc::channel();
+        let mut rule = MatchRule::new_signal(REQUEST_INTERFACE, "Response");
+        rule.path = Some(Path::from(handle));
+        connection
+            .add_match(rule, move |(response, results): (u32, PropMap), _, _| {
+                // 0 is success, 1 canceled by the user and 2 another error
+                let paths = if response == 0 {
+                    prop_cast::<Vec<String>>(&results, "uris")
+                        .map(|uris| {
+                            uris.iter()
+                                .filter_map(|uri| file_uri_to_path(uri))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                } else {
+                    vec![]
+                };
+                result_sender.send(paths).ok();
+                false
+            })
+            .map_err(|e| DesktopError::Request(e.to_string()))?;
+
+        let method = if request.kind == FileDialogKind::Save {
+            "SaveFile"
+        } else {
+            "OpenFile"
+        };
+
+        let _: (Path<'static>,) = connection
+            .with_proxy(PORTAL_NAME, PORTAL_PATH, TIMEOUT)
+            .method_call(
+                FILE_CHOOSER_INTERFACE,
+                method,
+                ("", request.display_title(), options(request, token)),
+            )
+            .map_err(|e| DesktopError::Request(e.to_string()))?;
+
+        thread::spawn(move || loop {
+            if connection.process(Duration::from_millis(100)).is_err() {
+                reply(vec![]);
+                return;
+            }
+
+            if let Ok(paths) = result_receiver.try_recv() {
+                reply(paths);
+                return;
+            }
+        });
+
+        Ok(())
+    }
+}
+
+fn options(request: &FileDialogRequest, token: String) -> PropMap {
+    let mut options = PropMap::new();
+    options.insert("handle_token".into(), Variant(Box::new(token)));
+    options.insert("modal".into(), Variant(Box::new(true)));
+
+    match request.kind {
+        FileDialogKind::OpenMultiple => {
+            options.insert("multiple".into(), Variant(Box::new(true)));
+        }
+        FileDialogKind::Folder => {
+            options.insert("directory".into(), Variant(Box::new(true)));
+        }
+        FileDialogKind::Save if !request.file_name.is_empty() => {
+            options.insert(
+                "current_name".into(),
+                Variant(Box::new(request.file_name.clone())),
+            );
+        }
+        _ => {}
+    }
+
+    // a filter is a list of glob patterns (0) or mime types (1)
+    let filters: Vec<PortalFilter> = request
+        .filters
+        .iter()
+        .map(|filter| {
+            let patterns = if filter.patterns.is_empty() {
+                vec![(0, String::from("*"))]
+            } else {
+                filter.patterns.iter().map(|p| (0, p.clone())).collect()
+            };
+            (filter.name.clone(), patterns)
+        })
+        .collect();
+
+    if let Some(current) = filters.first().cloned() {
+        options.insert("current_filter".into(), Variant(Box::new(current)));
+        options.insert("filters".into(), Variant(Box::new(filters)));
+    }
+
+    // the folder is a null terminated byte array
+    if let Some(folder) = &request.folder {
+        let mut folder = folder.as_os_str().as_bytes().to_vec();
+        folder.push(0);
+        options.insert("current_folder".into(), Variant(Box::new(folder)));
+    }
+
+    options
+}
@@ -87,6 +87,18 @@ pub enum Key {
     BraceRight,
     CurlyBraceLeft,
     CurlyBraceRight,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
 }
 
 impl From<Key> for &'static str {
@@ -398,6 +410,33 @@ pub struct MouseEvent {
     pub state: ButtonState,
 }
 
+/// Describes the phase of a touch.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TouchPhase {
+    /// A finger touched the screen.
+    Down,
+
+    /// A finger was moved on the screen.
+    Move,
+
+    /// A finger was lifted from the screen.
+    Up,
+
+    /// The touch was cancelled by the system, e.g. if the window lost the focus.
+    Cancel,
+}
+
+/// Represents a touch event of a single finger.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct TouchEvent {
+    /// Identifies the finger. The id is unique as long as the finger touches the screen.
+    pub id: u64,
+
+    pub position: Point,
+
+    pub phase: TouchPhase,
+}
+
 /// Represents a keyboard key event.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct KeyEvent {
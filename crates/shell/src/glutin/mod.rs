@@ -26,6 +26,7 @@ where
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
     event_loop: Vec<EventLoop<()>>,
+    last_window_closed: Option<Box<dyn FnMut() -> bool>>,
 }
 
 impl<A> Shell<A>
@@ -38,6 +39,7 @@ where
             window_shells: vec![],
             requests,
             event_loop: vec![EventLoop::new()],
+            last_window_closed: None,
         }
     }
 
@@ -71,6 +73,12 @@ where
         }
     }
 
+    /// Sets a handler that is called after the last window is closed. The glutin shell could not
+    /// create windows after its event loop is started, it quits in any case.
+    pub fn on_last_window_closed<F: FnMut() -> bool + 'static>(&mut self, handler: F) {
+        self.last_window_closed = Some(Box::new(handler));
+    }
+
     pub fn event_loop(&self) -> &EventLoop<()> {
         self.event_loop.get(0).unwrap()
     }
@@ -82,6 +90,10 @@ where
             .unwrap()
             .run(move |event, _, control_flow| {
                 if self.window_shells.is_empty() {
+                    if let Some(mut last_window_closed) = self.last_window_closed.take() {
+                        last_window_closed();
+                    }
+
                     *control_flow = ControlFlow::Exit;
                 }
 
@@ -1,16 +1,38 @@
 use std::sync::mpsc;
 
-use glutin::{event, event_loop::ControlFlow, window, ContextWrapper, PossiblyCurrent};
+use glutin::{
+    dpi::{LogicalPosition, LogicalSize},
+    event,
+    event_loop::ControlFlow,
+    window, ContextWrapper, PossiblyCurrent,
+};
 
 use raw_window_handle::HasRawWindowHandle;
 
 use crate::{
-    event::{ButtonState, MouseButton, MouseEvent},
+    event::{ButtonState, MouseButton, MouseEvent, TouchEvent, TouchPhase},
+    native::SystemClipboard,
     render::RenderContext2D,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowRequest,
 };
 
+fn cursor_icon(cursor_icon: CursorIcon) -> window::CursorIcon {
+    match cursor_icon {
+        CursorIcon::Default => window::CursorIcon::Default,
+        CursorIcon::Text => window::CursorIcon::Text,
+        CursorIcon::Pointer => window::CursorIcon::Hand,
+        CursorIcon::Crosshair => window::CursorIcon::Crosshair,
+        CursorIcon::Move => window::CursorIcon::Move,
+        CursorIcon::Wait => window::CursorIcon::Wait,
+        CursorIcon::NotAllowed => window::CursorIcon::NotAllowed,
+        CursorIcon::ResizeHorizontal => window::CursorIcon::EwResize,
+        CursorIcon::ResizeVertical => window::CursorIcon::NsResize,
+        CursorIcon::ResizeTopLeft => window::CursorIcon::NwseResize,
+        CursorIcon::ResizeTopRight => window::CursorIcon::NeswResize,
+    }
+}
+
 /// Represents a wrapper for a glutin window. It handles events, propagate them to
 /// the window adapter and handles the update and redraw pipeline.
 pub struct Window<A>
@@ -26,6 +48,9 @@ where
     close: bool,
     mouse_pos: (f64, f64),
     scale_factor: f64,
+    clipboard: Option<SystemClipboard>,
+    // the system clipboard is read again after the window is focused or clicked
+    has_clipboard_update: bool,
 }
 
 impl<A> Window<A>
@@ -41,6 +66,7 @@ where
     ) -> Self {
         let mut adapter = adapter;
         adapter.set_raw_window_handle(gl_context.window().raw_window_handle());
+        adapter.scale_factor_changed(scale_factor);
 
         Window {
             gl_context,
@@ -52,6 +78,8 @@ where
             close: false,
             mouse_pos: (0., 0.),
             scale_factor,
+            clipboard: SystemClipboard::new(),
+            has_clipboard_update: true,
         }
     }
 }
@@ -81,7 +109,18 @@ where
 
     /// Updates the clipboard.
     pub fn update_clipboard(&mut self) {
-        // todo
+        if !self.has_clipboard_update {
+            return;
+        }
+
+        self.has_clipboard_update = false;
+
+        if let Some(clipboard) = &mut self.clipboard {
+            for (kind, content) in clipboard.changes() {
+                self.adapter.clipboard_changed(kind, content);
+                self.update = true;
+            }
+        }
     }
 
     /// Drain events and propagate the events to the adapter.
@@ -94,11 +133,43 @@ where
                 if !window_id.eq(&self.id()) {
                     return;
                 }
-                self.adapter.resize(s.width as f64, s.height as f64);
-                self.render_context.resize(s.width as f64, s.height as f64);
+                let size = s.to_logical::<f64>(self.scale_factor);
+                self.adapter.resize(size.width, size.height);
+                self.render_context.resize(size.width, size.height);
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event:
+                    event::WindowEvent::ScaleFactorChanged {
+                        scale_factor,
+                        new_inner_size,
+                    },
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.scale_factor = *scale_factor;
+                self.render_context.set_scale_factor(*scale_factor);
+                self.adapter.scale_factor_changed(*scale_factor);
+
+                let size = new_inner_size.to_logical::<f64>(self.scale_factor);
+                self.adapter.resize(size.width, size.height);
+                self.render_context.resize(size.width, size.height);
                 self.update = true;
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Focused(focused),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.has_clipboard_update |= *focused;
+                *control_flow = ControlFlow::Wait;
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::CloseRequested,
                 window_id,
@@ -134,6 +205,12 @@ where
                     }
                 };
 
+                // e.g. the primary selection is pasted by the middle button
+                if state == ButtonState::Down {
+                    self.has_clipboard_update = true;
+                    self.update_clipboard();
+                }
+
                 let mouse_pos = self.mouse_pos;
 
                 self.adapter.mouse_event(MouseEvent {
@@ -176,6 +253,29 @@ where
                 self.redraw = true;
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Touch(touch),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                let position = touch.location.to_logical::<f64>(self.scale_factor);
+                let phase = match touch.phase {
+                    event::TouchPhase::Started => TouchPhase::Down,
+                    event::TouchPhase::Moved => TouchPhase::Move,
+                    event::TouchPhase::Ended => TouchPhase::Up,
+                    event::TouchPhase::Cancelled => TouchPhase::Cancel,
+                };
+                self.adapter.touch_event(TouchEvent {
+                    id: touch.id,
+                    position: (position.x, position.y).into(),
+                    phase,
+                });
+                self.update = true;
+                self.redraw = true;
+                *control_flow = ControlFlow::Wait;
+            }
             _ => *control_flow = ControlFlow::Wait,
         }
     }
@@ -185,19 +285,82 @@ where
         if let Some(request_receiver) = &self.request_receiver {
             for request in request_receiver.try_iter() {
                 match request {
-                    WindowRequest::Redraw => {
+                    // timed redraws are not supported yet, redraws immediately
+                    WindowRequest::Redraw | WindowRequest::RedrawAt(_) => {
                         self.update = true;
                         self.redraw = true;
                     }
                     WindowRequest::ChangeTitle(title) => {
-                        // todo fix
-                        // self.window.set_title(&title);
+                        self.gl_context.window().set_title(&title);
                         self.update = true;
                         self.redraw = true;
                     }
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::Resize(width, height) => {
+                        self.gl_context
+                            .window()
+                            .set_inner_size(LogicalSize::new(width, height));
+                    }
+                    WindowRequest::Move(x, y) => {
+                        self.gl_context
+                            .window()
+                            .set_outer_position(LogicalPosition::new(x, y));
+                    }
+                    WindowRequest::Minimize => {
+                        self.gl_context.window().set_minimized(true);
+                    }
+                    WindowRequest::Maximize => {
+                        self.gl_context.window().set_maximized(true);
+                    }
+                    WindowRequest::Restore => {
+                        self.gl_context.window().set_minimized(false);
+                        self.gl_context.window().set_maximized(false);
+                    }
+                    WindowRequest::Fullscreen(fullscreen) => {
+                        let window = self.gl_context.window();
+                        let monitor = if fullscreen {
+                            Some(window::Fullscreen::Borderless(window.current_monitor()))
+                        } else {
+                            None
+                        };
+                        window.set_fullscreen(monitor);
+                    }
+                    WindowRequest::ChangeMinSize(width, height) => {
+                        self.gl_context
+                            .window()
+                            .set_min_inner_size(Some(LogicalSize::new(width, height)));
+                    }
+                    WindowRequest::ChangeMaxSize(width, height) => {
+                        self.gl_context
+                            .window()
+                            .set_max_inner_size(Some(LogicalSize::new(width, height)));
+                    }
+                    WindowRequest::ChangeIcon(image) => {
+                        let icon = window::Icon::from_rgba(
+                            crate::native::argb_to_rgba(image.data()),
+                            image.width() as u32,
+                            image.height() as u32,
+                        );
+                        self.gl_context.window().set_window_icon(icon.ok());
+                    }
+                    WindowRequest::ChangeCursor(icon) => {
+                        self.gl_context.window().set_cursor_icon(cursor_icon(icon));
+                    }
+                    WindowRequest::Show => {
+                        self.gl_context.window().set_visible(true);
+                    }
+                    WindowRequest::Hide => {
+                        self.gl_context.window().set_visible(false);
+                    }
+                    WindowRequest::SetClipboard(kind, content) => {
+                        if let Some(clipboard) = &mut self.clipboard {
+                            clipboard.set(kind, content);
+                        }
+                    }
+                    // not supported by winit
+                    WindowRequest::Focus => {}
                 }
             }
         }
@@ -100,10 +100,9 @@ where
     /// Sets the bounds.
     pub fn bounds(mut self, bounds: impl Into<Rectangle>) -> Self {
         self.bounds = bounds.into();
-        let window_size = (self.bounds.width(), self.bounds.height());
-        let physical_size = PhysicalSize::new(window_size.0, window_size.1);
+        let logical_size = LogicalSize::new(self.bounds.width(), self.bounds.height());
 
-        self.window_builder = self.window_builder.with_inner_size(physical_size);
+        self.window_builder = self.window_builder.with_inner_size(logical_size);
         self
     }
 
@@ -27,6 +27,8 @@ runner.run()
 #[macro_use]
 extern crate lazy_static;
 
+pub mod clipboard;
+pub mod desktop;
 pub mod event;
 pub mod prelude;
 pub mod window_adapter;
@@ -59,6 +61,10 @@ pub mod platform;
 
 pub use orbtk_render::prelude as render;
 
+/// A point in time of a monotonic clock, like `std::time::Instant`. In the browser it's based on
+/// `performance.now()`, `std::time::Instant` is not available there.
+pub use instant::Instant;
+
 use std::{collections::HashMap, sync::mpsc};
 
 /// Used to send a request to the window.
@@ -67,11 +73,117 @@ pub enum WindowRequest {
     /// Request redraw of the `Windows`s content.
     Redraw,
 
+    /// Request redraw of the `Windows`s content at the given time e.g. for timers and animations.
+    /// Until then the shell sleeps if there is nothing else to do.
+    RedrawAt(Instant),
+
     /// Request to close the `Windows`.
     Close,
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to resize the content of the `Windows` to the given width and height.
+    Resize(f64, f64),
+
+    /// Request to move the `Windows` to the given screen position.
+    Move(f64, f64),
+
+    /// Request to minimize the `Windows`.
+    Minimize,
+
+    /// Request to maximize the `Windows`.
+    Maximize,
+
+    /// Request to restore the `Windows` after it was minimized or maximized.
+    Restore,
+
+    /// Request to enter (`true`) or leave (`false`) the fullscreen mode.
+    Fullscreen(bool),
+
+    /// Request to change the minimum size of the `Windows` content.
+    ChangeMinSize(f64, f64),
+
+    /// Request to change the maximum size of the `Windows` content.
+    ChangeMaxSize(f64, f64),
+
+    /// Request to change the icon of the `Windows`.
+    ChangeIcon(render::Image),
+
+    /// Request to change the shape of the mouse cursor over the `Windows`.
+    ChangeCursor(CursorIcon),
+
+    /// Request to show the `Windows`.
+    Show,
+
+    /// Request to hide the `Windows`.
+    Hide,
+
+    /// Request to bring the `Windows` to the front and focus it.
+    Focus,
+
+    /// Request to set the content of the system clipboard or primary selection.
+    SetClipboard(clipboard::ClipboardKind, clipboard::ClipboardContent),
+}
+
+/// Describes the shape of the mouse cursor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    /// The default arrow of the platform.
+    Default,
+
+    /// I-beam used over editable text.
+    Text,
+
+    /// Hand used over links and clickable elements.
+    Pointer,
+
+    Crosshair,
+
+    /// Used over elements that could be moved.
+    Move,
+
+    /// Indicates that the application is busy.
+    Wait,
+
+    /// Indicates that the action is not allowed e.g. while dragging.
+    NotAllowed,
+
+    /// Arrows to resize in horizontal direction e.g. over a vertical splitter.
+    ResizeHorizontal,
+
+    /// Arrows to resize in vertical direction e.g. over a horizontal splitter.
+    ResizeVertical,
+
+    /// Arrows to resize from the top left or bottom right corner.
+    ResizeTopLeft,
+
+    /// Arrows to resize from the top right or bottom left corner.
+    ResizeTopRight,
+}
+
+impl Default for CursorIcon {
+    fn default() -> Self {
+        CursorIcon::Default
+    }
+}
+
+impl From<&str> for CursorIcon {
+    fn from(s: &str) -> Self {
+        match s {
+            "Text" | "text" => CursorIcon::Text,
+            "Pointer" | "pointer" => CursorIcon::Pointer,
+            "Crosshair" | "crosshair" => CursorIcon::Crosshair,
+            "Move" | "move" => CursorIcon::Move,
+            "Wait" | "wait" => CursorIcon::Wait,
+            "NotAllowed" | "not_allowed" => CursorIcon::NotAllowed,
+            "ResizeHorizontal" | "resize_horizontal" => CursorIcon::ResizeHorizontal,
+            "ResizeVertical" | "resize_vertical" => CursorIcon::ResizeVertical,
+            "ResizeTopLeft" | "resize_top_left" => CursorIcon::ResizeTopLeft,
+            "ResizeTopRight" | "resize_top_right" => CursorIcon::ResizeTopRight,
+            _ => CursorIcon::Default,
+        }
+    }
 }
 
 /// Used to send a request to the application shell.
@@ -1,6 +1,6 @@
 //! This module contains a platform specific implementation of the window shell.
 
-use std::sync::mpsc;
+use std::{sync::mpsc, time::Instant};
 
 pub use super::native::*;
 
@@ -24,6 +24,9 @@ where
 {
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
+    last_window_closed: Option<Box<dyn FnMut() -> bool>>,
+    wake_sender: mpsc::Sender<()>,
+    wake_receiver: mpsc::Receiver<()>,
 }
 
 impl<A> Shell<A>
@@ -32,9 +35,14 @@ where
 {
     /// Creates a new application shell.
     pub fn new(requests: mpsc::Receiver<ShellRequest<A>>) -> Self {
+        let (wake_sender, wake_receiver) = mpsc::channel();
+
         Shell {
             window_shells: vec![],
             requests,
+            last_window_closed: None,
+            wake_sender,
+            wake_receiver,
         }
     }
 
@@ -60,18 +68,72 @@ where
         }
 
         for request in requests {
-            if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
-                self.create_window_from_settings(settings, adapter)
-                    .request_receiver(window_requests)
-                    .build();
+            self.handle_request(request);
+        }
+    }
+
+    fn handle_request(&mut self, request: ShellRequest<A>) {
+        if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
+            self.create_window_from_settings(settings, adapter)
+                .request_receiver(window_requests)
+                .build();
+        }
+    }
+
+    /// Sets a handler that is called after the last window is closed. If it returns `true` the
+    /// shell quits, otherwise it waits for a new window e.g. created by the handler. Without a
+    /// handler the shell quits.
+    pub fn on_last_window_closed<F: FnMut() -> bool + 'static>(&mut self, handler: F) {
+        self.last_window_closed = Some(Box::new(handler));
+    }
+
+    // Calls the last window closed handler and waits for a new window if the shell should not
+    // quit. Returns `false` if the shell should quit.
+    fn keep_running(&mut self) -> bool {
+        let quit = self
+            .last_window_closed
+            .as_mut()
+            .map_or(true, |last_window_closed| last_window_closed());
+
+        while !quit && self.window_shells.is_empty() {
+            match self.requests.recv() {
+                Ok(request) => self.handle_request(request),
+                Err(_) => return false,
             }
         }
+
+        !quit
+    }
+
+    // Sleeps until a window request arrives, the next requested redraw is due or the poll
+    // interval is elapsed. minifb could not wait for window events, they are polled instead.
+    // Returns immediately if a window has pending work.
+    fn wait(&self) {
+        if self.window_shells.iter().any(|w| w.needs_update()) {
+            return;
+        }
+
+        let timeout = self
+            .window_shells
+            .iter()
+            .filter_map(|w| w.redraw_at())
+            .min()
+            .map_or(POLL_INTERVAL, |redraw_at| {
+                redraw_at
+                    .saturating_duration_since(Instant::now())
+                    .min(POLL_INTERVAL)
+            });
+
+        let _ = self.wake_receiver.recv_timeout(timeout);
+
+        // the requests of all wake ups are received on the next iteration
+        for _ in self.wake_receiver.try_iter() {}
     }
 
     /// Runs (starts) the application shell and its windows.
     pub fn run(&mut self) {
         loop {
-            if self.window_shells.is_empty() {
+            if self.window_shells.is_empty() && !self.keep_running() {
                 return;
             }
 
@@ -97,6 +159,7 @@ where
             }
 
             self.receive_requests();
+            self.wait();
         }
     }
 }
@@ -1,16 +1,34 @@
-use std::{cell::RefCell, rc::Rc, sync::mpsc};
+use std::{cell::RefCell, rc::Rc, sync::mpsc, thread, time::Instant};
 
 use super::{KeyState, MouseState, WindowState};
 use crate::{
     event::{ButtonState, KeyEvent, MouseButton, MouseEvent},
+    native::SystemClipboard,
     render::RenderContext2D,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowRequest,
 };
 
 use orbtk_utils::Point;
 use raw_window_handle::HasRawWindowHandle;
 
+// minifb provides only a subset of the cursor shapes.
+fn cursor_style(cursor_icon: CursorIcon) -> minifb::CursorStyle {
+    match cursor_icon {
+        CursorIcon::Text => minifb::CursorStyle::Ibeam,
+        CursorIcon::Pointer => minifb::CursorStyle::OpenHand,
+        CursorIcon::Crosshair => minifb::CursorStyle::Crosshair,
+        CursorIcon::Move | CursorIcon::ResizeTopLeft | CursorIcon::ResizeTopRight => {
+            minifb::CursorStyle::ResizeAll
+        }
+        CursorIcon::ResizeHorizontal => minifb::CursorStyle::ResizeLeftRight,
+        CursorIcon::ResizeVertical => minifb::CursorStyle::ResizeUpDown,
+        CursorIcon::Default | CursorIcon::Wait | CursorIcon::NotAllowed => {
+            minifb::CursorStyle::Arrow
+        }
+    }
+}
+
 /// Represents a wrapper for a minifb window. It handles events, propagate them to
 /// the window adapter and handles the update and render pipeline.
 pub struct Window<A>
@@ -25,9 +43,14 @@ where
     mouse: MouseState,
     update: bool,
     redraw: bool,
+    redraw_at: Option<Instant>,
     close: bool,
     key_states: Vec<KeyState>,
     key_events: Rc<RefCell<Vec<KeyEvent>>>,
+    _request_thread: Option<thread::JoinHandle<()>>,
+    clipboard: Option<SystemClipboard>,
+    // the system clipboard is read again after the window is activated or clicked
+    has_clipboard_update: bool,
 }
 
 impl<A> Window<A>
@@ -41,9 +64,22 @@ where
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
         key_states: Vec<KeyState>,
         key_events: Rc<RefCell<Vec<KeyEvent>>>,
+        wake_up: mpsc::Sender<()>,
     ) -> Self {
         let mut adapter = adapter;
         adapter.set_raw_window_handle(window.raw_window_handle());
+        adapter.scale_factor_changed(render_context.scale_factor());
+
+        // each request wakes up the waiting shell
+        let (request_receiver, _request_thread) = match request_receiver {
+            Some(receiver) => {
+                let (receiver, thread) = super::forward_requests(receiver, move || {
+                    let _ = wake_up.send(());
+                });
+                (Some(receiver), Some(thread))
+            }
+            None => (None, None),
+        };
 
         Window {
             window,
@@ -54,9 +90,13 @@ where
             mouse: MouseState::default(),
             update: true,
             redraw: true,
+            redraw_at: None,
             close: false,
             key_states,
             key_events,
+            _request_thread,
+            clipboard: SystemClipboard::new(),
+            has_clipboard_update: true,
         }
     }
 }
@@ -76,13 +116,22 @@ where
 {
     fn push_mouse_event(&mut self, pressed: bool, button: MouseButton) {
         let state = if pressed {
+            // e.g. the primary selection is pasted by the middle button
+            self.has_clipboard_update = true;
+            self.update_clipboard();
             ButtonState::Down
         } else {
             ButtonState::Up
         };
 
+        // the mouse position is stored in physical pixels
+        let scale_factor = self.render_context.scale_factor();
+
         self.adapter.mouse_event(MouseEvent {
-            position: Point::new(self.mouse.mouse_pos.0 as f64, self.mouse.mouse_pos.1 as f64),
+            position: Point::new(
+                self.mouse.mouse_pos.0 as f64 / scale_factor,
+                self.mouse.mouse_pos.1 as f64 / scale_factor,
+            ),
             button,
             state,
         });
@@ -133,9 +182,30 @@ where
         self.window.is_open() && !self.close
     }
 
+    /// Check if the window has pending input or requests that are not yet updated and rendered.
+    pub fn needs_update(&self) -> bool {
+        self.update || self.redraw
+    }
+
+    /// Gets the time of the next requested redraw.
+    pub fn redraw_at(&self) -> Option<Instant> {
+        self.redraw_at
+    }
+
     /// Updates the clipboard.
     pub fn update_clipboard(&mut self) {
-        // todo
+        if !self.has_clipboard_update {
+            return;
+        }
+
+        self.has_clipboard_update = false;
+
+        if let Some(clipboard) = &mut self.clipboard {
+            for (kind, content) in clipboard.changes() {
+                self.adapter.clipboard_changed(kind, content);
+                self.update = true;
+            }
+        }
     }
 
     /// Drain events and propagate the events to the adapter.
@@ -145,7 +215,9 @@ where
         // mouse move
         if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
             if (pos.0.floor(), pos.1.floor()) != self.mouse.mouse_pos {
-                self.adapter.mouse(pos.0 as f64, pos.1 as f64);
+                let scale_factor = self.render_context.scale_factor();
+                self.adapter
+                    .mouse(pos.0 as f64 / scale_factor, pos.1 as f64 / scale_factor);
                 self.mouse.mouse_pos = (pos.0.floor(), pos.1.floor());
                 self.update = true;
             }
@@ -195,20 +267,18 @@ where
         // resize
         if self.window_state.size != self.window.get_size() {
             self.window_state.size = self.window.get_size();
-            self.render_context.resize(
-                self.window_state.size.0 as f64,
-                self.window_state.size.1 as f64,
-            );
-            self.adapter.resize(
-                self.window_state.size.0 as f64,
-                self.window_state.size.1 as f64,
-            );
+            let scale_factor = self.render_context.scale_factor();
+            let width = self.window_state.size.0 as f64 / scale_factor;
+            let height = self.window_state.size.1 as f64 / scale_factor;
+            self.render_context.resize(width, height);
+            self.adapter.resize(width, height);
             self.update = true;
         }
 
         if self.window_state.active != self.window.is_active() {
             self.adapter.active(self.window.is_active());
             self.window_state.active = self.window.is_active();
+            self.has_clipboard_update |= self.window_state.active;
         }
 
         // keys
@@ -237,6 +307,11 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    WindowRequest::RedrawAt(instant) => {
+                        if self.redraw_at.map_or(true, |redraw_at| instant < redraw_at) {
+                            self.redraw_at = Some(instant);
+                        }
+                    }
                     WindowRequest::ChangeTitle(title) => {
                         self.window.set_title(&title);
                         self.update = true;
@@ -245,6 +320,29 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::Move(x, y) => {
+                        self.window.set_position(x as isize, y as isize);
+                    }
+                    WindowRequest::ChangeCursor(cursor_icon) => {
+                        self.window.set_cursor_style(cursor_style(cursor_icon));
+                    }
+                    WindowRequest::SetClipboard(kind, content) => {
+                        if let Some(clipboard) = &mut self.clipboard {
+                            clipboard.set(kind, content);
+                        }
+                    }
+                    // not supported by minifb
+                    WindowRequest::Resize(..)
+                    | WindowRequest::Minimize
+                    | WindowRequest::Maximize
+                    | WindowRequest::Restore
+                    | WindowRequest::Fullscreen(_)
+                    | WindowRequest::ChangeMinSize(..)
+                    | WindowRequest::ChangeMaxSize(..)
+                    | WindowRequest::ChangeIcon(_)
+                    | WindowRequest::Show
+                    | WindowRequest::Hide
+                    | WindowRequest::Focus => {}
                 }
             }
         }
@@ -253,6 +351,13 @@ where
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         //super::CONSOLE.time("complete");
+        if let Some(redraw_at) = self.redraw_at {
+            if redraw_at <= Instant::now() {
+                self.redraw_at = None;
+                self.update = true;
+            }
+        }
+
         if !self.update {
             return;
         }
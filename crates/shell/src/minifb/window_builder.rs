@@ -1,6 +1,6 @@
 use std::{cell::RefCell, char, collections::HashMap, rc::Rc, sync::mpsc, time::Duration};
 
-use super::{KeyState, Shell, Window};
+use super::{scale_factor_from_env, KeyState, Shell, Window};
 use crate::{
     event::{ButtonState, Key, KeyEvent},
     render::RenderContext2D,
@@ -112,10 +112,14 @@ where
             ..Default::default()
         };
 
+        // minifb does not report the dpi of the monitor, the scale factor could only be set by
+        // the environment
+        let scale_factor = scale_factor_from_env().unwrap_or(1.0);
+
         let mut window = minifb::Window::new(
             self.title.as_str(),
-            self.bounds.width() as usize,
-            self.bounds.height() as usize,
+            (self.bounds.width() * scale_factor).round() as usize,
+            (self.bounds.height() * scale_factor).round() as usize,
             window_options,
         )
         .unwrap_or_else(|e| {
@@ -134,11 +138,14 @@ where
         window.set_position(self.bounds.x() as isize, self.bounds.y() as isize);
 
         let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
+        render_context.set_scale_factor(scale_factor);
 
         for (family, font) in self.fonts {
             render_context.register_font(&family, font);
         }
 
+        let wake_up = self.shell.wake_sender.clone();
+
         self.shell.window_shells.push(Window::new(
             window,
             self.adapter,
@@ -180,8 +187,21 @@ where
                 KeyState::new(minifb::Key::C, Key::C(false)),
                 KeyState::new(minifb::Key::V, Key::V(false)),
                 KeyState::new(minifb::Key::X, Key::X(false)),
+                KeyState::new(minifb::Key::F1, Key::F1),
+                KeyState::new(minifb::Key::F2, Key::F2),
+                KeyState::new(minifb::Key::F3, Key::F3),
+                KeyState::new(minifb::Key::F4, Key::F4),
+                KeyState::new(minifb::Key::F5, Key::F5),
+                KeyState::new(minifb::Key::F6, Key::F6),
+                KeyState::new(minifb::Key::F7, Key::F7),
+                KeyState::new(minifb::Key::F8, Key::F8),
+                KeyState::new(minifb::Key::F9, Key::F9),
+                KeyState::new(minifb::Key::F10, Key::F10),
+                KeyState::new(minifb::Key::F11, Key::F11),
+                KeyState::new(minifb::Key::F12, Key::F12),
             ],
             key_events,
+            wake_up,
         ));
     }
 }
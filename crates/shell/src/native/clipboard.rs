@@ -0,0 +1,168 @@
+use arboard::{Clipboard, Get, Set};
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "android"))))]
+use arboard::{GetExtLinux, LinuxClipboardKind, SetExtLinux};
+
+use crate::clipboard::{ClipboardContent, ClipboardKind};
+
+#[cfg(not(feature = "glupath"))]
+use crate::render::Image;
+
+/// The clipboard and the primary selection of the system, X11 and Wayland on Linux. It is shared
+/// by the desktop shells.
+///
+/// The content is exchanged in all its formats. On writing the system keeps one of them: the file
+/// paths, the image, the html together with its text or the text, in this order. The primary
+/// selection exists only on X11 and Wayland, on other systems it's kept by OrbTk.
+pub struct SystemClipboard {
+    clipboard: Clipboard,
+    // last known contents, only changes of other applications are reported
+    clipboard_content: ClipboardContent,
+    selection_content: ClipboardContent,
+}
+
+impl SystemClipboard {
+    /// Connects to the clipboard of the system. Returns `None` if it's not available, e.g. if
+    /// there is no display server.
+    pub fn new() -> Option<Self> {
+        Some(SystemClipboard {
+            clipboard: Clipboard::new().ok()?,
+            clipboard_content: ClipboardContent::default(),
+            selection_content: ClipboardContent::default(),
+        })
+    }
+
+    /// Sets the content of the clipboard or the primary selection of the system.
+    pub fn set(&mut self, kind: ClipboardKind, content: ClipboardContent) {
+        if let Some(set) = set(&mut self.clipboard, kind) {
+            let _ = write(set, &content);
+        }
+
+        // the content is read back, formats that are not kept are not reported as change
+        let content = self.read(kind).unwrap_or(content);
+        *self.content_mut(kind) = content;
+    }
+
+    /// Reads the content of the clipboard or the primary selection of the system. Returns it if it
+    /// was changed by another application since the last call.
+    pub fn changed(&mut self, kind: ClipboardKind) -> Option<ClipboardContent> {
+        let content = self.read(kind)?;
+
+        if *self.content_mut(kind) == content {
+            return None;
+        }
+
+        *self.content_mut(kind) = content.clone();
+        Some(content)
+    }
+
+    /// Returns the contents of the clipboard and the primary selection that were changed by other
+    /// applications since the last call.
+    pub fn changes(&mut self) -> Vec<(ClipboardKind, ClipboardContent)> {
+        let mut changes = vec![];
+
+        for kind in &[ClipboardKind::Clipboard, ClipboardKind::Selection] {
+            if let Some(content) = self.changed(*kind) {
+                changes.push((*kind, content));
+            }
+        }
+
+        changes
+    }
+
+    fn content_mut(&mut self, kind: ClipboardKind) -> &mut ClipboardContent {
+        match kind {
+            ClipboardKind::Clipboard => &mut self.clipboard_content,
+            ClipboardKind::Selection => &mut self.selection_content,
+        }
+    }
+
+    fn read(&mut self, kind: ClipboardKind) -> Option<ClipboardContent> {
+        let mut content = ClipboardContent::default();
+
+        content.text = get(&mut self.clipboard, kind)?.text().ok();
+        content.html = get(&mut self.clipboard, kind)?.html().ok();
+        content.files = get(&mut self.clipboard, kind)?
+            .file_list()
+            .unwrap_or_default();
+
+        #[cfg(not(feature = "glupath"))]
+        {
+            content.image = get(&mut self.clipboard, kind)?
+                .image()
+                .ok()
+                .and_then(|image| {
+                    Image::from_data(
+                        image.width as u32,
+                        image.height as u32,
+                        super::rgba_to_argb(&image.bytes),
+                    )
+                    .ok()
+                });
+        }
+
+        Some(content)
+    }
+}
+
+// Writes the richest format of the content, the system keeps only one of them.
+fn write(set: Set<'_>, content: &ClipboardContent) -> Result<(), arboard::Error> {
+    if !content.files.is_empty() {
+        return set.file_list(&content.files);
+    }
+
+    #[cfg(not(feature = "glupath"))]
+    {
+        if let Some(image) = &content.image {
+            return set.image(arboard::ImageData {
+                width: image.width() as usize,
+                height: image.height() as usize,
+                bytes: super::argb_to_rgba(image.data()).into(),
+            });
+        }
+    }
+
+    if let Some(html) = &content.html {
+        return set.html(html.as_str(), content.text.as_deref());
+    }
+
+    match &content.text {
+        Some(text) => set.text(text.as_str()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "android"))))]
+fn linux_kind(kind: ClipboardKind) -> LinuxClipboardKind {
+    match kind {
+        ClipboardKind::Clipboard => LinuxClipboardKind::Clipboard,
+        ClipboardKind::Selection => LinuxClipboardKind::Primary,
+    }
+}
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "android"))))]
+fn get(clipboard: &mut Clipboard, kind: ClipboardKind) -> Option<Get<'_>> {
+    Some(clipboard.get().clipboard(linux_kind(kind)))
+}
+
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "android"))))]
+fn set(clipboard: &mut Clipboard, kind: ClipboardKind) -> Option<Set<'_>> {
+    Some(clipboard.set().clipboard(linux_kind(kind)))
+}
+
+// there is no primary selection
+#[cfg(not(all(unix, not(any(target_os = "macos", target_os = "android")))))]
+fn get(clipboard: &mut Clipboard, kind: ClipboardKind) -> Option<Get<'_>> {
+    match kind {
+        ClipboardKind::Clipboard => Some(clipboard.get()),
+        ClipboardKind::Selection => None,
+    }
+}
+
+#[cfg(not(all(unix, not(any(target_os = "macos", target_os = "android")))))]
+fn set(clipboard: &mut Clipboard, kind: ClipboardKind) -> Option<Set<'_>> {
+    match kind {
+        ClipboardKind::Clipboard => Some(clipboard.set()),
+        ClipboardKind::Selection => None,
+    }
+}
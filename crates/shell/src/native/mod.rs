@@ -1,4 +1,21 @@
-use std::{collections::HashMap, sync::Mutex, time::Instant};
+use std::{
+    collections::HashMap,
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::WindowRequest;
+
+#[cfg(not(target_os = "redox"))]
+pub use self::clipboard::*;
+
+#[cfg(not(target_os = "redox"))]
+mod clipboard;
+
+/// Maximum time the shell sleeps between two checks of the window events on backends that
+/// cannot wait for them.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(16);
 
 lazy_static! {
     pub static ref CONSOLE: Console = Console {
@@ -31,3 +48,89 @@ impl Console {
         println!("{}", message.into());
     }
 }
+
+/// Forwards the requests of `receiver` on a separate thread to the returned receiver and calls
+/// `wake_up` after each request. It is used to wake up a shell that is waiting for events, also
+/// if the request is sent from another thread.
+pub fn forward_requests<F>(
+    receiver: mpsc::Receiver<WindowRequest>,
+    wake_up: F,
+) -> (mpsc::Receiver<WindowRequest>, thread::JoinHandle<()>)
+where
+    F: Fn() + Send + 'static,
+{
+    let (sender, forward_receiver) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        // ends if all senders or the forward receiver are dropped
+        for request in receiver.iter() {
+            if sender.send(request).is_err() {
+                break;
+            }
+
+            wake_up();
+        }
+    });
+
+    (forward_receiver, handle)
+}
+
+/// Converts the ARGB pixels of an image to RGBA bytes e.g. to create a window icon.
+pub fn argb_to_rgba(data: &[u32]) -> Vec<u8> {
+    data.iter()
+        .flat_map(|p| {
+            let [a, r, g, b] = p.to_be_bytes();
+            vec![r, g, b, a]
+        })
+        .collect()
+}
+
+/// Converts RGBA bytes to the ARGB pixels of an image.
+pub fn rgba_to_argb(data: &[u8]) -> Vec<u32> {
+    data.chunks_exact(4)
+        .map(|p| u32::from_be_bytes([p[3], p[0], p[1], p[2]]))
+        .collect()
+}
+
+/// Environment variable that overrides the scale factor of the monitor, e.g. `ORBTK_SCALE_FACTOR=2`.
+pub const SCALE_FACTOR_VAR: &str = "ORBTK_SCALE_FACTOR";
+
+/// Reads the scale factor from the `ORBTK_SCALE_FACTOR` environment variable. Returns `None` if the
+/// variable is not set or is not a positive number.
+pub fn scale_factor_from_env() -> Option<f64> {
+    std::env::var(SCALE_FACTOR_VAR)
+        .ok()?
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|scale_factor| scale_factor.is_finite() && *scale_factor > 0.0)
+}
+
+/// Converts the dpi of a monitor to a scale factor. 96 dpi is the scale factor `1.0`, the result
+/// is rounded to quarter steps and is never smaller than `1.0`.
+pub fn dpi_to_scale_factor(dpi: f64) -> f64 {
+    ((dpi / 96.0 * 4.0).round() / 4.0).max(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgba_to_argb() {
+        let pixels = vec![0xFF_FF_00_00, 0x80_00_40_FF];
+        let rgba = argb_to_rgba(&pixels);
+
+        assert_eq!(rgba, vec![0xFF, 0x00, 0x00, 0xFF, 0x00, 0x40, 0xFF, 0x80]);
+        assert_eq!(rgba_to_argb(&rgba), pixels);
+    }
+
+    #[test]
+    fn test_dpi_to_scale_factor() {
+        assert!((dpi_to_scale_factor(96.0) - 1.0).abs() < f64::EPSILON);
+        assert!((dpi_to_scale_factor(72.0) - 1.0).abs() < f64::EPSILON);
+        assert!((dpi_to_scale_factor(144.0) - 1.5).abs() < f64::EPSILON);
+        assert!((dpi_to_scale_factor(190.0) - 2.0).abs() < f64::EPSILON);
+        assert!((dpi_to_scale_factor(282.0) - 3.0).abs() < f64::EPSILON);
+    }
+}
@@ -1,6 +1,9 @@
 //! This module contains a platform specific implementation of the window shell.
 
-use std::sync::mpsc;
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
 
 pub use super::native::*;
 
@@ -24,6 +27,7 @@ where
 {
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
+    last_window_closed: Option<Box<dyn FnMut() -> bool>>,
 }
 
 impl<A> Shell<A>
@@ -35,6 +39,7 @@ where
         Shell {
             window_shells: vec![],
             requests,
+            last_window_closed: None,
         }
     }
 
@@ -60,18 +65,64 @@ where
         }
 
         for request in requests {
-            if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
-                self.create_window_from_settings(settings, adapter)
-                    .request_receiver(window_requests)
-                    .build();
+            self.handle_request(request);
+        }
+    }
+
+    fn handle_request(&mut self, request: ShellRequest<A>) {
+        if let ShellRequest::CreateWindow(adapter, settings, window_requests) = request {
+            self.create_window_from_settings(settings, adapter)
+                .request_receiver(window_requests)
+                .build();
+        }
+    }
+
+    /// Sets a handler that is called after the last window is closed. If it returns `true` the
+    /// shell quits, otherwise it waits for a new window e.g. created by the handler. Without a
+    /// handler the shell quits.
+    pub fn on_last_window_closed<F: FnMut() -> bool + 'static>(&mut self, handler: F) {
+        self.last_window_closed = Some(Box::new(handler));
+    }
+
+    // Calls the last window closed handler and waits for a new window if the shell should not
+    // quit. Returns `false` if the shell should quit.
+    fn keep_running(&mut self) -> bool {
+        let quit = self
+            .last_window_closed
+            .as_mut()
+            .map_or(true, |last_window_closed| last_window_closed());
+
+        while !quit && self.window_shells.is_empty() {
+            match self.requests.recv() {
+                Ok(request) => self.handle_request(request),
+                Err(_) => return false,
             }
         }
+
+        !quit
+    }
+
+    // Blocks until new events or requests arrive or the next requested redraw is due. Returns
+    // immediately if a window has pending work.
+    fn wait(&self) {
+        if self.window_shells.iter().any(|w| w.needs_update()) {
+            return;
+        }
+
+        let timeout = self
+            .window_shells
+            .iter()
+            .filter_map(|w| w.redraw_at())
+            .min()
+            .map(|redraw_at| redraw_at.saturating_duration_since(Instant::now()));
+
+        wait_for_events(timeout);
     }
 
     /// Runs (starts) the application shell and its windows.
     pub fn run(&mut self) {
         loop {
-            if self.window_shells.is_empty() {
+            if self.window_shells.is_empty() && !self.keep_running() {
                 return;
             }
 
@@ -97,6 +148,77 @@ where
             }
 
             self.receive_requests();
+            self.wait();
         }
     }
 }
+
+// Waits for the next sdl2 event. Window requests push an event to wake up the wait.
+#[cfg(not(target_os = "redox"))]
+fn wait_for_events(timeout: Option<Duration>) {
+    // the event is left in the queue and is drained by the windows
+    unsafe {
+        match timeout {
+            Some(timeout) => {
+                // rounds up, otherwise a remaining time below one millisecond would not wait
+                let millis = (timeout.as_micros() + 999) / 1000;
+                sdl2::sys::SDL_WaitEventTimeout(
+                    std::ptr::null_mut(),
+                    millis.min(i32::MAX as u128) as i32,
+                )
+            }
+            None => sdl2::sys::SDL_WaitEvent(std::ptr::null_mut()),
+        };
+    }
+}
+
+// Orbital events could not be awaited for several windows at once, the events are polled instead.
+#[cfg(target_os = "redox")]
+fn wait_for_events(timeout: Option<Duration>) {
+    std::thread::sleep(timeout.map_or(POLL_INTERVAL, |timeout| timeout.min(POLL_INTERVAL)));
+}
+
+// Gets the scale factor of the display that contains the given position from the dpi reported
+// by sdl2. The `ORBTK_SCALE_FACTOR` environment variable overrides the detected scale factor.
+#[cfg(not(target_os = "redox"))]
+fn scale_factor(x: i32, y: i32) -> f64 {
+    if let Some(scale_factor) = scale_factor_from_env() {
+        return scale_factor;
+    }
+
+    // sdl2 is initialized by orbclient, its context is used directly
+    unsafe {
+        let mut bounds = sdl2::sys::SDL_Rect {
+            x: 0,
+            y: 0,
+            w: 0,
+            h: 0,
+        };
+
+        let display = (0..sdl2::sys::SDL_GetNumVideoDisplays())
+            .find(|display| {
+                sdl2::sys::SDL_GetDisplayBounds(*display, &mut bounds) == 0
+                    && x >= bounds.x
+                    && x < bounds.x + bounds.w
+                    && y >= bounds.y
+                    && y < bounds.y + bounds.h
+            })
+            .unwrap_or(0);
+
+        let (mut ddpi, mut hdpi, mut vdpi) = (0.0, 0.0, 0.0);
+
+        if sdl2::sys::SDL_GetDisplayDPI(display, &mut ddpi, &mut hdpi, &mut vdpi) != 0
+            || hdpi <= 0.0
+        {
+            return 1.0;
+        }
+
+        dpi_to_scale_factor(hdpi as f64)
+    }
+}
+
+// Orbital does not report the dpi of its displays, only the environment variable is used.
+#[cfg(target_os = "redox")]
+fn scale_factor(_: i32, _: i32) -> f64 {
+    scale_factor_from_env().unwrap_or(1.0)
+}
@@ -1,15 +1,19 @@
-use std::{sync::mpsc, thread};
+use std::{sync::mpsc, thread, time::Instant};
 
 use super::MouseState;
 use crate::{
+    clipboard::{ClipboardContent, ClipboardKind},
     event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowRequest,
 };
 
 #[cfg(not(target_os = "redox"))]
-use sdl2::event;
+use sdl2::{event, mouse};
+
+#[cfg(not(target_os = "redox"))]
+use crate::native::SystemClipboard;
 
 use orbclient::Renderer;
 use raw_window_handle::HasRawWindowHandle;
@@ -28,34 +32,54 @@ where
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
     //window_state: WindowState,
     mouse: MouseState,
+    // physical pixels per logical unit
+    scale_factor: f64,
     update: bool,
     redraw: bool,
+    redraw_at: Option<Instant>,
     close: bool,
     has_clipboard_update: bool,
+    // last known text of the orbital clipboard
+    #[cfg(target_os = "redox")]
+    clipboard_text: Option<String>,
+    #[cfg(not(target_os = "redox"))]
+    clipboard: Option<SystemClipboard>,
     #[cfg(not(target_os = "redox"))]
     _sdl2_sync_thread: Option<thread::JoinHandle<()>>,
+    // the cursor has to be kept alive while it is displayed
+    #[cfg(not(target_os = "redox"))]
+    cursor: Option<mouse::Cursor>,
 }
 
-// internal method to sync if OrbClient backend is sdl2
+// internal method to sync if OrbClient backend is sdl2. Each request wakes up the shell that
+// waits for sdl2 events.
 #[cfg(not(target_os = "redox"))]
 fn init_sync(
     window: &orbclient::Window,
     receiver: mpsc::Receiver<WindowRequest>,
 ) -> (mpsc::Receiver<WindowRequest>, thread::JoinHandle<()>) {
-    let (internal_sender, internal_receiver) = mpsc::channel();
-
     let event_sender = window.event_sender();
 
-    let _sdl2_sync_thread = thread::spawn(move || loop {
-        if let Ok(request) = receiver.recv() {
-            let _ = internal_sender.send(request.clone());
-            if request == WindowRequest::Redraw {
-                let _ = event_sender.push_event(event::Event::RenderTargetsReset { timestamp: 0 });
-            }
-        }
-    });
+    super::forward_requests(receiver, move || {
+        let _ = event_sender.push_event(event::Event::RenderTargetsReset { timestamp: 0 });
+    })
+}
 
-    (internal_receiver, _sdl2_sync_thread)
+#[cfg(not(target_os = "redox"))]
+fn system_cursor(cursor_icon: CursorIcon) -> mouse::SystemCursor {
+    match cursor_icon {
+        CursorIcon::Default => mouse::SystemCursor::Arrow,
+        CursorIcon::Text => mouse::SystemCursor::IBeam,
+        CursorIcon::Pointer => mouse::SystemCursor::Hand,
+        CursorIcon::Crosshair => mouse::SystemCursor::Crosshair,
+        CursorIcon::Move => mouse::SystemCursor::SizeAll,
+        CursorIcon::Wait => mouse::SystemCursor::Wait,
+        CursorIcon::NotAllowed => mouse::SystemCursor::No,
+        CursorIcon::ResizeHorizontal => mouse::SystemCursor::SizeWE,
+        CursorIcon::ResizeVertical => mouse::SystemCursor::SizeNS,
+        CursorIcon::ResizeTopLeft => mouse::SystemCursor::SizeNWSE,
+        CursorIcon::ResizeTopRight => mouse::SystemCursor::SizeNESW,
+    }
 }
 
 impl<A> Window<A>
@@ -68,7 +92,11 @@ where
         adapter: A,
         render_context: RenderContext2D,
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        scale_factor: f64,
     ) -> Self {
+        let mut adapter = adapter;
+        adapter.scale_factor_changed(scale_factor);
+
         Window {
             window,
             adapter,
@@ -76,10 +104,13 @@ where
             request_receiver,
             // window_state: WindowState::default(),
             mouse: MouseState::default(),
+            scale_factor,
             update: true,
             redraw: true,
+            redraw_at: None,
             close: false,
             has_clipboard_update: true,
+            clipboard_text: None,
         }
     }
 
@@ -89,10 +120,12 @@ where
         adapter: A,
         render_context: RenderContext2D,
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        scale_factor: f64,
     ) -> Self {
         let mut adapter = adapter;
 
         adapter.set_raw_window_handle(window.raw_window_handle());
+        adapter.scale_factor_changed(scale_factor);
 
         let (request_receiver, _sdl2_sync_thread) = {
             if let Some(receiver) = request_receiver {
@@ -108,13 +141,17 @@ where
             adapter,
             render_context,
             _sdl2_sync_thread,
+            cursor: None,
             request_receiver,
             // window_state: WindowState::default(),
             mouse: MouseState::default(),
+            scale_factor,
             update: true,
             redraw: true,
+            redraw_at: None,
             close: false,
             has_clipboard_update: true,
+            clipboard: SystemClipboard::new(),
         }
     }
 
@@ -149,8 +186,21 @@ where
         !self.close
     }
 
+    /// Check if the window has pending input or requests that are not yet updated and rendered.
+    pub fn needs_update(&self) -> bool {
+        self.update || self.redraw
+    }
+
+    /// Gets the time of the next requested redraw.
+    pub fn redraw_at(&self) -> Option<Instant> {
+        self.redraw_at
+    }
+
     fn push_mouse_event(&mut self, pressed: bool, button: MouseButton) {
         let state = if pressed {
+            // e.g. the primary selection is pasted by the middle button
+            self.has_clipboard_update = true;
+            self.update_clipboard();
             ButtonState::Down
         } else {
             ButtonState::Up
@@ -197,6 +247,18 @@ where
                     orbclient::K_HOME => {
                         key = Key::Home;
                     }
+                    orbclient::K_F1 => key = Key::F1,
+                    orbclient::K_F2 => key = Key::F2,
+                    orbclient::K_F3 => key = Key::F3,
+                    orbclient::K_F4 => key = Key::F4,
+                    orbclient::K_F5 => key = Key::F5,
+                    orbclient::K_F6 => key = Key::F6,
+                    orbclient::K_F7 => key = Key::F7,
+                    orbclient::K_F8 => key = Key::F8,
+                    orbclient::K_F9 => key = Key::F9,
+                    orbclient::K_F10 => key = Key::F10,
+                    orbclient::K_F11 => key = Key::F11,
+                    orbclient::K_F12 => key = Key::F12,
                     _ => key = Key::Unknown,
                 };
                 String::default()
@@ -207,21 +269,101 @@ where
     }
 
     /// Updates the clipboard.
+    #[cfg(not(target_os = "redox"))]
     pub fn update_clipboard(&mut self) {
-        let mut clipboard_value = if self.has_clipboard_update() {
-            self.has_clipboard_update = false;
-            Some(self.window.clipboard())
-        } else {
-            None
-        };
+        if !self.has_clipboard_update() {
+            return;
+        }
+
+        self.has_clipboard_update = false;
+
+        if let Some(clipboard) = &mut self.clipboard {
+            for (kind, content) in clipboard.changes() {
+                self.adapter.clipboard_changed(kind, content);
+                self.update = true;
+            }
+        }
+    }
+
+    /// Updates the clipboard.
+    #[cfg(target_os = "redox")]
+    pub fn update_clipboard(&mut self) {
+        if !self.has_clipboard_update() {
+            return;
+        }
+
+        self.has_clipboard_update = false;
+        let text = self.window.clipboard();
+
+        // orbital supports only text, the adapter is notified only about new values
+        if self.clipboard_text.as_ref() != Some(&text) {
+            self.clipboard_text = Some(text.clone());
+            self.adapter
+                .clipboard_changed(ClipboardKind::Clipboard, ClipboardContent::from_text(text));
+        }
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    fn set_clipboard(&mut self, kind: ClipboardKind, content: ClipboardContent) {
+        if let Some(clipboard) = &mut self.clipboard {
+            clipboard.set(kind, content);
+        }
+    }
+
+    // orbital has only a text clipboard and no primary selection
+    #[cfg(target_os = "redox")]
+    fn set_clipboard(&mut self, kind: ClipboardKind, content: ClipboardContent) {
+        if kind != ClipboardKind::Clipboard {
+            return;
+        }
 
-        self.adapter.clipboard_update(&mut clipboard_value);
+        if let Some(text) = content.text {
+            self.window.set_clipboard(text.as_str());
+            self.clipboard_text = Some(text);
+        }
+    }
 
-        if let Some(value) = clipboard_value {
-            self.window.set_clipboard(value.as_str());
+    fn resize(&mut self, width: f64, height: f64) {
+        self.adapter.resize(width, height);
+        self.render_context.resize(width, height);
+        self.update = true;
+        self.redraw = true;
+    }
+
+    // Checks if the window is moved to a display with another scale factor. The logical size of
+    // the window is kept, its physical size is adjusted to the new scale factor.
+    fn update_scale_factor(&mut self) {
+        let scale_factor = super::scale_factor(self.window.x(), self.window.y());
+
+        if (scale_factor - self.scale_factor).abs() < f64::EPSILON {
+            return;
         }
+
+        let width = self.window.width() as f64 / self.scale_factor;
+        let height = self.window.height() as f64 / self.scale_factor;
+
+        self.scale_factor = scale_factor;
+        self.render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
+        self.window.set_size(
+            (width * scale_factor).round() as u32,
+            (height * scale_factor).round() as u32,
+        );
+        self.resize(width, height);
     }
 
+    #[cfg(not(target_os = "redox"))]
+    fn change_cursor(&mut self, cursor_icon: CursorIcon) {
+        if let Ok(cursor) = mouse::Cursor::from_system(system_cursor(cursor_icon)) {
+            cursor.set();
+            self.cursor = Some(cursor);
+        }
+    }
+
+    // orbital has no cursor shapes
+    #[cfg(target_os = "redox")]
+    fn change_cursor(&mut self, _: CursorIcon) {}
+
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
         for event in self.window.events() {
@@ -231,8 +373,10 @@ where
                     self.update = true;
                 }
                 orbclient::EventOption::Mouse(event) => {
-                    self.mouse.mouse_pos = (event.x as f32, event.y as f32);
-                    self.adapter.mouse(event.x as f64, event.y as f64);
+                    let x = event.x as f64 / self.scale_factor;
+                    let y = event.y as f64 / self.scale_factor;
+                    self.mouse.mouse_pos = (x as f32, y as f32);
+                    self.adapter.mouse(x, y);
                     self.update = true;
                 }
                 orbclient::EventOption::MouseRelative(_) => {}
@@ -278,16 +422,18 @@ where
                     self.close = true;
                     self.update = true
                 }
-                orbclient::EventOption::Focus(_) => {}
-                orbclient::EventOption::Move(_) => {}
+                orbclient::EventOption::Focus(event) => {
+                    self.has_clipboard_update |= event.focused;
+                }
+                orbclient::EventOption::Move(_) | orbclient::EventOption::Screen(_) => {
+                    self.update_scale_factor();
+                }
                 orbclient::EventOption::Resize(event) => {
-                    self.adapter.resize(event.width as f64, event.height as f64);
-                    self.render_context
-                        .resize(event.width as f64, event.height as f64);
-                    self.update = true;
-                    self.redraw = true;
+                    self.resize(
+                        event.width as f64 / self.scale_factor,
+                        event.height as f64 / self.scale_factor,
+                    );
                 }
-                orbclient::EventOption::Screen(_) => {}
                 orbclient::EventOption::Clipboard(_) => {}
                 orbclient::EventOption::Drop(event) => {
                     self.window.sync_path();
@@ -307,22 +453,59 @@ where
 
     /// Receives window request from the application and handles them.
     pub fn receive_requests(&mut self) {
-        if let Some(request_receiver) = &self.request_receiver {
-            for request in request_receiver.try_iter() {
-                match request {
-                    WindowRequest::Redraw => {
-                        self.update = true;
-                        self.redraw = true;
-                    }
-                    WindowRequest::ChangeTitle(title) => {
-                        self.window.set_title(title.as_str());
-                        self.update = true;
-                        self.redraw = true;
-                    }
-                    WindowRequest::Close => {
-                        self.close = true;
+        // collects the requests first, some of them need mutable access to the window
+        let requests: Vec<WindowRequest> = match &self.request_receiver {
+            Some(request_receiver) => request_receiver.try_iter().collect(),
+            None => return,
+        };
+
+        for request in requests {
+            match request {
+                WindowRequest::Redraw => {
+                    self.update = true;
+                    self.redraw = true;
+                }
+                WindowRequest::RedrawAt(instant) => {
+                    if self.redraw_at.map_or(true, |redraw_at| instant < redraw_at) {
+                        self.redraw_at = Some(instant);
                     }
                 }
+                WindowRequest::ChangeTitle(title) => {
+                    self.window.set_title(title.as_str());
+                    self.update = true;
+                    self.redraw = true;
+                }
+                WindowRequest::Close => {
+                    self.close = true;
+                }
+                WindowRequest::Resize(width, height) => {
+                    self.window.set_size(
+                        (width * self.scale_factor).round() as u32,
+                        (height * self.scale_factor).round() as u32,
+                    );
+                    self.resize(width, height);
+                }
+                WindowRequest::Move(x, y) => {
+                    self.window.set_pos(x as i32, y as i32);
+                    self.update_scale_factor();
+                }
+                WindowRequest::ChangeCursor(cursor_icon) => {
+                    self.change_cursor(cursor_icon);
+                }
+                WindowRequest::SetClipboard(kind, content) => {
+                    self.set_clipboard(kind, content);
+                }
+                // not supported by orbclient
+                WindowRequest::Minimize
+                | WindowRequest::Maximize
+                | WindowRequest::Restore
+                | WindowRequest::Fullscreen(_)
+                | WindowRequest::ChangeMinSize(..)
+                | WindowRequest::ChangeMaxSize(..)
+                | WindowRequest::ChangeIcon(_)
+                | WindowRequest::Show
+                | WindowRequest::Hide
+                | WindowRequest::Focus => {}
             }
         }
     }
@@ -330,6 +513,13 @@ where
     /// Runs update on the adapter.
     pub fn update(&mut self) {
         //super::CONSOLE.time("complete");
+        if let Some(redraw_at) = self.redraw_at {
+            if redraw_at <= Instant::now() {
+                self.redraw_at = None;
+                self.update = true;
+            }
+        }
+
         if !self.update {
             return;
         }
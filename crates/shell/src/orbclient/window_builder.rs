@@ -102,7 +102,8 @@ where
     pub fn build(self) {
         let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
 
-        let mut flags = vec![];
+        // the shell waits for events of all windows, draining the events of a window must not block
+        let mut flags = vec![orbclient::WindowFlag::Async];
 
         if self.resizeable {
             flags.push(orbclient::WindowFlag::Resizable);
@@ -112,7 +113,7 @@ where
             flags.push(orbclient::WindowFlag::Borderless);
         }
 
-        let window = orbclient::Window::new_flags(
+        let mut window = orbclient::Window::new_flags(
             self.bounds.x() as i32,
             self.bounds.y() as i32,
             self.bounds.width() as u32,
@@ -122,6 +123,14 @@ where
         )
         .expect("WindowBuilder: Could no create an orblient window.");
 
+        // the bounds are logical units, the window is resized to physical pixels after its display is known
+        let scale_factor = super::scale_factor(window.x(), window.y());
+        render_context.set_scale_factor(scale_factor);
+        window.set_size(
+            (self.bounds.width() * scale_factor).round() as u32,
+            (self.bounds.height() * scale_factor).round() as u32,
+        );
+
         for (family, font) in self.fonts {
             render_context.register_font(&family, font);
         }
@@ -131,6 +140,7 @@ where
             self.adapter,
             render_context,
             self.request_receiver,
+            scale_factor,
         ));
     }
 }
@@ -1,3 +1,4 @@
 pub use crate::{
-    event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    clipboard::*, event::*, platform::*, window_adapter::*, CursorIcon, Instant, ShellRequest,
+    WindowRequest, WindowSettings,
 };
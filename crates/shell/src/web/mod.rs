@@ -1,6 +1,10 @@
 //! This module contains a platform specific implementation of the window shell.
 use std::sync::mpsc;
-use stdweb::web::window;
+use stdweb::{
+    js,
+    unstable::TryInto,
+    web::{html_element::CanvasElement, window, CanvasRenderingContext2d},
+};
 
 use crate::prelude::*;
 
@@ -23,6 +27,33 @@ fn set_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
+// Gets the number of canvas pixels per css pixel, the scale factor of the window.
+fn scale_factor(ctx: &CanvasRenderingContext2d) -> f64 {
+    let backing_store_ratio: f64 = js! {
+        var ctx = @{ctx};
+         return ctx.webkitBackingStorePixelRatio ||
+             ctx.mozBackingStorePixelRatio ||
+             ctx.msBackingStorePixelRatio ||
+             ctx.oBackingStorePixelRatio ||
+             ctx.backingStorePixelRatio || 1;
+    }
+    .try_into()
+    .unwrap();
+
+    window().device_pixel_ratio() / backing_store_ratio
+}
+
+// Sizes the canvas in physical pixels, its css size is the given logical size.
+fn scale_canvas(canvas: &CanvasElement, width: f64, height: f64, scale_factor: f64) {
+    canvas.set_width((width * scale_factor).round() as u32);
+    canvas.set_height((height * scale_factor).round() as u32);
+
+    js! {
+        @{canvas}.style.width = @{width} + "px";
+        @{canvas}.style.height = @{height} + "px";
+    }
+}
+
 /// Initializes web stuff.
 pub fn initialize() {
     set_panic_hook();
@@ -64,6 +95,9 @@ where
         WindowBuilder::from_settings(settings, self, adapter)
     }
 
+    /// The web shell keeps running as long as the page is open, the handler is never called.
+    pub fn on_last_window_closed<F: FnMut() -> bool + 'static>(&mut self, _: F) {}
+
     /// Receives window request from the application and handles them.
     pub fn receive_requests(&mut self) {
         let mut requests = vec![];
@@ -9,6 +9,7 @@ pub struct EventState {
     pub touch_start_events: Rc<RefCell<Vec<event::TouchStart>>>,
     pub touch_end_events: Rc<RefCell<Vec<event::TouchEnd>>>,
     pub touch_move_events: Rc<RefCell<Vec<event::TouchMove>>>,
+    pub touch_cancel_events: Rc<RefCell<Vec<event::TouchCancel>>>,
     pub mouse_down_events: Rc<RefCell<Vec<event::MouseDownEvent>>>,
     pub scroll_events: Rc<RefCell<Vec<event::MouseWheelEvent>>>,
     pub key_up_events: Rc<RefCell<Vec<event::KeyUpEvent>>>,
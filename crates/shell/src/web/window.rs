@@ -4,15 +4,16 @@ use stdweb::{
     js,
     traits::*,
     unstable::TryInto,
-    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
+    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d, Touch},
 };
 
 use super::EventState;
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    clipboard::ClipboardKind,
+    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent, TouchEvent, TouchPhase},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    CursorIcon, WindowRequest,
 };
 
 use orbtk_utils::Point;
@@ -29,6 +30,7 @@ where
     event_state: EventState,
     canvas: CanvasElement,
     old_canvas: Option<CanvasElement>,
+    scale_factor: f64,
     update: bool,
     redraw: bool,
     close: bool,
@@ -44,6 +46,7 @@ where
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
         event_state: EventState,
         canvas: CanvasElement,
+        scale_factor: f64,
     ) -> Self {
         let mut adapter = adapter;
 
@@ -53,6 +56,7 @@ where
         };
 
         adapter.set_raw_window_handle(raw_window_handle::RawWindowHandle::Web(web_handle));
+        adapter.scale_factor_changed(scale_factor);
 
         Window {
             adapter,
@@ -61,6 +65,7 @@ where
             event_state,
             canvas,
             old_canvas: None,
+            scale_factor,
             update: true,
             redraw: true,
             close: false,
@@ -127,36 +132,26 @@ where
             self.update = true;
         }
 
-        // todo tmp solution to map touch events to mouse vent
-        while let Some(event) = self.event_state.touch_start_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
-                position: Point::new(
-                    event.changed_touches()[0].client_x() as f64,
-                    event.changed_touches()[0].client_y() as f64,
-                ),
-                button: MouseButton::Left,
-                state: ButtonState::Down,
-            });
+        for event in self.event_state.touch_start_events.borrow_mut().drain(..) {
+            touch_events(&mut self.adapter, event.changed_touches(), TouchPhase::Down);
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.touch_end_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
-                position: Point::new(
-                    event.changed_touches()[0].client_x() as f64,
-                    event.changed_touches()[0].client_y() as f64,
-                ),
-                button: MouseButton::Left,
-                state: ButtonState::Up,
-            });
+        for event in self.event_state.touch_move_events.borrow_mut().drain(..) {
+            touch_events(&mut self.adapter, event.changed_touches(), TouchPhase::Move);
+            self.update = true;
+        }
 
+        for event in self.event_state.touch_end_events.borrow_mut().drain(..) {
+            touch_events(&mut self.adapter, event.changed_touches(), TouchPhase::Up);
             self.update = true;
         }
 
-        while let Some(event) = self.event_state.touch_move_events.borrow_mut().pop() {
-            self.adapter.mouse(
-                event.changed_touches()[0].client_x() as f64,
-                event.changed_touches()[0].client_y() as f64,
+        for event in self.event_state.touch_cancel_events.borrow_mut().drain(..) {
+            touch_events(
+                &mut self.adapter,
+                event.changed_touches(),
+                TouchPhase::Cancel,
             );
             self.update = true;
         }
@@ -183,66 +178,55 @@ where
             self.update = true;
         }
 
-        while let Some(_) = self.event_state.resize_events.borrow_mut().pop() {
-            let window_size = (
-                window().inner_width() as f64,
-                window().inner_height() as f64,
-            );
-
-            let canvas: CanvasElement = document()
-                .create_element("canvas")
-                .unwrap()
-                .try_into()
-                .unwrap();
+        let mut resized = false;
 
-            canvas.set_width(window_size.0 as u32);
-            canvas.set_height(window_size.1 as u32);
+        while self.event_state.resize_events.borrow_mut().pop().is_some() {
+            resized = true;
+        }
 
-            js! {
-                document.body.style.padding = 0;
-                document.body.style.margin = 0;
-                @{&canvas}.style.display = "block";
-                @{&canvas}.style.margin = "0";
-            }
+        // the device pixel ratio changes e.g. if the browser window is moved to another monitor
+        let ctx: CanvasRenderingContext2d = self.canvas.get_context().unwrap();
+        let scale_factor = super::scale_factor(&ctx);
 
-            let device_pixel_ratio = window().device_pixel_ratio();
-            let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
+        if resized || (scale_factor - self.scale_factor).abs() > f64::EPSILON {
+            self.resize(scale_factor);
+        }
+    }
 
-            let backing_store_ratio = js! {
-                var ctx = @{&ctx};
-                 return ctx.webkitBackingStorePixelRatio ||
-                     ctx.mozBackingStorePixelRatio ||
-                     ctx.msBackingStorePixelRatio ||
-                     ctx.oBackingStorePixelRatio ||
-                     ctx.backingStorePixelRatio || 1;
-            };
+    // Replaces the canvas by a new one with the size of the browser window.
+    fn resize(&mut self, scale_factor: f64) {
+        let window_size = (
+            window().inner_width() as f64,
+            window().inner_height() as f64,
+        );
 
-            let ratio: f64 = js! {
-                return @{&device_pixel_ratio} / @{&backing_store_ratio};
-            }
+        let canvas: CanvasElement = document()
+            .create_element("canvas")
+            .unwrap()
             .try_into()
             .unwrap();
 
-            if device_pixel_ratio != backing_store_ratio {
-                let old_width = canvas.width();
-                let old_height = canvas.height();
-                canvas.set_width((old_width as f64 * ratio) as u32);
-                canvas.set_height((old_height as f64 * ratio) as u32);
-
-                js! {
-                    @{&canvas}.style.width = @{&old_width} + "px";
-                    @{&canvas}.style.height = @{&old_height} + "px";
-                }
+        js! {
+            document.body.style.padding = 0;
+            document.body.style.margin = 0;
+            @{&canvas}.style.display = "block";
+            @{&canvas}.style.margin = "0";
+        }
 
-                ctx.scale(ratio, ratio);
-            }
+        super::scale_canvas(&canvas, window_size.0, window_size.1, scale_factor);
 
-            self.render_context.set_canvas_render_context_2d(ctx);
-            self.adapter.resize(window_size.0, window_size.1);
-            self.old_canvas = Some(self.canvas.clone());
-            self.canvas = canvas;
-            self.update = true;
+        if (scale_factor - self.scale_factor).abs() > f64::EPSILON {
+            self.scale_factor = scale_factor;
+            self.render_context.set_scale_factor(scale_factor);
+            self.adapter.scale_factor_changed(scale_factor);
         }
+
+        self.render_context
+            .set_canvas_render_context_2d(canvas.get_context().unwrap());
+        self.adapter.resize(window_size.0, window_size.1);
+        self.old_canvas = Some(self.canvas.clone());
+        self.canvas = canvas;
+        self.update = true;
     }
 
     /// Receives window request from the application and handles them.
@@ -250,7 +234,8 @@ where
         if let Some(request_receiver) = &self.request_receiver {
             for request in request_receiver.try_iter() {
                 match request {
-                    WindowRequest::Redraw => {
+                    // timed redraws are not supported yet, redraws immediately
+                    WindowRequest::Redraw | WindowRequest::RedrawAt(_) => {
                         self.update = true;
                         self.redraw = true;
                     }
@@ -262,6 +247,70 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    WindowRequest::Fullscreen(fullscreen) => {
+                        if fullscreen {
+                            js! { document.documentElement.requestFullscreen(); }
+                        } else {
+                            js! {
+                                if (document.fullscreenElement) {
+                                    document.exitFullscreen();
+                                }
+                            }
+                        }
+                    }
+                    WindowRequest::ChangeIcon(image) => {
+                        js! {
+                            var link = document.querySelector("link[rel~='icon']");
+                            if (!link) {
+                                link = document.createElement("link");
+                                link.rel = "icon";
+                                document.head.appendChild(link);
+                            }
+                            link.href = @{image.source};
+                        }
+                    }
+                    WindowRequest::ChangeCursor(cursor_icon) => {
+                        js! { document.body.style.cursor = @{css_cursor(cursor_icon)}; }
+                    }
+                    WindowRequest::Show => {
+                        js! { document.body.style.visibility = "visible"; }
+                    }
+                    WindowRequest::Hide => {
+                        js! { document.body.style.visibility = "hidden"; }
+                    }
+                    WindowRequest::Focus => {
+                        js! { window.focus(); }
+                    }
+                    // browsers have no primary selection and allow to read the clipboard only
+                    // with the permission of the user, the content of other applications is not
+                    // read
+                    WindowRequest::SetClipboard(ClipboardKind::Clipboard, content) => {
+                        js! {
+                            var text = @{content.text};
+                            var html = @{content.html};
+
+                            if (navigator.clipboard) {
+                                if (html !== null && window.ClipboardItem) {
+                                    var items = { "text/html": new Blob([html], { type: "text/html" }) };
+                                    if (text !== null) {
+                                        items["text/plain"] = new Blob([text], { type: "text/plain" });
+                                    }
+                                    navigator.clipboard.write([new ClipboardItem(items)]);
+                                } else if (text !== null) {
+                                    navigator.clipboard.writeText(text);
+                                }
+                            }
+                        }
+                    }
+                    // the canvas fills the browser window, it could not be moved or resized
+                    WindowRequest::Resize(..)
+                    | WindowRequest::Move(..)
+                    | WindowRequest::Minimize
+                    | WindowRequest::Maximize
+                    | WindowRequest::Restore
+                    | WindowRequest::ChangeMinSize(..)
+                    | WindowRequest::ChangeMaxSize(..)
+                    | WindowRequest::SetClipboard(ClipboardKind::Selection, _) => {}
                 }
             }
         }
@@ -296,6 +345,33 @@ where
 
 // -- Helpers --
 
+fn css_cursor(cursor_icon: CursorIcon) -> &'static str {
+    match cursor_icon {
+        CursorIcon::Default => "default",
+        CursorIcon::Text => "text",
+        CursorIcon::Pointer => "pointer",
+        CursorIcon::Crosshair => "crosshair",
+        CursorIcon::Move => "move",
+        CursorIcon::Wait => "wait",
+        CursorIcon::NotAllowed => "not-allowed",
+        CursorIcon::ResizeHorizontal => "ew-resize",
+        CursorIcon::ResizeVertical => "ns-resize",
+        CursorIcon::ResizeTopLeft => "nwse-resize",
+        CursorIcon::ResizeTopRight => "nesw-resize",
+    }
+}
+
+// Sends an event for each changed finger of a web touch event.
+fn touch_events<A: WindowAdapter>(adapter: &mut A, touches: Vec<Touch>, phase: TouchPhase) {
+    for touch in touches {
+        adapter.touch_event(TouchEvent {
+            id: touch.identifier() as u64,
+            position: Point::new(touch.client_x() as f64, touch.client_y() as f64),
+            phase,
+        });
+    }
+}
+
 fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     match button {
         event::MouseButton::Wheel => MouseButton::Middle,
@@ -323,6 +399,18 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         "Enter" => Key::Enter,
         "OSLeft" | "OSRight" => Key::Home,
         "CapsLock" => Key::CapsLock,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
         _ => {
             text = key.clone();
             Key::from(key.chars().next().unwrap())
@@ -333,3 +421,19 @@ fn get_key(code: &str, key: String) -> (Key, String) {
 }
 
 // -- Helpers --
+
+fn css_cursor(cursor_icon: CursorIcon) -> &'static str {
+    match cursor_icon {
+        CursorIcon::Default => "default",
+        CursorIcon::Text => "text",
+        CursorIcon::Pointer => "pointer",
+        CursorIcon::Crosshair => "crosshair",
+        CursorIcon::Move => "move",
+        CursorIcon::Wait => "wait",
+        CursorIcon::NotAllowed => "not-allowed",
+        CursorIcon::ResizeHorizontal => "ew-resize",
+        CursorIcon::ResizeVertical => "ns-resize",
+        CursorIcon::ResizeTopLeft => "nwse-resize",
+        CursorIcon::ResizeTopRight => "nesw-resize",
+    }
+}
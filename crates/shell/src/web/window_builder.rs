@@ -143,6 +143,7 @@ where
         let touch_start = Rc::new(RefCell::new(vec![]));
         let touch_end = Rc::new(RefCell::new(vec![]));
         let touch_move = Rc::new(RefCell::new(vec![]));
+        let touch_cancel = Rc::new(RefCell::new(vec![]));
         let mouse_down = Rc::new(RefCell::new(vec![]));
         let scroll = Rc::new(RefCell::new(vec![]));
         let key_down = Rc::new(RefCell::new(vec![]));
@@ -199,6 +200,14 @@ where
                 touch_move_c.borrow_mut().push(e);
             });
 
+        let touch_cancel_c = touch_cancel.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::TouchCancel| {
+                touch_cancel_c.borrow_mut().push(e);
+            });
+
         let mouse_move_c = mouse_move.clone();
         document()
             .body()
@@ -239,38 +248,11 @@ where
         document().body().unwrap().append_child(&canvas);
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
 
-        let device_pixel_ratio = window().device_pixel_ratio();
-
-        let backing_store_ratio = js! {
-            var ctx = @{&ctx};
-             return ctx.webkitBackingStorePixelRatio ||
-                 ctx.mozBackingStorePixelRatio ||
-                 ctx.msBackingStorePixelRatio ||
-                 ctx.oBackingStorePixelRatio ||
-                 ctx.backingStorePixelRatio || 1;
-        };
-
-        let ratio: f64 = js! {
-            return @{&device_pixel_ratio} / @{&backing_store_ratio};
-        }
-        .try_into()
-        .unwrap();
-
-        if device_pixel_ratio != backing_store_ratio {
-            let old_width = canvas.width();
-            let old_height = canvas.height();
-            canvas.set_width((old_width as f64 * ratio) as u32);
-            canvas.set_height((old_height as f64 * ratio) as u32);
-
-            js! {
-                @{&canvas}.style.width = @{&old_width} + "px";
-                @{&canvas}.style.height = @{&old_height} + "px";
-            }
-
-            ctx.scale(ratio, ratio);
-        }
+        let scale_factor = super::scale_factor(&ctx);
+        super::scale_canvas(&canvas, window_size.0, window_size.1, scale_factor);
 
-        let render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        let mut render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        render_context.set_scale_factor(scale_factor);
 
         document().set_title(self.title.as_str());
 
@@ -286,6 +268,7 @@ where
                 touch_start_events: touch_start,
                 touch_end_events: touch_end,
                 touch_move_events: touch_move,
+                touch_cancel_events: touch_cancel,
                 mouse_down_events: mouse_down,
                 scroll_events: scroll,
                 key_down_events: key_down,
@@ -293,6 +276,7 @@ where
                 resize_events: resize,
             },
             canvas,
+            scale_factor,
         ));
     }
 }
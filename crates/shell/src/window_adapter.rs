@@ -1,7 +1,11 @@
 //! This module contains traits to inject custom logic into the window shell.
 
 use crate::render::RenderContext2D;
-use crate::{event::*, utils::Point};
+use crate::{
+    clipboard::{ClipboardContent, ClipboardKind},
+    event::*,
+    utils::Point,
+};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
@@ -9,12 +13,18 @@ pub trait WindowAdapter {
     /// Sets raw window handle.
     fn set_raw_window_handle(&mut self, raw_window_handle: raw_window_handle::RawWindowHandle);
 
-    /// Used to update the clipboard, could be used to read and set the current clipboard value.
-    fn clipboard_update(&mut self, value: &mut Option<String>);
+    /// Is called after the content of the system clipboard or primary selection is changed by
+    /// another application.
+    fn clipboard_changed(&mut self, _kind: ClipboardKind, _content: ClipboardContent) {}
 
-    /// Is called after the window is resized.
+    /// Is called after the window is resized. The size is given in logical units.
     fn resize(&mut self, _width: f64, _height: f64) {}
 
+    /// Is called after the scale factor of the window is changed, e.g. if the window is moved to
+    /// a monitor with a different dpi. The scale factor is the number of physical pixels per
+    /// logical unit.
+    fn scale_factor_changed(&mut self, _scale_factor: f64) {}
+
     /// Is called after the mouse was moved.
     fn mouse(&mut self, _x: f64, _y: f64) {}
 
@@ -24,6 +34,9 @@ pub trait WindowAdapter {
     /// Is called if mouse wheel or trackpad detect scroll event.
     fn scroll(&mut self, _delta_x: f64, _delta_y: f64) {}
 
+    /// Is called after a finger touched, moved on or left the screen.
+    fn touch_event(&mut self, _event: TouchEvent) {}
+
     /// Is called after the state of a keyboard key is changed.
     fn key_event(&mut self, _event: KeyEvent) {}
 
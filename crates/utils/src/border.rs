@@ -1,11 +1,11 @@
-use super::{Brush, Thickness};
+use super::{Brush, CornerRadius, Thickness};
 
 /// Used to build a border, specifying additional details.
 #[derive(Default)]
 pub struct BorderBuilder {
     brush: Brush,
     thickness: Thickness,
-    radius: f64,
+    radius: CornerRadius,
 }
 
 impl BorderBuilder {
@@ -27,8 +27,8 @@ impl BorderBuilder {
     }
 
     /// Inserts a border radius.
-    pub fn radius(mut self, radius: f64) -> Self {
-        self.radius = radius;
+    pub fn radius<R: Into<CornerRadius>>(mut self, radius: R) -> Self {
+        self.radius = radius.into();
         self
     }
 
@@ -47,7 +47,7 @@ impl BorderBuilder {
 pub struct Border {
     brush: Brush,
     thickness: Thickness,
-    radius: f64,
+    radius: CornerRadius,
 }
 
 impl Border {
@@ -77,13 +77,13 @@ impl Border {
     }
 
     /// Gets the radius.
-    pub fn radius(&self) -> f64 {
+    pub fn radius(&self) -> CornerRadius {
         self.radius
     }
 
     /// Sets the radius.
-    pub fn set_radius(&mut self, radius: f64) {
-        self.radius = radius
+    pub fn set_radius<R: Into<CornerRadius>>(&mut self, radius: R) {
+        self.radius = radius.into();
     }
 }
 
@@ -102,10 +102,10 @@ pub trait Bordered {
     fn set_border_brush(&mut self, brush: Brush);
 
     /// Gets the border radius.
-    fn border_radius(&self) -> f64;
+    fn border_radius(&self) -> CornerRadius;
 
     /// Sets the border radius.
-    fn set_border_radius(&mut self, radius: f64);
+    fn set_border_radius(&mut self, radius: CornerRadius);
 
     /// Gets the complete border.
     fn border(&self) -> &Border;
@@ -144,7 +144,7 @@ mod tests {
 
         let builder = BorderBuilder::new();
         let border = builder.radius(radius).build();
-        assert_eq!(border.radius(), radius);
+        assert_eq!(border.radius(), CornerRadius::from(radius));
     }
 
     #[test]
@@ -173,6 +173,6 @@ mod tests {
 
         let mut border = Border::default();
         border.set_radius(radius);
-        assert_eq!(border.radius(), radius);
+        assert_eq!(border.radius(), CornerRadius::from(radius));
     }
 }
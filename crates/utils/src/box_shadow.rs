@@ -0,0 +1,221 @@
+use crate::{Color, Point, Value};
+
+/// Describes the shadow of a box e.g. a popup or a card.
+///
+/// A box shadow could be created from a string like a css box shadow: `"x y [blur [spread]] color [inset]"`.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::BoxShadow;
+/// let shadow = BoxShadow::from("0 2 8 0 #66000000");
+/// let inset_shadow = BoxShadow::from("0 1 4 #40000000 inset");
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct BoxShadow {
+    /// Offset of the shadow relative to the box.
+    pub offset: Point,
+
+    /// Blur radius of the shadow. The shadow is sharp if the radius is `0`.
+    pub blur: f64,
+
+    /// Grows the shadow in each direction, negative values shrink it.
+    pub spread: f64,
+
+    /// Color of the shadow.
+    pub color: Color,
+
+    /// If `true` the shadow is drawn inside of the box.
+    pub inset: bool,
+}
+
+impl BoxShadow {
+    /// Creates a new box shadow with the given parameters.
+    pub fn new(offset: impl Into<Point>, blur: f64, spread: f64, color: impl Into<Color>) -> Self {
+        BoxShadow {
+            offset: offset.into(),
+            blur,
+            spread,
+            color: color.into(),
+            inset: false,
+        }
+    }
+
+    /// Gets the offset.
+    pub fn offset(&self) -> Point {
+        self.offset
+    }
+
+    /// Sets the offset.
+    pub fn set_offset(&mut self, offset: impl Into<Point>) {
+        self.offset = offset.into();
+    }
+
+    /// Gets the blur radius.
+    pub fn blur(&self) -> f64 {
+        self.blur
+    }
+
+    /// Sets the blur radius.
+    pub fn set_blur(&mut self, blur: f64) {
+        self.blur = blur;
+    }
+
+    /// Gets the spread.
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    /// Sets the spread.
+    pub fn set_spread(&mut self, spread: f64) {
+        self.spread = spread;
+    }
+
+    /// Gets the color.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Sets the color.
+    pub fn set_color(&mut self, color: impl Into<Color>) {
+        self.color = color.into();
+    }
+
+    /// Gets inset.
+    pub fn inset(&self) -> bool {
+        self.inset
+    }
+
+    /// Sets inset.
+    pub fn set_inset(&mut self, inset: bool) {
+        self.inset = inset;
+    }
+
+    /// Checks if the shadow is drawn. A shadow with a transparent color is not drawn.
+    pub fn is_visible(&self) -> bool {
+        self.color.a() > 0
+    }
+}
+
+impl From<&str> for BoxShadow {
+    fn from(s: &str) -> Self {
+        let mut numbers = vec![];
+        let mut color = None;
+        let mut inset = false;
+
+        for part in s.split_whitespace() {
+            if part == "none" {
+                return BoxShadow::default();
+            }
+
+            if part == "inset" {
+                inset = true;
+            } else if let Ok(number) = part.parse::<f64>() {
+                numbers.push(number);
+            } else {
+                color = Some(Color::from(part));
+            }
+        }
+
+        if numbers.len() < 2 {
+            return BoxShadow::default();
+        }
+
+        BoxShadow {
+            offset: Point::new(numbers[0], numbers[1]),
+            blur: numbers.get(2).copied().unwrap_or(0.0),
+            spread: numbers.get(3).copied().unwrap_or(0.0),
+            color: color.unwrap_or_else(|| Color::rgb(0, 0, 0)),
+            inset,
+        }
+    }
+}
+
+impl From<String> for BoxShadow {
+    fn from(s: String) -> Self {
+        BoxShadow::from(s.as_str())
+    }
+}
+
+impl From<Value> for BoxShadow {
+    fn from(v: Value) -> Self {
+        match v.0 {
+            ron::Value::String(value) => BoxShadow::from(value.as_str()),
+            ron::Value::Map(map) => {
+                let mut box_shadow = BoxShadow::new((0.0, 0.0), 0.0, 0.0, Color::rgb(0, 0, 0));
+
+                for (key, value) in map.iter() {
+                    if let Ok(key) = key.clone().into_rust::<String>() {
+                        match key.as_str() {
+                            "x" => box_shadow
+                                .offset
+                                .set_x(value.clone().into_rust::<f64>().unwrap_or(0.0)),
+                            "y" => box_shadow
+                                .offset
+                                .set_y(value.clone().into_rust::<f64>().unwrap_or(0.0)),
+                            "blur" => box_shadow.blur = value.clone().into_rust().unwrap_or(0.0),
+                            "spread" => {
+                                box_shadow.spread = value.clone().into_rust().unwrap_or(0.0)
+                            }
+                            "color" => {
+                                if let Ok(color) = value.clone().into_rust::<String>() {
+                                    box_shadow.color = Color::from(color);
+                                }
+                            }
+                            "inset" => {
+                                box_shadow.inset = value.clone().into_rust().unwrap_or(false)
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                box_shadow
+            }
+            _ => BoxShadow::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let shadow = BoxShadow::from("1 2 8 3 #ff0000");
+        assert_eq!(shadow.offset(), Point::new(1.0, 2.0));
+        assert!((shadow.blur() - 8.0).abs() < f64::EPSILON);
+        assert!((shadow.spread() - 3.0).abs() < f64::EPSILON);
+        assert_eq!(shadow.color(), Color::rgb(255, 0, 0));
+        assert!(!shadow.inset());
+
+        let shadow = BoxShadow::from("0 1 4 #40000000 inset");
+        assert!((shadow.blur() - 4.0).abs() < f64::EPSILON);
+        assert!((shadow.spread() - 0.0).abs() < f64::EPSILON);
+        assert_eq!(shadow.color(), Color::rgba(0, 0, 0, 0x40));
+        assert!(shadow.inset());
+
+        assert!(!BoxShadow::from("none").is_visible());
+        assert!(!BoxShadow::from("#000000").is_visible());
+    }
+
+    #[test]
+    fn test_from_value() {
+        let value = ron::from_str::<ron::Value>(
+            "{ \"y\": 2, \"blur\": 6, \"color\": \"#000000\", \"inset\": true }",
+        )
+        .unwrap();
+        let shadow = BoxShadow::from(Value(value));
+
+        assert_eq!(shadow.offset(), Point::new(0.0, 2.0));
+        assert!((shadow.blur() - 6.0).abs() < f64::EPSILON);
+        assert_eq!(shadow.color(), Color::rgb(0, 0, 0));
+        assert!(shadow.inset());
+
+        let value = ron::from_str::<ron::Value>("\"0 4 12 #33000000\"").unwrap();
+        let shadow = BoxShadow::from(Value(value));
+        assert_eq!(shadow.offset(), Point::new(0.0, 4.0));
+        assert!(shadow.is_visible());
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Size, Value};
+
+/// Describes the radii of the four corners of a rectangle e.g. a border radius.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::CornerRadius;
+/// let uniform = CornerRadius::from(4.0);
+/// let tab = CornerRadius::new(4.0, 4.0, 0.0, 0.0);
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+pub struct CornerRadius {
+    /// Radius of the top left corner.
+    pub top_left: f64,
+
+    /// Radius of the top right corner.
+    pub top_right: f64,
+
+    /// Radius of the bottom right corner.
+    pub bottom_right: f64,
+
+    /// Radius of the bottom left corner.
+    pub bottom_left: f64,
+}
+
+impl CornerRadius {
+    /// Create a new corner radius with the given parameters in clockwise order starting at the
+    /// top left corner.
+    pub fn new(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        CornerRadius {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Gets top left.
+    pub fn top_left(&self) -> f64 {
+        self.top_left
+    }
+
+    /// Sets top left.
+    pub fn set_top_left(&mut self, top_left: f64) {
+        self.top_left = top_left;
+    }
+
+    /// Gets top right.
+    pub fn top_right(&self) -> f64 {
+        self.top_right
+    }
+
+    /// Sets top right.
+    pub fn set_top_right(&mut self, top_right: f64) {
+        self.top_right = top_right;
+    }
+
+    /// Gets bottom right.
+    pub fn bottom_right(&self) -> f64 {
+        self.bottom_right
+    }
+
+    /// Sets bottom right.
+    pub fn set_bottom_right(&mut self, bottom_right: f64) {
+        self.bottom_right = bottom_right;
+    }
+
+    /// Gets bottom left.
+    pub fn bottom_left(&self) -> f64 {
+        self.bottom_left
+    }
+
+    /// Sets bottom left.
+    pub fn set_bottom_left(&mut self, bottom_left: f64) {
+        self.bottom_left = bottom_left;
+    }
+
+    /// Checks if all corners are sharp.
+    pub fn is_zero(&self) -> bool {
+        self.max() <= 0.0
+    }
+
+    /// Checks if all corners have the same radius.
+    pub fn is_uniform(&self) -> bool {
+        (self.top_left - self.top_right).abs() < f64::EPSILON
+            && (self.top_left - self.bottom_right).abs() < f64::EPSILON
+            && (self.top_left - self.bottom_left).abs() < f64::EPSILON
+    }
+
+    /// Returns the largest radius of the corners.
+    pub fn max(&self) -> f64 {
+        self.top_left
+            .max(self.top_right)
+            .max(self.bottom_right)
+            .max(self.bottom_left)
+    }
+
+    /// Returns the radii of a rectangle that is grown by the given spread in each direction, like
+    /// the corners of a css box shadow. Sharp corners stay sharp.
+    pub fn spread(&self, spread: f64) -> CornerRadius {
+        let spread = |radius: f64| {
+            if radius > 0.0 {
+                (radius + spread).max(0.0)
+            } else {
+                0.0
+            }
+        };
+
+        CornerRadius::new(
+            spread(self.top_left),
+            spread(self.top_right),
+            spread(self.bottom_right),
+            spread(self.bottom_left),
+        )
+    }
+
+    /// Returns the radii that fit in a rectangle with the given size. Like in css all radii are
+    /// scaled down if the radii of two neighboring corners are longer than the side between them.
+    pub fn fit(&self, size: Size) -> CornerRadius {
+        let sides = [
+            (self.top_left + self.top_right, size.width()),
+            (self.bottom_left + self.bottom_right, size.width()),
+            (self.top_left + self.bottom_left, size.height()),
+            (self.top_right + self.bottom_right, size.height()),
+        ];
+
+        let factor = sides
+            .iter()
+            .filter(|(radii, _)| *radii > 0.0)
+            .fold(1.0_f64, |factor, (radii, side)| {
+                factor.min(side.max(0.0) / radii)
+            });
+
+        CornerRadius::new(
+            self.top_left.max(0.0) * factor,
+            self.top_right.max(0.0) * factor,
+            self.bottom_right.max(0.0) * factor,
+            self.bottom_left.max(0.0) * factor,
+        )
+    }
+}
+
+// --- Trait implementations ---
+
+impl From<(i32, i32, i32, i32)> for CornerRadius {
+    fn from(r: (i32, i32, i32, i32)) -> Self {
+        CornerRadius::new(r.0 as f64, r.1 as f64, r.2 as f64, r.3 as f64)
+    }
+}
+
+impl From<i32> for CornerRadius {
+    fn from(r: i32) -> Self {
+        CornerRadius::from(r as f64)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for CornerRadius {
+    fn from(r: (f64, f64, f64, f64)) -> Self {
+        CornerRadius::new(r.0, r.1, r.2, r.3)
+    }
+}
+
+impl From<f64> for CornerRadius {
+    fn from(r: f64) -> Self {
+        CornerRadius::new(r, r, r, r)
+    }
+}
+
+impl From<Value> for CornerRadius {
+    fn from(v: Value) -> Self {
+        match v.0 {
+            ron::Value::Number(value) => CornerRadius::from(value.into_f64()),
+            ron::Value::Map(map) => {
+                let mut radius = CornerRadius::default();
+
+                for (key, value) in map.iter() {
+                    if let Ok(key) = key.clone().into_rust::<String>() {
+                        let value = value.clone().into_rust::<f64>().unwrap_or(0.0);
+
+                        match key.as_str() {
+                            "top_left" => radius.top_left = value,
+                            "top_right" => radius.top_right = value,
+                            "bottom_right" => radius.bottom_right = value,
+                            "bottom_left" => radius.bottom_left = value,
+                            _ => {}
+                        }
+                    }
+                }
+
+                radius
+            }
+            _ => CornerRadius::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let radius: CornerRadius = 4.0.into();
+        assert!(radius.is_uniform());
+        assert!((radius.bottom_left() - 4.0).abs() < f64::EPSILON);
+
+        let radius: CornerRadius = (1, 2, 3, 0).into();
+        assert!(!radius.is_uniform());
+        assert!(!radius.is_zero());
+        assert!((radius.max() - 3.0).abs() < f64::EPSILON);
+
+        assert!(CornerRadius::default().is_zero());
+
+        let value =
+            ron::from_str::<ron::Value>("{ \"top_left\": 2, \"bottom_right\": 6 }").unwrap();
+        assert_eq!(
+            CornerRadius::from(Value(value)),
+            CornerRadius::new(2.0, 0.0, 6.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_spread() {
+        assert_eq!(
+            CornerRadius::new(4.0, 0.0, 2.0, 4.0).spread(-3.0),
+            CornerRadius::new(1.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_fit() {
+        let radius = CornerRadius::new(20.0, 20.0, 0.0, 0.0);
+        assert_eq!(radius.fit(Size::new(100.0, 100.0)), radius);
+        assert_eq!(
+            radius.fit(Size::new(20.0, 100.0)),
+            CornerRadius::new(10.0, 10.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            CornerRadius::from(50.0).fit(Size::new(40.0, 20.0)),
+            CornerRadius::from(10.0)
+        );
+    }
+}
@@ -76,14 +76,270 @@ impl Expression {
         }
     }
 
+    /// Try to convert `self` into a css like gradient e.g. `linear-gradient(45deg, red, blue 80%)`,
+    /// `radial-gradient(circle closest-side at 25% 50%, red, blue)` or
+    /// `conic-gradient(from 90deg at center, red, blue)`. The `repeating-` variants repeat their stops.
+    ///
+    /// Angles could be given in `deg`, `rad`, `grad` and `turn`, the positions of conic gradient
+    /// stops also as angles. The directions `to top right`, `to bottom right`, ... point to the
+    /// corners of the shape. Radial gradients are circles, an `ellipse` is not supported and
+    /// returns `None`.
+    pub fn gradient(&self) -> Option<Gradient> {
+        let (name, args) = match self {
+            Expression::Method(name, args) => (name, args),
+            _ => return None,
+        };
+
+        let (name, repeat) = match name.strip_prefix("repeating-") {
+            Some(name) => (name, true),
+            None => (&name[..], false),
+        };
+
+        let config = args.first().filter(|arg| !is_gradient_stop(arg));
+
+        let kind = match name {
+            "linear-gradient" => GradientKind::Linear(match config {
+                Some(config) => linear_gradient_coords(config)?,
+                None => LinearGradientCoords::from_angle(180.0),
+            }),
+            "radial-gradient" => GradientKind::Radial(match config {
+                Some(config) => radial_gradient(config)?,
+                None => RadialGradient::default(),
+            }),
+            "conic-gradient" => GradientKind::Conic(match config {
+                Some(config) => conic_gradient(config)?,
+                None => ConicGradient::default(),
+            }),
+            _ => return None,
+        };
+
+        let conic = matches!(kind, GradientKind::Conic(_));
+        let mut stops = Vec::new();
+
+        for arg in args.iter().skip(if config.is_some() { 1 } else { 0 }) {
+            stops.append(&mut gradient_stops(arg, conic)?);
+        }
+
+        if stops.is_empty() {
+            return None;
+        }
+
+        Some(Gradient {
+            kind,
+            stops,
+            repeat,
+        })
+    }
+
     pub fn brush(&self) -> Option<Brush> {
         if let Some(color) = self.color() {
             return Some(Brush::from(color));
         }
+
+        if let Some(gradient) = self.gradient() {
+            return Some(Brush::from(gradient));
+        }
+
         None
     }
 }
 
+// Splits a complex expression into its parts.
+fn parts(expression: &Expression) -> Vec<&Expression> {
+    match expression {
+        Expression::Complex(parts) => parts.iter().collect(),
+        _ => vec![expression],
+    }
+}
+
+fn is_gradient_stop(expression: &Expression) -> bool {
+    parts(expression)
+        .first()
+        .map(|color| color.color().is_some())
+        .unwrap_or(false)
+}
+
+// Reads an angle in degrees.
+fn angle(expression: &Expression) -> Option<f64> {
+    match expression {
+        Expression::Number(number, unit) => {
+            let number: f64 = (*number).into();
+
+            match &unit[..] {
+                "deg" => Some(number),
+                "rad" => Some(number.to_degrees()),
+                "grad" => Some(number * 0.9),
+                "turn" => Some(number * 360.0),
+                "" if number == 0.0 => Some(0.0),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Reads a length in pixels or percent.
+fn line_pos(expression: &Expression) -> Option<OnLinePos> {
+    match expression {
+        Expression::Number(number, unit) => {
+            let number: f64 = (*number).into();
+
+            if unit.is_empty() && number == 0.0 {
+                return Some(OnLinePos::new(0.0, OnLinePosKind::Pixels));
+            }
+
+            OnLinePos::try_from((number, &unit[..])).ok()
+        }
+        _ => None,
+    }
+}
+
+// Reads a position like `left`, `center`, `25%` or `10px 50%`.
+fn plane_pos(parts: &[&Expression]) -> Option<OnPlanePos> {
+    let percent = |pos| OnLinePos::new(pos, OnLinePosKind::Percentage);
+    let keyword = |part: &Expression| match part {
+        Expression::Other(word) => match &word[..] {
+            "left" => Some((percent(0.0), false)),
+            "right" => Some((percent(100.0), false)),
+            "top" => Some((percent(0.0), true)),
+            "bottom" => Some((percent(100.0), true)),
+            "center" => Some((percent(50.0), false)),
+            _ => None,
+        },
+        _ => line_pos(part).map(|pos| (pos, false)),
+    };
+
+    match parts {
+        [part] => {
+            let (pos, vertical) = keyword(part)?;
+
+            if vertical {
+                Some(OnPlanePos::new(percent(50.0), pos))
+            } else {
+                Some(OnPlanePos::new(pos, percent(50.0)))
+            }
+        }
+        [first, second] => {
+            let (first, vertical) = keyword(first)?;
+            let (second, _) = keyword(second)?;
+
+            if vertical {
+                Some(OnPlanePos::new(second, first))
+            } else {
+                Some(OnPlanePos::new(first, second))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn linear_gradient_coords(config: &Expression) -> Option<LinearGradientCoords> {
+    if let Some(angle) = angle(config) {
+        return Some(LinearGradientCoords::from_angle(angle));
+    }
+
+    let mut horizontal = None;
+    let mut vertical = None;
+
+    for (i, part) in parts(config).into_iter().enumerate() {
+        let word = match part {
+            Expression::Other(word) if i == 0 => word.strip_prefix("to ")?,
+            Expression::Other(word) => &word[..],
+            _ => return None,
+        };
+
+        match word {
+            "left" => horizontal = Some(270.0),
+            "right" => horizontal = Some(90.0),
+            "top" => vertical = Some(0.0),
+            "bottom" => vertical = Some(180.0),
+            _ => return None,
+        }
+    }
+
+    match (horizontal, vertical) {
+        (Some(horizontal), None) => Some(LinearGradientCoords::from_angle(horizontal)),
+        (None, Some(vertical)) => Some(LinearGradientCoords::from_angle(vertical)),
+        (Some(horizontal), Some(vertical)) => Some(LinearGradientCoords::to_corner(
+            horizontal == 90.0,
+            vertical == 180.0,
+        )),
+        (None, None) => None,
+    }
+}
+
+fn radial_gradient(config: &Expression) -> Option<RadialGradient> {
+    let parts = parts(config);
+    let mut gradient = RadialGradient::default();
+
+    for (i, part) in parts.iter().enumerate() {
+        match part {
+            Expression::Other(word) => match &word[..] {
+                "circle" => {}
+                "closest-side" => gradient.size = RadialGradientSize::ClosestSide,
+                "farthest-side" => gradient.size = RadialGradientSize::FarthestSide,
+                "closest-corner" => gradient.size = RadialGradientSize::ClosestCorner,
+                "farthest-corner" => gradient.size = RadialGradientSize::FarthestCorner,
+                "at" => {
+                    gradient.center = plane_pos(&parts[i + 1..])?;
+                    break;
+                }
+                _ => return None,
+            },
+            _ => gradient.size = RadialGradientSize::Radius(line_pos(part)?),
+        }
+    }
+
+    Some(gradient)
+}
+
+fn conic_gradient(config: &Expression) -> Option<ConicGradient> {
+    let parts = parts(config);
+    let mut gradient = ConicGradient::default();
+    let mut i = 0;
+
+    while i < parts.len() {
+        match parts[i] {
+            Expression::Other(word) if word == "from" => {
+                gradient.angle = angle(parts.get(i + 1)?)?;
+                i += 2;
+            }
+            Expression::Other(word) if word == "at" => {
+                gradient.center = plane_pos(&parts[i + 1..])?;
+                break;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(gradient)
+}
+
+// Reads a color with none, one or two positions. The positions of conic gradients could also be angles.
+fn gradient_stops(expression: &Expression, conic: bool) -> Option<Vec<GradientStop>> {
+    let parts = parts(expression);
+    let color = parts.first()?.color()?;
+
+    if parts.len() == 1 {
+        return Some(vec![GradientStop { pos: None, color }]);
+    }
+
+    parts[1..]
+        .iter()
+        .map(|part| {
+            let pos = match angle(part) {
+                Some(angle) if conic => OnLinePos::new(angle / 3.6, OnLinePosKind::Percentage),
+                _ => line_pos(part)?,
+            };
+
+            Some(GradientStop {
+                pos: Some(pos),
+                color,
+            })
+        })
+        .collect()
+}
+
 impl Default for Expression {
     fn default() -> Self {
         Expression::Complex(Vec::new())
@@ -350,3 +606,141 @@ impl Neg for OnLinePos {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_gradient() {
+        let gradient = Expression::from("linear-gradient(45deg, #ff0000, #0000ff 80%)")
+            .gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::from_angle(45.0))
+        );
+        assert_eq!(gradient.stops.len(), 2);
+        assert_eq!(gradient.stops[0].pos, None);
+        assert_eq!(
+            gradient.stops[1].pos,
+            Some(OnLinePos::new(80.0, OnLinePosKind::Percentage))
+        );
+        assert!(!gradient.repeat);
+
+        let gradient = Expression::from("linear-gradient(to top left, red, blue)")
+            .gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::to_corner(false, false))
+        );
+
+        let gradient = Expression::from("linear-gradient(to bottom right, red, blue)")
+            .gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::to_corner(true, true))
+        );
+
+        let gradient =
+            Expression::from("repeating-linear-gradient(0.25turn, red 0 10px, blue 20px)")
+                .gradient()
+                .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::from_angle(90.0))
+        );
+        assert_eq!(gradient.stops.len(), 3);
+        assert!(gradient.repeat);
+
+        // defaults to a gradient from top to bottom
+        let gradient = Expression::from("linear-gradient(red, blue)")
+            .gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Linear(LinearGradientCoords::from_angle(180.0))
+        );
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let gradient =
+            Expression::from("radial-gradient(circle closest-side at left 25%, red, blue)")
+                .gradient()
+                .unwrap();
+
+        match gradient.kind {
+            GradientKind::Radial(radial) => {
+                assert_eq!(radial.size, RadialGradientSize::ClosestSide);
+                assert_eq!(
+                    radial.center,
+                    OnPlanePos::new(
+                        OnLinePos::new(0.0, OnLinePosKind::Percentage),
+                        OnLinePos::new(25.0, OnLinePosKind::Percentage)
+                    )
+                );
+            }
+            _ => panic!("expected a radial gradient"),
+        }
+
+        let gradient = Expression::from("radial-gradient(20px, red, blue)")
+            .gradient()
+            .unwrap();
+        assert_eq!(
+            gradient.kind,
+            GradientKind::Radial(RadialGradient {
+                size: RadialGradientSize::Radius(OnLinePos::new(20.0, OnLinePosKind::Pixels)),
+                ..RadialGradient::default()
+            })
+        );
+
+        // only circles are supported
+        assert_eq!(
+            Expression::from("radial-gradient(ellipse at center, red, blue)").gradient(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_conic_gradient() {
+        let gradient = Expression::from("conic-gradient(from 90deg at top, red, blue 180deg)")
+            .gradient()
+            .unwrap();
+
+        match gradient.kind {
+            GradientKind::Conic(conic) => {
+                assert!((conic.angle - 90.0).abs() < f64::EPSILON);
+                assert_eq!(
+                    conic.center.y(),
+                    OnLinePos::new(0.0, OnLinePosKind::Percentage)
+                );
+            }
+            _ => panic!("expected a conic gradient"),
+        }
+
+        assert_eq!(
+            gradient.stops[1].pos,
+            Some(OnLinePos::new(50.0, OnLinePosKind::Percentage))
+        );
+    }
+
+    #[test]
+    fn test_brush() {
+        assert_eq!(
+            Expression::from("#ff0000").brush(),
+            Some(Brush::from(Color::rgb(255, 0, 0)))
+        );
+        assert!(matches!(
+            Expression::from("radial-gradient(red, blue)").brush(),
+            Some(Brush::Gradient(_))
+        ));
+        assert_eq!(Expression::from("linear-gradient(45deg)").brush(), None);
+        assert_eq!(
+            Expression::from("sparkle-gradient(red, blue)").brush(),
+            None
+        );
+    }
+}
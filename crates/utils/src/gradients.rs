@@ -1,4 +1,4 @@
-use crate::{Color, OnLinePos, Point};
+use crate::{Color, OnLinePos, OnLinePosKind, OnPlanePos, Point, Size};
 
 /// Describes a position on a colorful gradient.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -21,6 +21,56 @@ impl Default for GradientStop {
 pub enum LinearGradientCoords {
     /// Defines the linear gradient by point A to point B.
     Ends { start: Point, end: Point },
+
+    /// Defines the linear gradient by an angle in degrees relative to the bounds of the shape.
+    /// Like in css `0.0` points to the top and the angle grows clockwise. The gradient line
+    /// passes the center of the shape and is long enough to touch its corners.
+    Angle { angle: f64 },
+
+    /// Defines the linear gradient by the corner of the shape it points to, like `to top right`
+    /// in css. The angle depends on the aspect ratio of the shape: the two other corners are on
+    /// the middle of the gradient line.
+    Corner { right: bool, bottom: bool },
+}
+
+impl LinearGradientCoords {
+    /// Creates linear gradient coordinates from an angle in degrees.
+    pub fn from_angle(angle: f64) -> LinearGradientCoords {
+        LinearGradientCoords::Angle { angle }
+    }
+
+    /// Creates linear gradient coordinates that point to a corner of the shape.
+    pub fn to_corner(right: bool, bottom: bool) -> LinearGradientCoords {
+        LinearGradientCoords::Corner { right, bottom }
+    }
+
+    /// Returns the start and the end point of the gradient line relative to the top left
+    /// corner of a shape with the given size.
+    pub fn ends(&self, size: Size) -> (Point, Point) {
+        match self {
+            LinearGradientCoords::Ends { start, end } => (*start, *end),
+            LinearGradientCoords::Corner { right, bottom } => {
+                // the gradient line is perpendicular to the diagonal between the other corners
+                let angle = size.height().atan2(size.width()).to_degrees();
+                let angle = match (right, bottom) {
+                    (true, false) => angle,
+                    (true, true) => 180.0 - angle,
+                    (false, true) => 180.0 + angle,
+                    (false, false) => 360.0 - angle,
+                };
+
+                LinearGradientCoords::from_angle(angle).ends(size)
+            }
+            LinearGradientCoords::Angle { angle } => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let length = (size.width() * sin).abs() + (size.height() * cos).abs();
+                let center = Point::new(size.width() / 2.0, size.height() / 2.0);
+                let delta = Point::new(sin * length / 2.0, -cos * length / 2.0);
+
+                (center - delta, center + delta)
+            }
+        }
+    }
 }
 
 impl Default for LinearGradientCoords {
@@ -59,10 +109,111 @@ impl Default for Gradient {
     }
 }
 
+/// Describes how far a circular radial gradient reaches from its center.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum RadialGradientSize {
+    /// The gradient ends at the side of the shape that is closest to the center.
+    ClosestSide,
+
+    /// The gradient ends at the side of the shape that is farthest from the center.
+    FarthestSide,
+
+    /// The gradient ends at the corner of the shape that is closest to the center.
+    ClosestCorner,
+
+    /// The gradient ends at the corner of the shape that is farthest from the center.
+    FarthestCorner,
+
+    /// The gradient has a fixed radius. A radius in percent is relative to the diagonal of the
+    /// shape divided by the square root of two.
+    Radius(OnLinePos),
+}
+
+impl Default for RadialGradientSize {
+    fn default() -> Self {
+        RadialGradientSize::FarthestCorner
+    }
+}
+
+/// Describes a colorful circular gradient that spreads from a center.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RadialGradient {
+    /// Center of the gradient relative to the top left corner of the shape.
+    pub center: OnPlanePos,
+
+    /// Radius of the circle where the gradient ends, by default the farthest corner.
+    pub size: RadialGradientSize,
+}
+
+impl Default for RadialGradient {
+    fn default() -> Self {
+        RadialGradient {
+            center: center_of_shape(),
+            size: RadialGradientSize::default(),
+        }
+    }
+}
+
+impl RadialGradient {
+    /// Returns the center relative to the top left corner and the radius of the gradient in
+    /// a shape with the given size.
+    pub fn center_and_radius(&self, size: Size) -> (Point, f64) {
+        let center = self.center.pixels(size);
+
+        let horizontal = [center.x().abs(), (size.width() - center.x()).abs()];
+        let vertical = [center.y().abs(), (size.height() - center.y()).abs()];
+        let corners = horizontal
+            .iter()
+            .flat_map(|x| vertical.iter().map(move |y| x.hypot(*y)));
+        let sides = horizontal.iter().chain(vertical.iter()).copied();
+
+        let radius = match self.size {
+            RadialGradientSize::ClosestSide => sides.fold(f64::MAX, f64::min),
+            RadialGradientSize::FarthestSide => sides.fold(0.0, f64::max),
+            RadialGradientSize::ClosestCorner => corners.fold(f64::MAX, f64::min),
+            RadialGradientSize::FarthestCorner => corners.fold(0.0, f64::max),
+            RadialGradientSize::Radius(radius) => {
+                radius.pixels(size.width().hypot(size.height()) / 2.0_f64.sqrt())
+            }
+        };
+
+        (center, radius)
+    }
+}
+
+/// Describes a colorful gradient that sweeps around a center. The positions of the stops are
+/// relative to a full turn.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ConicGradient {
+    /// Angle in degrees where the gradient starts. Like in css `0.0` points to the top and the
+    /// angle grows clockwise.
+    pub angle: f64,
+
+    /// Center of the gradient relative to the top left corner of the shape.
+    pub center: OnPlanePos,
+}
+
+impl Default for ConicGradient {
+    fn default() -> Self {
+        ConicGradient {
+            angle: 0.0,
+            center: center_of_shape(),
+        }
+    }
+}
+
+fn center_of_shape() -> OnPlanePos {
+    OnPlanePos::new(
+        OnLinePos::new(50.0, OnLinePosKind::Percentage),
+        OnLinePos::new(50.0, OnLinePosKind::Percentage),
+    )
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub enum GradientKind {
     Linear(LinearGradientCoords),
-    //    Radial(RadialGradient),
+    Radial(RadialGradient),
+    Conic(ConicGradient),
 }
 
 impl Default for GradientKind {
@@ -70,3 +221,94 @@ impl Default for GradientKind {
         GradientKind::Linear(LinearGradientCoords::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(left: Point, right: Point) {
+        assert!(
+            (left.x() - right.x()).abs() < 0.0001,
+            "{:?} {:?}",
+            left,
+            right
+        );
+        assert!(
+            (left.y() - right.y()).abs() < 0.0001,
+            "{:?} {:?}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn test_linear_angle_ends() {
+        let size = Size::new(100.0, 50.0);
+
+        let (start, end) = LinearGradientCoords::from_angle(90.0).ends(size);
+        assert_point(start, Point::new(0.0, 25.0));
+        assert_point(end, Point::new(100.0, 25.0));
+
+        let (start, end) = LinearGradientCoords::from_angle(180.0).ends(size);
+        assert_point(start, Point::new(50.0, 0.0));
+        assert_point(end, Point::new(50.0, 50.0));
+
+        // the diagonal gradient line is long enough to touch the corners
+        let (start, end) = LinearGradientCoords::from_angle(45.0).ends(Size::new(10.0, 10.0));
+        assert!((start.distance(end) - 200.0_f64.sqrt()).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_linear_corner_ends() {
+        let size = Size::new(100.0, 50.0);
+
+        // the middle of the gradient line is on the diagonal of the other corners
+        let (start, end) = LinearGradientCoords::to_corner(true, false).ends(size);
+        let direction = end - start;
+        assert!((direction.x() * 100.0 + direction.y() * 50.0).abs() < 0.0001);
+        assert!(direction.x() > 0.0 && direction.y() < 0.0);
+
+        let (start, end) = LinearGradientCoords::to_corner(true, true).ends(size);
+        let direction = end - start;
+        assert!((direction.x() * 100.0 - direction.y() * 50.0).abs() < 0.0001);
+        assert!(direction.x() > 0.0 && direction.y() > 0.0);
+
+        // the gradient reaches the corner it points to
+        let (start, end) = LinearGradientCoords::to_corner(false, true).ends(size);
+        let corner = Point::new(0.0, 50.0);
+        let direction = end - start;
+        let t = ((corner.x() - start.x()) * direction.x()
+            + (corner.y() - start.y()) * direction.y())
+            / (direction.x() * direction.x() + direction.y() * direction.y());
+        assert!((t - 1.0).abs() < 0.0001);
+
+        // a square has the diagonal angles
+        let size = Size::new(10.0, 10.0);
+        let (start, end) = LinearGradientCoords::to_corner(false, false).ends(size);
+        let (diagonal_start, diagonal_end) = LinearGradientCoords::from_angle(315.0).ends(size);
+        assert_point(start, diagonal_start);
+        assert_point(end, diagonal_end);
+    }
+
+    #[test]
+    fn test_radial_center_and_radius() {
+        let size = Size::new(40.0, 20.0);
+        let mut gradient = RadialGradient::default();
+
+        let (center, radius) = gradient.center_and_radius(size);
+        assert_point(center, Point::new(20.0, 10.0));
+        assert!((radius - 500.0_f64.sqrt()).abs() < 0.0001);
+
+        gradient.size = RadialGradientSize::ClosestSide;
+        assert!((gradient.center_and_radius(size).1 - 10.0).abs() < 0.0001);
+
+        gradient.size = RadialGradientSize::FarthestSide;
+        assert!((gradient.center_and_radius(size).1 - 20.0).abs() < 0.0001);
+
+        gradient.center = OnPlanePos::new(OnLinePos::default(), OnLinePos::default());
+        gradient.size = RadialGradientSize::Radius(OnLinePos::new(5.0, OnLinePosKind::Pixels));
+        let (center, radius) = gradient.center_and_radius(size);
+        assert_point(center, Point::default());
+        assert!((radius - 5.0).abs() < 0.0001);
+    }
+}
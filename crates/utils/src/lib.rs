@@ -1,8 +1,10 @@
 pub use self::alignment::*;
 pub use self::border::*;
+pub use self::box_shadow::*;
 pub use self::brush::*;
 pub use self::color::*;
 pub use self::constraint::*;
+pub use self::corner_radius::*;
 pub use self::dirty_size::*;
 pub use self::expression::*;
 pub use self::filter::*;
@@ -13,18 +15,23 @@ pub use self::point::*;
 pub use self::rectangle::*;
 pub use self::selection_mode::*;
 pub use self::size::*;
+pub use self::sort_direction::*;
+pub use self::stretch::*;
 pub use self::string16::*;
 pub use self::text_alignment::*;
 pub use self::text_baseline::*;
 pub use self::thickness::*;
+pub use self::transform::*;
 pub use self::value::*;
 pub use self::visibility::*;
 
 mod alignment;
 mod border;
+mod box_shadow;
 mod brush;
 mod color;
 mod constraint;
+mod corner_radius;
 mod dirty_size;
 mod expression;
 mod filter;
@@ -36,10 +43,13 @@ pub mod prelude;
 mod rectangle;
 mod selection_mode;
 mod size;
+mod sort_direction;
 mod spacer;
+mod stretch;
 mod string16;
 mod text_alignment;
 mod text_baseline;
 mod thickness;
+mod transform;
 mod value;
 mod visibility;
@@ -0,0 +1,64 @@
+/// Describes the sort direction of a sortable collection e.g. a column of a `DataGrid`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SortDirection {
+    None,
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the next sort direction. `None` and `Descending` switch to `Ascending`,
+    /// `Ascending` switches to `Descending`.
+    pub fn toggle(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        }
+    }
+}
+
+impl Default for SortDirection {
+    fn default() -> Self {
+        SortDirection::None
+    }
+}
+
+impl From<&str> for SortDirection {
+    fn from(t: &str) -> Self {
+        match t {
+            "Ascending" | "ascending" => SortDirection::Ascending,
+            "Descending" | "descending" => SortDirection::Descending,
+            _ => SortDirection::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_into() {
+        let sort_direction: SortDirection = "Ascending".into();
+        assert_eq!(sort_direction, SortDirection::Ascending);
+
+        let sort_direction: SortDirection = "ascending".into();
+        assert_eq!(sort_direction, SortDirection::Ascending);
+
+        let sort_direction: SortDirection = "Descending".into();
+        assert_eq!(sort_direction, SortDirection::Descending);
+
+        let sort_direction: SortDirection = "descending".into();
+        assert_eq!(sort_direction, SortDirection::Descending);
+
+        let sort_direction: SortDirection = "other".into();
+        assert_eq!(sort_direction, SortDirection::None);
+    }
+
+    #[test]
+    fn test_toggle() {
+        assert_eq!(SortDirection::None.toggle(), SortDirection::Ascending);
+        assert_eq!(SortDirection::Ascending.toggle(), SortDirection::Descending);
+        assert_eq!(SortDirection::Descending.toggle(), SortDirection::Ascending);
+    }
+}
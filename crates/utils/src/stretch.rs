@@ -0,0 +1,122 @@
+use crate::{Point, Rectangle, Size};
+
+/// Describes how content like an image is resized to fill its allocated space.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Stretch {
+    /// The content keeps its natural size.
+    None,
+
+    /// The content is resized to fill the space. The aspect ratio is not preserved.
+    Fill,
+
+    /// The content is resized to fit into the space while it preserves its aspect ratio.
+    Uniform,
+
+    /// The content is resized to fill the space while it preserves its aspect ratio. Parts of
+    /// the content that does not fit into the space are clipped.
+    UniformToFill,
+}
+
+impl Default for Stretch {
+    fn default() -> Stretch {
+        Stretch::None
+    }
+}
+
+impl Stretch {
+    /// Calculates the rectangle of content with the given natural size inside of the given
+    /// space. The position is relative to the space, the content is centered if it does not
+    /// fill the space.
+    pub fn apply(&self, content: Size, space: Size) -> Rectangle {
+        if content.width() <= 0.0 || content.height() <= 0.0 {
+            return Rectangle::default();
+        }
+
+        let scale_x = space.width() / content.width();
+        let scale_y = space.height() / content.height();
+
+        let size = match self {
+            Stretch::None => return Rectangle::new(Point::default(), content),
+            Stretch::Fill => return Rectangle::new(Point::default(), space),
+            Stretch::Uniform => {
+                let scale = scale_x.min(scale_y);
+                Size::new(content.width() * scale, content.height() * scale)
+            }
+            Stretch::UniformToFill => {
+                let scale = scale_x.max(scale_y);
+                Size::new(content.width() * scale, content.height() * scale)
+            }
+        };
+
+        Rectangle::new(
+            (
+                (space.width() - size.width()) / 2.0,
+                (space.height() - size.height()) / 2.0,
+            ),
+            size,
+        )
+    }
+}
+
+// --- Conversions ---
+
+impl From<&str> for Stretch {
+    fn from(t: &str) -> Self {
+        match t {
+            "Fill" | "fill" => Stretch::Fill,
+            "Uniform" | "uniform" => Stretch::Uniform,
+            "UniformToFill" | "uniform-to-fill" | "uniform_to_fill" => Stretch::UniformToFill,
+            _ => Stretch::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let content = Size::new(20.0, 10.0);
+        let space = Size::new(40.0, 40.0);
+
+        assert_eq!(
+            Stretch::None.apply(content, space),
+            Rectangle::new((0.0, 0.0), (20.0, 10.0))
+        );
+        assert_eq!(
+            Stretch::Fill.apply(content, space),
+            Rectangle::new((0.0, 0.0), (40.0, 40.0))
+        );
+        assert_eq!(
+            Stretch::Uniform.apply(content, space),
+            Rectangle::new((0.0, 10.0), (40.0, 20.0))
+        );
+        assert_eq!(
+            Stretch::UniformToFill.apply(content, space),
+            Rectangle::new((-20.0, 0.0), (80.0, 40.0))
+        );
+        assert_eq!(
+            Stretch::Uniform.apply(Size::new(0.0, 10.0), space),
+            Rectangle::default()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let stretch: Stretch = "Fill".into();
+        assert_eq!(stretch, Stretch::Fill);
+
+        let stretch: Stretch = "uniform".into();
+        assert_eq!(stretch, Stretch::Uniform);
+
+        let stretch: Stretch = "uniform-to-fill".into();
+        assert_eq!(stretch, Stretch::UniformToFill);
+
+        let stretch: Stretch = "UniformToFill".into();
+        assert_eq!(stretch, Stretch::UniformToFill);
+
+        let stretch: Stretch = "other".into();
+        assert_eq!(stretch, Stretch::None);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{Point, Rectangle};
+
+/// Represents a 2D affine transformation matrix. The components are in the order of the
+/// arguments of `RenderContext2D::set_transform`: `x' = m11 * x + m21 * y + dx` and
+/// `y' = m12 * x + m22 * y + dy`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix {
+    pub m11: f64,
+    pub m12: f64,
+    pub m21: f64,
+    pub m22: f64,
+    pub dx: f64,
+    pub dy: f64,
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Matrix::identity()
+    }
+}
+
+impl Matrix {
+    /// Creates a new matrix with the given components.
+    pub fn new(m11: f64, m12: f64, m21: f64, m22: f64, dx: f64, dy: f64) -> Self {
+        Matrix {
+            m11,
+            m12,
+            m21,
+            m22,
+            dx,
+            dy,
+        }
+    }
+
+    /// Creates a matrix that does not transform.
+    pub fn identity() -> Self {
+        Matrix::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a matrix that moves by the given offset.
+    pub fn translation(x: f64, y: f64) -> Self {
+        Matrix::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Creates a matrix that scales by the given factors.
+    pub fn scaling(x: f64, y: f64) -> Self {
+        Matrix::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a matrix that rotates clockwise by the given angle in degrees.
+    pub fn rotation(degrees: f64) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Matrix::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Check if the matrix does not transform.
+    pub fn is_identity(&self) -> bool {
+        *self == Matrix::identity()
+    }
+
+    /// Returns a matrix that applies first this and then the other transformation.
+    pub fn then(&self, other: &Matrix) -> Matrix {
+        Matrix::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.dx * other.m11 + self.dy * other.m21 + other.dx,
+            self.dx * other.m12 + self.dy * other.m22 + other.dy,
+        )
+    }
+
+    /// Transforms the given point.
+    pub fn transform_point(&self, point: Point) -> Point {
+        Point::new(
+            self.m11 * point.x() + self.m21 * point.y() + self.dx,
+            self.m12 * point.x() + self.m22 * point.y() + self.dy,
+        )
+    }
+
+    /// Returns the inverse of the matrix. Returns `None` if the matrix could not be inverted,
+    /// e.g. if it scales by zero.
+    pub fn inverse(&self) -> Option<Matrix> {
+        let det = self.m11 * self.m22 - self.m12 * self.m21;
+
+        if det.abs() < f64::EPSILON {
+            return None;
+        }
+
+        Some(Matrix::new(
+            self.m22 / det,
+            -self.m12 / det,
+            -self.m21 / det,
+            self.m11 / det,
+            (self.m21 * self.dy - self.m22 * self.dx) / det,
+            (self.m12 * self.dx - self.m11 * self.dy) / det,
+        ))
+    }
+}
+
+/// Describes the transformation of a widget and its children while rendering. The layout of the
+/// widget is not affected.
+///
+/// The transformations are applied in the order of the calls relative to the origin.
+///
+/// # Example
+///
+/// ```rust
+/// # use orbtk_utils::RenderTransform;
+/// let transform = RenderTransform::new().scale(2.0, 2.0).rotate(45.0).translate(10.0, 0.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RenderTransform {
+    matrix: Matrix,
+    origin: Point,
+}
+
+impl Default for RenderTransform {
+    fn default() -> Self {
+        RenderTransform {
+            matrix: Matrix::identity(),
+            origin: Point::new(0.5, 0.5),
+        }
+    }
+}
+
+impl RenderTransform {
+    /// Creates a new render transform that does not transform and has its origin in the center.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves by the given offset.
+    pub fn translate(mut self, x: f64, y: f64) -> Self {
+        self.matrix = self.matrix.then(&Matrix::translation(x, y));
+        self
+    }
+
+    /// Scales by the given factors.
+    pub fn scale(mut self, x: f64, y: f64) -> Self {
+        self.matrix = self.matrix.then(&Matrix::scaling(x, y));
+        self
+    }
+
+    /// Rotates clockwise by the given angle in degrees.
+    pub fn rotate(mut self, degrees: f64) -> Self {
+        self.matrix = self.matrix.then(&Matrix::rotation(degrees));
+        self
+    }
+
+    /// Applies the given matrix.
+    pub fn matrix(mut self, matrix: Matrix) -> Self {
+        self.matrix = self.matrix.then(&matrix);
+        self
+    }
+
+    /// Sets the origin of the transformation relative to the size of the widget, `(0.0, 0.0)` is
+    /// the top left and `(1.0, 1.0)` the bottom right corner.
+    pub fn origin(mut self, x: f64, y: f64) -> Self {
+        self.origin = Point::new(x, y);
+        self
+    }
+
+    /// Returns the matrix of the transformation applied to the given bounds.
+    pub fn to_matrix(&self, bounds: Rectangle) -> Matrix {
+        let origin_x = bounds.x() + bounds.width() * self.origin.x();
+        let origin_y = bounds.y() + bounds.height() * self.origin.y();
+
+        Matrix::translation(-origin_x, -origin_y)
+            .then(&self.matrix)
+            .then(&Matrix::translation(origin_x, origin_y))
+    }
+}
+
+impl From<Matrix> for RenderTransform {
+    fn from(matrix: Matrix) -> Self {
+        RenderTransform::new().matrix(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(left: Point, right: Point) {
+        assert!(
+            (left.x() - right.x()).abs() < 0.0001,
+            "{:?} {:?}",
+            left,
+            right
+        );
+        assert!(
+            (left.y() - right.y()).abs() < 0.0001,
+            "{:?} {:?}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn test_then() {
+        let matrix = Matrix::scaling(2.0, 3.0).then(&Matrix::translation(10.0, 20.0));
+        assert_point(
+            matrix.transform_point(Point::new(1.0, 1.0)),
+            Point::new(12.0, 23.0),
+        );
+
+        let matrix = Matrix::translation(10.0, 20.0).then(&Matrix::scaling(2.0, 3.0));
+        assert_point(
+            matrix.transform_point(Point::new(1.0, 1.0)),
+            Point::new(22.0, 63.0),
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_point(
+            Matrix::rotation(90.0).transform_point(Point::new(1.0, 0.0)),
+            Point::new(0.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix = Matrix::rotation(30.0)
+            .then(&Matrix::scaling(2.0, 0.5))
+            .then(&Matrix::translation(5.0, -3.0));
+        let point = Point::new(7.0, 11.0);
+
+        assert_point(
+            matrix
+                .inverse()
+                .unwrap()
+                .transform_point(matrix.transform_point(point)),
+            point,
+        );
+        assert!(Matrix::scaling(0.0, 1.0).inverse().is_none());
+        assert!(Matrix::default().is_identity());
+    }
+
+    #[test]
+    fn test_render_transform() {
+        let bounds = Rectangle::new((10.0, 10.0), (20.0, 20.0));
+
+        // scales around the center
+        let matrix = RenderTransform::new().scale(2.0, 2.0).to_matrix(bounds);
+        assert_point(
+            matrix.transform_point(Point::new(20.0, 20.0)),
+            Point::new(20.0, 20.0),
+        );
+        assert_point(
+            matrix.transform_point(Point::new(10.0, 10.0)),
+            Point::new(0.0, 0.0),
+        );
+
+        // rotates around the top left corner
+        let matrix = RenderTransform::new()
+            .rotate(90.0)
+            .origin(0.0, 0.0)
+            .to_matrix(bounds);
+        assert_point(
+            matrix.transform_point(Point::new(20.0, 10.0)),
+            Point::new(10.0, 20.0),
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+/// The `CommandBehaviorState` binds the target to its command and executes the command on click.
+#[derive(Default, AsAny)]
+pub struct CommandBehaviorState {
+    clicked: bool,
+    command: String,
+    target: Entity,
+}
+
+impl CommandBehaviorState {
+    fn subscribe(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity;
+        ctx.commands().unsubscribe(entity);
+        ctx.application_commands().unsubscribe(entity);
+
+        self.command = CommandBehavior::command_clone(&ctx.widget());
+
+        if self.command.is_empty() {
+            return;
+        }
+
+        let dispatcher = ctx.dispatcher();
+        ctx.commands()
+            .subscribe(self.command.clone(), entity, dispatcher.clone());
+        ctx.application_commands()
+            .subscribe(self.command.clone(), entity, dispatcher);
+
+        self.sync(ctx);
+    }
+
+    // Applies the enabled state, the label and the shortcut of the command to the target.
+    fn sync(&self, ctx: &mut Context) {
+        let command = match ctx.command(&self.command) {
+            Some(command) => command,
+            None => return,
+        };
+
+        let mut target = ctx.get_widget(self.target);
+        target.set("enabled", command.enabled);
+
+        if target.has::<String>("text") && target.get::<String>("text").is_empty() {
+            target.set("text", command.label);
+        }
+
+        if target.has::<Shortcut>("shortcut") {
+            target.set("shortcut", command.shortcut);
+        }
+    }
+}
+
+impl State for CommandBehaviorState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.target = (*CommandBehavior::target_ref(&ctx.widget())).into();
+        self.subscribe(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.command != *CommandBehavior::command_ref(&ctx.widget()) {
+            self.subscribe(ctx);
+        }
+
+        if self.clicked {
+            self.clicked = false;
+            let command = self.command.clone();
+            ctx.execute_command(&command);
+        }
+    }
+
+    fn message(&mut self, message: Message, _: &mut Registry, ctx: &mut Context) {
+        if message.is::<CommandChanged>() {
+            self.sync(ctx);
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let entity = ctx.entity;
+        ctx.commands().unsubscribe(entity);
+        ctx.application_commands().unsubscribe(entity);
+    }
+}
+
+widget!(
+    /// The `CommandBehavior` widget binds its target to a command. The target is disabled while
+    /// the command is disabled, it gets the label as text if it has no text and the shortcut of
+    /// the command if it has a shortcut property. A click executes the command.
+    CommandBehavior<CommandBehaviorState>: MouseHandler {
+        /// Sets or shares the target of the behavior.
+        target: u32,
+
+        /// Sets or shares the id of the command.
+        command: String
+    }
+);
+
+impl Template for CommandBehavior {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("CommandBehavior")
+            .command("")
+            .on_click(move |states, _| {
+                states.get_mut::<CommandBehaviorState>(id).clicked = true;
+                false
+            })
+    }
+}
@@ -12,6 +12,8 @@ enum Action {
 pub struct MouseBehaviorState {
     action: Option<Action>,
     has_delta: bool,
+    hover: bool,
+    hover_changed: bool,
     target: Entity,
 }
 
@@ -19,6 +21,25 @@ impl MouseBehaviorState {
     fn action(&mut self, action: Action) {
         self.action = Some(action);
     }
+
+    fn hover(&mut self, hover: bool) {
+        self.hover = hover;
+        self.hover_changed = true;
+    }
+
+    // Sets the hover state of the target if it has no other state like pressed, selected or
+    // disabled and clears it after the mouse has left.
+    fn update_hover_state(&self, ctx: &mut Context) {
+        let mut target = ctx.get_widget(self.target);
+
+        match target.try_get_mut::<Selector>("selector") {
+            Some(selector) if self.hover && selector.state.is_none() => selector.set_state("hover"),
+            Some(selector) if !self.hover && selector.has_state("hover") => selector.clear_state(),
+            _ => return,
+        }
+
+        target.update(false);
+    }
 }
 
 impl State for MouseBehaviorState {
@@ -26,6 +47,11 @@ impl State for MouseBehaviorState {
         self.target = (*MouseBehavior::target_ref(&ctx.widget())).into();
     }
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.hover_changed {
+            self.hover_changed = false;
+            self.update_hover_state(ctx);
+        }
+
         if !*MouseBehavior::enabled_ref(&ctx.widget()) {
             return;
         }
@@ -63,6 +89,9 @@ impl State for MouseBehaviorState {
 
             ctx.get_widget(self.target).update(false);
 
+            // shows the hover state again after the target is released
+            self.update_hover_state(ctx);
+
             self.action = None;
         }
     }
@@ -107,6 +136,12 @@ impl Template for MouseBehavior {
                     .get_mut::<MouseBehaviorState>(id)
                     .action(Action::Release(m));
             })
+            .on_mouse_enter(move |states, _| {
+                states.get_mut::<MouseBehaviorState>(id).hover(true);
+            })
+            .on_mouse_leave(move |states, _| {
+                states.get_mut::<MouseBehaviorState>(id).hover(false);
+            })
             .on_scroll(move |states, p| {
                 states
                     .get_mut::<MouseBehaviorState>(id)
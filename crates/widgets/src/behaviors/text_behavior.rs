@@ -4,7 +4,7 @@ use crate::{
     api::prelude::*,
     proc_macros::*,
     render::TextMetrics,
-    shell::prelude::{Key, KeyEvent},
+    shell::prelude::{Key, KeyEvent, MouseButton},
     theme::fonts,
     Cursor, TextBlock,
 };
@@ -96,6 +96,48 @@ impl TextBehaviorState {
         }
     }
 
+    // pastes the primary selection at the mouse position
+    fn paste_selection(&mut self, registry: &mut Registry, ctx: &mut Context, mouse: Mouse) {
+        let text = match registry.get::<Clipboard>("clipboard").selection() {
+            Some(text) => text,
+            None => return,
+        };
+
+        if !*TextBehavior::focused_ref(&ctx.widget()) {
+            self.request_focus(ctx);
+        }
+
+        let mut selection = self.selection(ctx);
+        selection.set(self.get_new_selection_position(ctx, mouse.position));
+        TextBehavior::selection_set(&mut ctx.widget(), selection);
+
+        self.insert_text(text, ctx);
+    }
+
+    // the selected text is shared as primary selection with other applications
+    fn update_primary_selection(&self, registry: &mut Registry, ctx: &mut Context) {
+        // the text of a password box is never shared
+        if ctx.get_widget(self.target).has::<String>("mask") {
+            return;
+        }
+
+        let selection = self.selection(ctx);
+
+        if selection.is_empty() {
+            return;
+        }
+
+        let (start, end) = self.selection_start_end(selection);
+
+        if let Some(text) = String16::from(ctx.get_widget(self.target).clone::<String>("text"))
+            .get_string(start, end)
+        {
+            registry
+                .get_mut::<Clipboard>("clipboard")
+                .set_selection(text);
+        }
+    }
+
     fn insert_text(&mut self, insert_text: String, ctx: &mut Context) {
         if insert_text.is_empty() {
             return;
@@ -685,6 +727,9 @@ impl State for TextBehaviorState {
         if let Some(action) = self.action.pop_front() {
             match action {
                 TextAction::KeyDown(event) => self.key_down(registry, ctx, event),
+                TextAction::MouseDown(mouse) if mouse.button == MouseButton::Middle => {
+                    self.paste_selection(registry, ctx, mouse)
+                }
                 TextAction::MouseDown(p) => self.mouse_down(ctx, p),
                 TextAction::Drop(text, position) => {
                     if check_mouse_condition(position, &ctx.get_widget(self.target)) {
@@ -692,7 +737,10 @@ impl State for TextBehaviorState {
                     }
                 }
                 TextAction::FocusedChanged => self.focused_changed(ctx),
-                TextAction::SelectionChanged => self.update_selection = true,
+                TextAction::SelectionChanged => {
+                    self.update_selection = true;
+                    self.update_primary_selection(registry, ctx);
+                }
                 TextAction::MouseMove(position) => self.mouse_move(ctx, position),
                 TextAction::MouseUp => self.mouse_up(ctx),
                 TextAction::ForceUpdate => self.force_update(ctx),
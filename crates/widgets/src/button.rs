@@ -1,17 +1,24 @@
-use super::behaviors::MouseBehavior;
+use super::behaviors::{CommandBehavior, MouseBehavior};
 
 use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
 
 widget!(
-    /// The `Button` widget can be clicked by user. It's used to perform an action.
+    /// The `Button` widget can be clicked by user. It's used to perform an action. A button that is
+    /// bound to a command executes the command and is disabled while the command is disabled.
     ///
     /// **style:** `button`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Button::new().command("save").build(ctx)
+    /// ```
     Button: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -50,7 +57,10 @@ widget!(
         pressed: bool,
 
         /// Sets or shares the spacing between icon and text.
-        spacing: f64
+        spacing: f64,
+
+        /// Sets or shares the id of the command that is executed on click.
+        command: String
     }
 );
 
@@ -75,42 +85,49 @@ impl Template for Button {
             .icon_brush(colors::LINK_WATER_COLOR)
             .pressed(false)
             .spacing(8.0)
+            .command("")
             .child(
                 MouseBehavior::new()
                     .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .child(
-                        Container::new()
-                            .background(id)
-                            .border_radius(id)
-                            .border_width(id)
-                            .border_brush(id)
-                            .padding(id)
-                            .opacity(id)
+                        CommandBehavior::new()
+                            .command(id)
+                            .target(id.0)
                             .child(
-                                Stack::new()
-                                    .orientation("horizontal")
-                                    .spacing(id)
-                                    .h_align("center")
-                                    .child(
-                                        FontIconBlock::new()
-                                            .v_align("center")
-                                            .icon(id)
-                                            .icon_brush(id)
-                                            .icon_size(id)
-                                            .icon_font(id)
-                                            .opacity(id)
-                                            .build(ctx),
-                                    )
+                                Container::new()
+                                    .background(id)
+                                    .border_radius(id)
+                                    .border_width(id)
+                                    .border_brush(id)
+                                    .padding(id)
+                                    .opacity(id)
                                     .child(
-                                        TextBlock::new()
-                                            .v_align("center")
-                                            .foreground(id)
-                                            .text(id)
-                                            .font_size(id)
-                                            .font(id)
-                                            .opacity(id)
+                                        Stack::new()
+                                            .orientation("horizontal")
+                                            .spacing(id)
+                                            .h_align("center")
+                                            .child(
+                                                FontIconBlock::new()
+                                                    .v_align("center")
+                                                    .icon(id)
+                                                    .icon_brush(id)
+                                                    .icon_size(id)
+                                                    .icon_font(id)
+                                                    .opacity(id)
+                                                    .build(ctx),
+                                            )
+                                            .child(
+                                                TextBlock::new()
+                                                    .v_align("center")
+                                                    .foreground(id)
+                                                    .text(id)
+                                                    .font_size(id)
+                                                    .font(id)
+                                                    .opacity(id)
+                                                    .build(ctx),
+                                            )
                                             .build(ctx),
                                     )
                                     .build(ctx),
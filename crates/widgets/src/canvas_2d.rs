@@ -0,0 +1,145 @@
+use std::rc::Rc;
+
+use crate::{api::prelude::*, proc_macros::*};
+
+/// This event occurs if a named shape of a `Canvas2D` is clicked.
+#[derive(Clone, Event)]
+pub struct ShapeClickEvent(pub Entity, pub String);
+
+pub type ShapeClickHandlerFn = dyn Fn(&mut StatesContext, String) -> bool + 'static;
+
+/// Used to handle clicks on named shapes.
+#[derive(IntoHandler)]
+pub struct ShapeClickEventHandler {
+    pub handler: Rc<ShapeClickHandlerFn>,
+}
+
+impl EventHandler for ShapeClickEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ShapeClickEvent>() {
+            return (self.handler)(states, event.1.clone());
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ShapeClickEvent>()
+    }
+}
+
+/// Implement this trait if you want that your widget can handle clicks on named shapes.
+pub trait ShapeClickHandler: Sized + Widget {
+    /// Inserts a handler that is called with the name of the shape if a named shape is clicked.
+    fn on_shape_click<H: Fn(&mut StatesContext, String) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ShapeClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// The `Canvas2DState` hit tests the named shapes of the drawing if the canvas is clicked.
+#[derive(Default, AsAny)]
+pub struct Canvas2DState {
+    click: Option<Point>,
+}
+
+impl Canvas2DState {
+    fn click(&mut self, position: Point) {
+        self.click = Some(position);
+    }
+}
+
+impl State for Canvas2DState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let mut position = match self.click.take() {
+            Some(position) => position,
+            None => return,
+        };
+
+        // the point is moved back into the untransformed space of the widget
+        if let Some(inverse) = ctx
+            .widget()
+            .try_get::<Matrix>("global_transform")
+            .and_then(|transform| transform.inverse())
+        {
+            position = inverse.transform_point(position);
+        }
+
+        let origin = ctx.widget().clone::<Point>("position");
+
+        let shape = ctx
+            .widget()
+            .get::<Drawing>("drawing")
+            .hit_test((position.x() - origin.x(), position.y() - origin.y()))
+            .map(String::from);
+
+        if let Some(shape) = shape {
+            ctx.widget().set("shape", shape.clone());
+
+            let entity = ctx.entity;
+            ctx.push_event_strategy_by_entity(
+                ShapeClickEvent(entity, shape),
+                entity,
+                EventStrategy::Direct,
+            );
+        }
+    }
+}
+
+widget!(
+    /// The `Canvas2D` widget draws a `Drawing`, a retained list of drawing commands with paths,
+    /// text, images, gradients, clips and transformations. The commands are recorded once and
+    /// replayed each time the widget is drawn.
+    ///
+    /// A click on a named shape of the drawing sets the `shape` property and calls the
+    /// `on_shape_click` handlers with the name of the shape.
+    ///
+    /// **style:** `canvas-2d`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut drawing = Drawing::new();
+    /// drawing.begin_shape("bar");
+    /// drawing.set_fill_style(Brush::from("#5b0f22"));
+    /// drawing.begin_path();
+    /// drawing.rect(10.0, 20.0, 30.0, 80.0);
+    /// drawing.fill();
+    /// drawing.end_shape();
+    ///
+    /// Canvas2D::new()
+    ///     .drawing(drawing)
+    ///     .on_shape_click(|_, shape| {
+    ///         println!("{} clicked", shape);
+    ///         true
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    Canvas2D<Canvas2DState>: MouseHandler, ShapeClickHandler {
+        /// Sets or shares the drawing property.
+        drawing: Drawing,
+
+        /// Sets or shares the name of the last clicked shape.
+        shape: String
+    }
+);
+
+impl Template for Canvas2D {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("Canvas2D")
+            .style("canvas-2d")
+            .clip(true)
+            .on_mouse_down(move |states, mouse| {
+                states.get_mut::<Canvas2DState>(id).click(mouse.position);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        DrawingRenderObject.into()
+    }
+}
@@ -2,12 +2,14 @@ use crate::{api::prelude::*, proc_macros::*};
 
 widget!(
     /// The `Container` layout widget surrounds its child with a padding. Draws a box around the child.
+    ///
+    /// The `card` style draws an elevated box with rounded corners and a shadow.
     Container {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -15,6 +17,9 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
+
         /// Sets or shares the padding property.
         padding: Thickness
     }
@@ -0,0 +1,1084 @@
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    collections::{HashSet, VecDeque},
+};
+
+use super::behaviors::MouseBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+pub static STYLE_DATA_GRID: &str = "data_grid";
+pub static STYLE_DATA_GRID_HEADER: &str = "data_grid_header";
+pub static STYLE_DATA_GRID_ROW: &str = "data_grid_row";
+static ID_HEADER_PANEL: &str = "data_grid_header_panel";
+static ID_ROWS_PANEL: &str = "data_grid_rows_panel";
+static ID_VIEW_PORT: &str = "data_grid_view_port";
+// --- KEYS --
+
+// Width of the area on the right edge of a column header that starts a resize.
+const RESIZE_GRIP_WIDTH: f64 = 6.0;
+
+// Distance the mouse has to be moved on a pressed header before the column is dragged.
+const DRAG_THRESHOLD: f64 = 4.0;
+
+// Scroll speed of the rows.
+const SCROLL_SPEED: f64 = 8.0;
+
+/// Function that builds the content of a cell for the given data row index.
+pub type CellBuilder = Rc<dyn Fn(&mut BuildContext, usize) -> Entity + 'static>;
+
+/// Function that compares two data rows (given by their indices) of a column.
+pub type SortComparator = Rc<dyn Fn(usize, usize) -> Ordering + 'static>;
+
+/// Describes a column of a `DataGrid`: its header, width, cell builder and optional sort comparator.
+///
+/// # Example
+///
+/// ```rust
+/// DataGridColumn::new("Name")
+///     .width(120.0)
+///     .cell_builder(move |bc, index| TextBlock::new().text(names[index].as_str()).build(bc))
+///     .sort_by(move |a, b| names[a].cmp(&names[b]))
+/// ```
+#[derive(Clone)]
+pub struct DataGridColumn {
+    header: String,
+    width: f64,
+    min_width: f64,
+    cell_builder: Option<CellBuilder>,
+    comparator: Option<SortComparator>,
+}
+
+impl Default for DataGridColumn {
+    fn default() -> Self {
+        DataGridColumn {
+            header: String::default(),
+            width: 100.0,
+            min_width: 24.0,
+            cell_builder: None,
+            comparator: None,
+        }
+    }
+}
+
+impl DataGridColumn {
+    /// Creates a new column with the given header text.
+    pub fn new(header: impl Into<String>) -> Self {
+        DataGridColumn {
+            header: header.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the initial width of the column.
+    pub fn width(mut self, width: impl Into<f64>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the minimum width the column could be resized to.
+    pub fn min_width(mut self, min_width: impl Into<f64>) -> Self {
+        self.min_width = min_width.into();
+        self
+    }
+
+    /// Defines the template build function for the cells of the column.
+    pub fn cell_builder<F: Fn(&mut BuildContext, usize) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.cell_builder = Some(Rc::new(builder));
+        self
+    }
+
+    /// Defines the comparator that is used to sort the rows by this column. Columns without
+    /// comparator cannot be sorted.
+    pub fn sort_by<F: Fn(usize, usize) -> Ordering + 'static>(mut self, comparator: F) -> Self {
+        self.comparator = Some(Rc::new(comparator));
+        self
+    }
+
+    /// Gets the header text of the column.
+    pub fn header(&self) -> &str {
+        self.header.as_str()
+    }
+
+    /// Gets the current width of the column.
+    pub fn current_width(&self) -> f64 {
+        self.width
+    }
+
+    /// Returns `true` if the column defines a sort comparator.
+    pub fn is_sortable(&self) -> bool {
+        self.comparator.is_some()
+    }
+}
+
+// Returns the data row indices in view order.
+fn sorted_rows(
+    count: usize,
+    comparator: Option<&SortComparator>,
+    direction: SortDirection,
+) -> Vec<usize> {
+    let mut rows: Vec<usize> = (0..count).collect();
+
+    if let Some(comparator) = comparator {
+        match direction {
+            SortDirection::Ascending => rows.sort_by(|a, b| comparator(*a, *b)),
+            SortDirection::Descending => rows.sort_by(|a, b| comparator(*b, *a)),
+            SortDirection::None => {}
+        }
+    }
+
+    rows
+}
+
+// Returns the first realized row and the number of rows that have to be realized.
+fn visible_range(
+    scroll_offset: f64,
+    view_port_height: f64,
+    row_height: f64,
+    count: usize,
+) -> (usize, usize) {
+    if row_height <= 0.0 {
+        return (0, count);
+    }
+
+    let first = ((scroll_offset / row_height).floor().max(0.0) as usize).min(count);
+    let visible = ((view_port_height / row_height).ceil().max(0.0) as usize + 1).min(count - first);
+
+    (first, visible)
+}
+
+// Returns the scroll offset moved by delta and clamped to the scrollable area.
+fn clamp_scroll_offset(offset: f64, delta: f64, content_height: f64, view_port_height: f64) -> f64 {
+    (offset - delta)
+        .min((content_height - view_port_height).max(0.0))
+        .max(0.0)
+}
+
+// Returns the index of the column (given by x and width) that contains x.
+fn column_at(x: f64, columns: &[(f64, f64)]) -> usize {
+    for (index, (column_x, width)) in columns.iter().enumerate() {
+        if x < column_x + width {
+            return index;
+        }
+    }
+
+    columns.len().saturating_sub(1)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DataGridAction {
+    HeaderMouseDown { column: usize, position: Point },
+    MouseMove(Point),
+    MouseUp(Point),
+    Scroll(Point),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ColumnDrag {
+    Resize {
+        column: usize,
+        start_x: f64,
+        start_width: f64,
+    },
+    Move {
+        column: usize,
+        start_x: f64,
+        dragging: bool,
+    },
+}
+
+/// The `DataGridState` handles the columns, sorting, selection and row virtualization of a `DataGrid`.
+#[derive(Default, AsAny)]
+pub struct DataGridState {
+    actions: VecDeque<DataGridAction>,
+    columns: Vec<DataGridColumn>,
+    column_drag: Option<ColumnDrag>,
+    count: usize,
+    first_row: usize,
+    visible_rows: usize,
+    headers: Vec<Entity>,
+    // column of the header that shows the resize cursor
+    cursor_header: Option<usize>,
+    header_panel: Entity,
+    rows_panel: Entity,
+    view_port: Entity,
+    // realized rows with their cell containers
+    rows: Vec<(Entity, Vec<Entity>)>,
+    row_order: Vec<usize>,
+    scroll_offset: f64,
+    selected_indices: HashSet<usize>,
+    sort: (i32, SortDirection),
+    request_rows_update: bool,
+}
+
+impl DataGridState {
+    fn action(&mut self, action: DataGridAction) {
+        self.actions.push_back(action);
+    }
+
+    /// Gets the columns of the data grid in their current order.
+    pub fn columns(&self) -> &[DataGridColumn] {
+        &self.columns
+    }
+
+    /// Gets the data row indices in the current (sorted) view order.
+    pub fn row_order(&self) -> &[usize] {
+        &self.row_order
+    }
+
+    fn sort_rows(&mut self) {
+        let (column, direction) = self.sort;
+        let comparator = if column >= 0 {
+            self.columns
+                .get(column as usize)
+                .and_then(|c| c.comparator.as_ref())
+        } else {
+            None
+        };
+
+        self.row_order = sorted_rows(self.count, comparator, direction);
+        self.request_rows_update = true;
+    }
+
+    fn generate_headers(&mut self, ctx: &mut Context) {
+        let entity = ctx.entity;
+        ctx.clear_children_of(self.header_panel);
+        self.headers.clear();
+        self.cursor_header = None;
+
+        for (index, column) in self.columns.iter().enumerate() {
+            let icon = if self.sort.0 == index as i32 {
+                match self.sort.1 {
+                    SortDirection::Ascending => material_icons_font::MD_ARROW_UPWARD,
+                    SortDirection::Descending => material_icons_font::MD_ARROW_DOWNWARD,
+                    SortDirection::None => "",
+                }
+            } else {
+                ""
+            };
+
+            let header = DataGridColumnHeader::new()
+                .text(column.header.as_str())
+                .icon(icon)
+                .width(column.width)
+                .cursor_icon(CursorIcon::Default)
+                .on_mouse_down(move |states, m| {
+                    // follows the mouse also outside of the data grid, the captured release
+                    // ends the drag and resets the header wherever the mouse is
+                    states.capture_mouse(entity);
+                    states.get_mut::<DataGridState>(entity).action(
+                        DataGridAction::HeaderMouseDown {
+                            column: index,
+                            position: m.position,
+                        },
+                    );
+                    true
+                })
+                .build(&mut ctx.build_context());
+
+            ctx.append_child_entity_to(header, self.header_panel);
+            ctx.get_widget(header).update(false);
+            self.headers.push(header);
+        }
+    }
+
+    fn generate_rows(&mut self, ctx: &mut Context) {
+        self.request_rows_update = false;
+        ctx.clear_children_of(self.rows_panel);
+        self.rows.clear();
+
+        let entity = ctx.entity;
+        let row_height = *DataGrid::row_height_ref(&ctx.widget());
+        let end = (self.first_row + self.visible_rows).min(self.row_order.len());
+
+        for view_index in self.first_row..end {
+            let index = self.row_order[view_index];
+            let selected = self.selected_indices.contains(&index);
+
+            let (row, cells) = {
+                let build_context = &mut ctx.build_context();
+                let cells_panel = Stack::new().orientation("horizontal").build(build_context);
+                let mut cells = vec![];
+
+                for column in &self.columns {
+                    let cell = Container::new()
+                        .width(column.width)
+                        .clip(true)
+                        .build(build_context);
+
+                    if let Some(builder) = &column.cell_builder {
+                        let child = builder(build_context, index);
+                        build_context.register_shared_property::<f32>("opacity", child, entity);
+                        build_context.append_child(cell, child);
+                    }
+
+                    build_context.append_child(cells_panel, cell);
+                    cells.push(cell);
+                }
+
+                let row = DataGridRow::new()
+                    .index(index)
+                    .parent(entity.0)
+                    .selected(selected)
+                    .height(row_height)
+                    .build(build_context);
+                let mouse_behavior = MouseBehavior::new()
+                    .pressed(row)
+                    .enabled(row)
+                    .target(row.0)
+                    .build(build_context);
+                build_context.register_shared_property::<f32>("opacity", row, entity);
+                build_context.append_child(mouse_behavior, cells_panel);
+                build_context.append_child(row, mouse_behavior);
+                build_context.append_child(self.rows_panel, row);
+
+                (row, cells)
+            };
+
+            let mut row_widget = ctx.get_widget(row);
+            if selected {
+                row_widget
+                    .get_mut::<Selector>("selector")
+                    .set_state("selected");
+            }
+            row_widget.update(false);
+
+            self.rows.push((row, cells));
+        }
+    }
+
+    // Realizes the rows that are inside of the view port and moves the rows panel to the scroll offset.
+    fn realize_rows(&mut self, ctx: &mut Context) {
+        let view_port_height = ctx
+            .get_widget(self.view_port)
+            .get::<Rectangle>("bounds")
+            .height();
+        let row_height = *DataGrid::row_height_ref(&ctx.widget());
+
+        self.scroll_offset = clamp_scroll_offset(
+            self.scroll_offset,
+            0.0,
+            self.count as f64 * row_height,
+            view_port_height,
+        );
+
+        let (first_row, visible_rows) =
+            visible_range(self.scroll_offset, view_port_height, row_height, self.count);
+
+        if self.request_rows_update
+            || first_row != self.first_row
+            || visible_rows != self.visible_rows
+        {
+            self.first_row = first_row;
+            self.visible_rows = visible_rows;
+            self.generate_rows(ctx);
+        }
+
+        let offset = self.scroll_offset - first_row as f64 * row_height;
+        if (ctx
+            .get_widget(self.rows_panel)
+            .get::<Thickness>("margin")
+            .top()
+            + offset)
+            .abs()
+            > f64::EPSILON
+        {
+            ctx.get_widget(self.rows_panel)
+                .get_mut::<Thickness>("margin")
+                .set_top(-offset);
+        }
+    }
+
+    fn set_column_width(&mut self, ctx: &mut Context, column: usize, width: f64) {
+        let width = width.max(self.columns[column].min_width);
+        self.columns[column].width = width;
+
+        ctx.get_widget(self.headers[column])
+            .get_mut::<Constraint>("constraint")
+            .set_width(width);
+
+        for (_, cells) in &self.rows {
+            if let Some(cell) = cells.get(column) {
+                ctx.get_widget(*cell)
+                    .get_mut::<Constraint>("constraint")
+                    .set_width(width);
+            }
+        }
+    }
+
+    fn move_column(&mut self, ctx: &mut Context, from: usize, to: usize) {
+        let column = self.columns.remove(from);
+        self.columns.insert(to, column);
+
+        // keeps the sort column on the moved column
+        let sort_column = *DataGrid::sort_column_ref(&ctx.widget());
+        if sort_column >= 0 {
+            let sort_column = sort_column as usize;
+            let sort_column = if sort_column == from {
+                to
+            } else if from < sort_column && sort_column <= to {
+                sort_column - 1
+            } else if to <= sort_column && sort_column < from {
+                sort_column + 1
+            } else {
+                sort_column
+            };
+            self.sort.0 = sort_column as i32;
+            DataGrid::sort_column_set(&mut ctx.widget(), sort_column as i32);
+        }
+
+        self.generate_headers(ctx);
+        self.request_rows_update = true;
+    }
+
+    // Returns the column of the header whose resize grip contains the given position.
+    fn resize_grip_at(&self, ctx: &mut Context, position: Point) -> Option<usize> {
+        self.headers.iter().position(|header| {
+            let header = ctx.get_widget(*header);
+            let bounds = Rectangle::new(
+                header.clone::<Point>("position"),
+                header.clone::<Rectangle>("bounds").size(),
+            );
+
+            // the grip is on the right edge of the header
+            bounds.contains(position)
+                && position.x() >= bounds.x() + bounds.width() - RESIZE_GRIP_WIDTH
+        })
+    }
+
+    // Shows the resize cursor over the resize grips of the headers. Only the headers whose
+    // cursor changes are written.
+    fn update_header_cursors(&mut self, ctx: &mut Context, position: Point) {
+        let grip = self.resize_grip_at(ctx, position);
+
+        if grip == self.cursor_header {
+            return;
+        }
+
+        if let Some(header) = self.cursor_header.and_then(|c| self.headers.get(c)) {
+            ctx.get_widget(*header)
+                .set("cursor_icon", CursorIcon::Default);
+        }
+
+        if let Some(header) = grip.and_then(|c| self.headers.get(c)) {
+            ctx.get_widget(*header)
+                .set("cursor_icon", CursorIcon::ResizeHorizontal);
+        }
+
+        self.cursor_header = grip;
+    }
+
+    fn handle_action(&mut self, ctx: &mut Context, action: DataGridAction) {
+        match action {
+            DataGridAction::HeaderMouseDown { column, position } => {
+                if column >= self.headers.len() {
+                    return;
+                }
+
+                self.column_drag = if self.resize_grip_at(ctx, position) == Some(column) {
+                    Some(ColumnDrag::Resize {
+                        column,
+                        start_x: position.x(),
+                        start_width: self.columns[column].width,
+                    })
+                } else {
+                    Some(ColumnDrag::Move {
+                        column,
+                        start_x: position.x(),
+                        dragging: false,
+                    })
+                };
+            }
+            DataGridAction::MouseMove(position) => match self.column_drag {
+                Some(ColumnDrag::Resize {
+                    column,
+                    start_x,
+                    start_width,
+                }) => {
+                    self.set_column_width(ctx, column, start_width + position.x() - start_x);
+                }
+                Some(ColumnDrag::Move {
+                    column,
+                    start_x,
+                    dragging: false,
+                }) => {
+                    if (position.x() - start_x).abs() > DRAG_THRESHOLD {
+                        self.column_drag = Some(ColumnDrag::Move {
+                            column,
+                            start_x,
+                            dragging: true,
+                        });
+                        ctx.get_widget(self.headers[column]).set("opacity", 0.5_f32);
+                    }
+                }
+                None => self.update_header_cursors(ctx, position),
+                _ => {}
+            },
+            DataGridAction::MouseUp(position) => match self.column_drag.take() {
+                Some(ColumnDrag::Move {
+                    column,
+                    dragging: false,
+                    ..
+                }) => {
+                    if !self.columns[column].is_sortable() {
+                        return;
+                    }
+
+                    let direction = if self.sort.0 == column as i32 {
+                        self.sort.1.toggle()
+                    } else {
+                        SortDirection::Ascending
+                    };
+
+                    DataGrid::sort_column_set(&mut ctx.widget(), column as i32);
+                    DataGrid::sort_direction_set(&mut ctx.widget(), direction);
+                }
+                Some(ColumnDrag::Move {
+                    column,
+                    dragging: true,
+                    ..
+                }) => {
+                    ctx.get_widget(self.headers[column]).set("opacity", 1.0_f32);
+
+                    let header_columns: Vec<(f64, f64)> = self
+                        .headers
+                        .iter()
+                        .map(|header| {
+                            let header = ctx.get_widget(*header);
+                            (
+                                header.get::<Point>("position").x(),
+                                header.get::<Rectangle>("bounds").width(),
+                            )
+                        })
+                        .collect();
+
+                    let target = column_at(position.x(), &header_columns);
+                    if target != column {
+                        self.move_column(ctx, column, target);
+                    }
+                }
+                _ => {}
+            },
+            DataGridAction::Scroll(delta) => {
+                let view_port_height = ctx
+                    .get_widget(self.view_port)
+                    .get::<Rectangle>("bounds")
+                    .height();
+                let row_height = *DataGrid::row_height_ref(&ctx.widget());
+
+                self.scroll_offset = clamp_scroll_offset(
+                    self.scroll_offset,
+                    delta.y() * SCROLL_SPEED,
+                    self.count as f64 * row_height,
+                    view_port_height,
+                );
+            }
+        }
+    }
+
+    // Syncs the selected flag of the realized rows with the selected indices of the data grid.
+    fn update_selection(&mut self, ctx: &mut Context) {
+        let selected_indices = DataGrid::selected_indices_clone(&ctx.widget()).0;
+
+        if selected_indices == self.selected_indices {
+            return;
+        }
+
+        for (row, _) in &self.rows {
+            let mut widget = ctx.get_widget(*row);
+            let index = *widget.get::<usize>("index");
+            let selected = selected_indices.contains(&index);
+
+            if *widget.get::<bool>("selected") == selected {
+                continue;
+            }
+
+            widget.set("selected", selected);
+
+            if selected {
+                widget.get_mut::<Selector>("selector").set_state("selected");
+            } else {
+                widget.get_mut::<Selector>("selector").clear_state();
+            }
+
+            widget.update(false);
+        }
+
+        self.selected_indices = selected_indices;
+    }
+}
+
+impl State for DataGridState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.header_panel = ctx
+            .entity_of_child(ID_HEADER_PANEL)
+            .expect("DataGridState.init: header panel child could not be found.");
+        self.rows_panel = ctx
+            .entity_of_child(ID_ROWS_PANEL)
+            .expect("DataGridState.init: rows panel child could not be found.");
+        self.view_port = ctx
+            .entity_of_child(ID_VIEW_PORT)
+            .expect("DataGridState.init: view port child could not be found.");
+
+        self.count = *DataGrid::count_ref(&ctx.widget());
+        self.selected_indices = DataGrid::selected_indices_clone(&ctx.widget()).0;
+        self.sort = (
+            *DataGrid::sort_column_ref(&ctx.widget()),
+            *DataGrid::sort_direction_ref(&ctx.widget()),
+        );
+
+        self.sort_rows();
+        self.generate_headers(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        while let Some(action) = self.actions.pop_front() {
+            self.handle_action(ctx, action);
+        }
+
+        let count = *DataGrid::count_ref(&ctx.widget());
+        let sort = (
+            *DataGrid::sort_column_ref(&ctx.widget()),
+            *DataGrid::sort_direction_ref(&ctx.widget()),
+        );
+
+        if count != self.count || sort != self.sort || *DataGrid::request_update_ref(&ctx.widget())
+        {
+            DataGrid::request_update_set(&mut ctx.widget(), false);
+
+            let headers_changed = sort != self.sort;
+            self.count = count;
+            self.sort = sort;
+            self.sort_rows();
+
+            if headers_changed {
+                self.generate_headers(ctx);
+            }
+        }
+
+        self.update_selection(ctx);
+        self.realize_rows(ctx);
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // realizes missing rows after the view port has changed its size
+        self.realize_rows(ctx);
+    }
+}
+
+/// The `DataGridRowState` handles the interaction and selection of a `DataGridRow`.
+#[derive(Default, AsAny)]
+pub struct DataGridRowState {
+    request_selection_toggle: Cell<bool>,
+}
+
+impl DataGridRowState {
+    fn toggle_selection(&self) {
+        self.request_selection_toggle.set(true);
+    }
+}
+
+impl State for DataGridRowState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if !ctx.widget().get::<bool>("enabled") || !self.request_selection_toggle.get() {
+            return;
+        }
+        self.request_selection_toggle.set(false);
+
+        let selected = *ctx.widget().get::<bool>("selected");
+        let index = *ctx.widget().get::<usize>("index");
+        let parent_entity: Entity = (*ctx.widget().get::<u32>("parent")).into();
+
+        let mut parent = ctx.get_widget(parent_entity);
+        let selection_mode = *parent.get::<SelectionMode>("selection_mode");
+
+        if selection_mode == SelectionMode::None {
+            return;
+        }
+
+        if selected {
+            parent
+                .get_mut::<SelectedIndices>("selected_indices")
+                .0
+                .remove(&index);
+        } else {
+            if selection_mode == SelectionMode::Single {
+                parent
+                    .get_mut::<SelectedIndices>("selected_indices")
+                    .0
+                    .clear();
+            }
+
+            parent
+                .get_mut::<SelectedIndices>("selected_indices")
+                .0
+                .insert(index);
+        }
+
+        let selected_indices: Vec<usize> = parent
+            .get::<SelectedIndices>("selected_indices")
+            .0
+            .iter()
+            .copied()
+            .collect();
+
+        ctx.push_event_strategy_by_entity(
+            SelectionChangedEvent(parent_entity, selected_indices),
+            parent_entity,
+            EventStrategy::Direct,
+        );
+    }
+}
+
+widget!(
+    /// The `DataGridRow` describes a row inside of a `DataGrid`.
+    ///
+    /// **style:** `data_grid_row`
+    DataGridRow<DataGridRowState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the data index of the row.
+        index: usize,
+
+        /// Sets or shares the data grid id.
+        parent: u32
+    }
+);
+
+impl Template for DataGridRow {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("DataGridRow")
+            .style(STYLE_DATA_GRID_ROW)
+            .height(24.0)
+            .selected(false)
+            .pressed(false)
+            .padding(0.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .on_click(move |states, _| {
+                states.get::<DataGridRowState>(id).toggle_selection();
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+widget!(
+    /// The `DataGridColumnHeader` displays the header text and the sort direction of a `DataGrid` column.
+    ///
+    /// **style:** `data_grid_header`
+    DataGridColumnHeader: MouseHandler {
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or shares the sort icon property.
+        icon: String,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the padding property.
+        padding: Thickness
+    }
+);
+
+impl Template for DataGridColumnHeader {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("DataGridColumnHeader")
+            .style(STYLE_DATA_GRID_HEADER)
+            .height(28.0)
+            .padding((8.0, 0.0, 4.0, 0.0))
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_width((0.0, 0.0, 1.0, 1.0))
+            .border_brush(colors::BOMBAY_COLOR)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Medium")
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .clip(true)
+            .child(
+                Grid::new()
+                    .columns(Columns::create().push("*").push("auto"))
+                    .child(
+                        TextBlock::new()
+                            .v_align("center")
+                            .text(id)
+                            .foreground(id)
+                            .font_size(id)
+                            .font(id)
+                            .build(ctx),
+                    )
+                    .child(
+                        FontIconBlock::new()
+                            .attach(Grid::column(1))
+                            .v_align("center")
+                            .icon(id)
+                            .icon_brush(id)
+                            .icon_size(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+widget!(
+    /// The `DataGrid` displays tabular data in columns. Columns are defined with `DataGridColumn`s.
+    /// A click on the header of a sortable column sorts the rows, dragging the right header edge
+    /// resizes a column and dragging a header reorders the columns. Only the visible rows are
+    /// generated.
+    ///
+    /// **style:** `data_grid`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// DataGrid::new()
+    ///     .count(persons.len())
+    ///     .column(
+    ///         DataGridColumn::new("Name")
+    ///             .cell_builder(move |bc, index| TextBlock::new().text(names[index].as_str()).build(bc))
+    ///             .sort_by(move |a, b| sort_names[a].cmp(&sort_names[b])),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    DataGrid<DataGridState>: MouseHandler, SelectionChangedHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the row count.
+        count: usize,
+
+        /// Sets or shares the height of a row.
+        row_height: f64,
+
+        /// Sets or shares the selection mode property.
+        selection_mode: SelectionMode,
+
+        /// Sets or shares the selected (data) indices.
+        selected_indices: SelectedIndices,
+
+        /// Sets or shares the index of the sort column. `-1` means the rows are not sorted.
+        sort_column: i32,
+
+        /// Sets or shares the sort direction.
+        sort_direction: SortDirection,
+
+        /// Use this flag to force the redrawing of the rows.
+        request_update: bool
+    }
+);
+
+impl DataGrid {
+    /// Adds a column to the data grid.
+    pub fn column(mut self, column: DataGridColumn) -> Self {
+        self.state_mut().columns.push(column);
+        self
+    }
+
+    /// Defines all columns of the data grid.
+    pub fn columns(mut self, columns: Vec<DataGridColumn>) -> Self {
+        self.state_mut().columns = columns;
+        self
+    }
+}
+
+impl Template for DataGrid {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let header_panel = Stack::new()
+            .id(ID_HEADER_PANEL)
+            .orientation("horizontal")
+            .build(ctx);
+
+        let rows_panel = Stack::new()
+            .id(ID_ROWS_PANEL)
+            .v_align("start")
+            .orientation("vertical")
+            .build(ctx);
+
+        let view_port = Container::new()
+            .id(ID_VIEW_PORT)
+            .attach(Grid::row(1))
+            .clip(true)
+            .child(rows_panel)
+            .build(ctx);
+
+        self.name("DataGrid")
+            .style(STYLE_DATA_GRID)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(2.0)
+            .row_height(24.0)
+            .selection_mode("single")
+            .selected_indices(HashSet::new())
+            .sort_column(-1)
+            .sort_direction("none")
+            .on_mouse_move(move |states, p| {
+                states
+                    .get_mut::<DataGridState>(id)
+                    .action(DataGridAction::MouseMove(p));
+                false
+            })
+            .on_mouse_up(move |states, m| {
+                states
+                    .get_mut::<DataGridState>(id)
+                    .action(DataGridAction::MouseUp(m.position));
+            })
+            .on_scroll(move |states, delta| {
+                states
+                    .get_mut::<DataGridState>(id)
+                    .action(DataGridAction::Scroll(delta));
+                true
+            })
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(
+                        Grid::new()
+                            .rows(Rows::create().push("auto").push("*"))
+                            .clip(true)
+                            .child(header_panel)
+                            .child(view_port)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_rows() {
+        let values = vec![3, 1, 2];
+        let comparator: SortComparator = Rc::new(move |a, b| values[a].cmp(&values[b]));
+
+        assert_eq!(
+            sorted_rows(3, None, SortDirection::Ascending),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            sorted_rows(3, Some(&comparator), SortDirection::None),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            sorted_rows(3, Some(&comparator), SortDirection::Ascending),
+            vec![1, 2, 0]
+        );
+        assert_eq!(
+            sorted_rows(3, Some(&comparator), SortDirection::Descending),
+            vec![0, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_visible_range() {
+        assert_eq!(visible_range(0.0, 100.0, 20.0, 100), (0, 6));
+        assert_eq!(visible_range(30.0, 100.0, 20.0, 100), (1, 6));
+        assert_eq!(visible_range(0.0, 100.0, 20.0, 3), (0, 3));
+        assert_eq!(visible_range(1980.0, 100.0, 20.0, 100), (99, 1));
+        assert_eq!(visible_range(0.0, 100.0, 0.0, 10), (0, 10));
+    }
+
+    #[test]
+    fn test_clamp_scroll_offset() {
+        assert_eq!(clamp_scroll_offset(0.0, 10.0, 200.0, 100.0), 0.0);
+        assert_eq!(clamp_scroll_offset(0.0, -10.0, 200.0, 100.0), 10.0);
+        assert_eq!(clamp_scroll_offset(95.0, -10.0, 200.0, 100.0), 100.0);
+        assert_eq!(clamp_scroll_offset(0.0, -10.0, 50.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn test_column_at() {
+        let columns = vec![(0.0, 50.0), (50.0, 100.0), (150.0, 20.0)];
+
+        assert_eq!(column_at(10.0, &columns), 0);
+        assert_eq!(column_at(60.0, &columns), 1);
+        assert_eq!(column_at(160.0, &columns), 2);
+        assert_eq!(column_at(500.0, &columns), 2);
+        assert_eq!(column_at(10.0, &[]), 0);
+    }
+}
@@ -0,0 +1,314 @@
+use std::rc::Rc;
+
+use crate::{
+    api::prelude::*,
+    prelude::*,
+    proc_macros::*,
+    shell::prelude::{Key, WindowRequest},
+    theme::prelude::*,
+};
+
+// --- KEYS --
+pub static STYLE_DIALOG: &str = "dialog";
+// --- KEYS --
+
+/// Describes how a dialog was closed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DialogResult {
+    /// The dialog was closed without a decision e.g. by closing its window.
+    None,
+    Ok,
+    Cancel,
+    Yes,
+    No,
+}
+
+/// This event occurs if a dialog is closed.
+#[derive(Clone, Event)]
+pub struct DialogResultEvent(pub Entity, pub DialogResult);
+
+pub type DialogResultHandlerFn = dyn Fn(&mut StatesContext, DialogResult) + 'static;
+
+/// Used to handle the result of a dialog.
+#[derive(IntoHandler)]
+pub struct DialogResultEventHandler {
+    pub handler: Rc<DialogResultHandlerFn>,
+}
+
+impl EventHandler for DialogResultEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DialogResultEvent>() {
+            (self.handler)(states, event.1);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DialogResultEvent>()
+    }
+}
+
+/// Implement this trait if you want that your widget can handle the result of a dialog.
+pub trait DialogResultHandler: Sized + Widget {
+    /// Inserts a handler that is called with the result if the dialog is closed.
+    fn on_dialog_result<H: Fn(&mut StatesContext, DialogResult) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DialogResultEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// The `DialogState` closes the dialog and delivers its result.
+#[derive(Default, AsAny)]
+pub struct DialogState {
+    close_request: Option<DialogResult>,
+    closed: bool,
+    content: Option<Entity>,
+    owner: Option<WindowEventSender>,
+    result: Option<DialogResult>,
+}
+
+impl DialogState {
+    /// Closes the dialog with the given result.
+    pub fn close(&mut self, result: DialogResult) {
+        self.close_request = Some(result);
+    }
+
+    fn finish(&mut self, ctx: &mut Context, result: DialogResult) {
+        if self.closed {
+            return;
+        }
+
+        self.closed = true;
+
+        // a dialog window hands the result over to the blocker dialog of its owner window
+        if let Some(owner) = &self.owner {
+            owner.send(DialogResultEvent(owner.target(), result));
+            ctx.send_window_request(WindowRequest::Close);
+            return;
+        }
+
+        let entity = ctx.entity;
+        let _ = ctx.remove_child_from_overlay(entity);
+    }
+}
+
+impl State for DialogState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(result) = self.close_request.take() {
+            let entity = ctx.entity;
+            ctx.push_event_strategy_by_entity(
+                DialogResultEvent(entity, result),
+                entity,
+                EventStrategy::Direct,
+            );
+            ctx.send_window_request(WindowRequest::Redraw);
+        }
+
+        if let Some(result) = self.result.take() {
+            self.finish(ctx, result);
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, _: &mut Context) {
+        // the dialog window was closed by the window manager
+        if let (false, Some(owner)) = (self.closed, &self.owner) {
+            owner.send(DialogResultEvent(owner.target(), DialogResult::None));
+        }
+    }
+}
+
+widget!(
+    /// The `Dialog` displays its content modal above the window content. The `scrim` covers the
+    /// rest of the window and blocks all mouse and keyboard input that is not handled by the
+    /// content. A dialog is shown by adding it to the overlay or in a separate window by
+    /// `Dialog::show_window`. The content closes the dialog by `Dialog::close`, `Escape` closes
+    /// it with `DialogResult::Cancel`. The result is delivered to `on_dialog_result`.
+    ///
+    /// **style:** `dialog`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let dialog = Dialog::new()
+    ///     .title("Rename")
+    ///     .content(TextBox::new().build(&mut ctx.build_context()))
+    ///     .on_dialog_result(|states, result| println!("{:?}", result));
+    /// ctx.append_child_to_overlay(dialog);
+    /// ```
+    Dialog<DialogState>: DialogResultHandler, KeyDownHandler, MouseHandler {
+        /// Sets or shares the title property.
+        title: String,
+
+        /// Sets or shares the brush that covers the window below the dialog.
+        scrim: Brush,
+
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String
+    }
+);
+
+impl Dialog {
+    /// Sets the content of the dialog.
+    pub fn content(mut self, content: Entity) -> Self {
+        self.state_mut().content = Some(content);
+        self
+    }
+
+    // Used by dialog windows to deliver the result to the owner window.
+    fn owner(mut self, owner: WindowEventSender) -> Self {
+        self.state_mut().owner = Some(owner);
+        self
+    }
+
+    /// Closes the dialog that contains the widget of the given context with the given result.
+    pub fn close(ctx: &mut Context, result: DialogResult) {
+        if let Some(dialog) = ctx.parent_entity_by_style(STYLE_DIALOG) {
+            ctx.push_event_strategy_by_entity(
+                DialogResultEvent(dialog, result),
+                dialog,
+                EventStrategy::Direct,
+            );
+            ctx.send_window_request(WindowRequest::Redraw);
+        }
+    }
+
+    /// Shows the content created by `content` modal in a new window that is owned by the current
+    /// window. The current window is blocked until the dialog window is closed, afterwards
+    /// `handler` is called with the result.
+    pub fn show_window<F, H>(
+        ctx: &mut Context,
+        title: impl Into<String>,
+        size: (f64, f64),
+        content: F,
+        handler: H,
+    ) where
+        F: Fn(&mut BuildContext) -> Entity + 'static,
+        H: Fn(&mut StatesContext, DialogResult) + 'static,
+    {
+        let blocker = Dialog::new()
+            .scrim("transparent")
+            .on_dialog_result(handler)
+            .build(&mut ctx.build_context());
+        let _ = ctx.build_context().append_child_to_overlay(blocker);
+
+        let owner = ctx.window_event_sender(blocker);
+        let title = title.into();
+
+        ctx.show_child_window(move |ctx| {
+            let content = content(ctx);
+
+            Window::new()
+                .title(title.clone())
+                .size(size.0, size.1)
+                .child(
+                    Dialog::new()
+                        .scrim("transparent")
+                        .owner(owner.clone())
+                        .content(content)
+                        .build(ctx),
+                )
+                .build(ctx)
+        });
+    }
+}
+
+impl Template for Dialog {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let content = self.state_mut().content;
+        let in_window = self.state_mut().owner.is_some();
+
+        let mut dialog = self
+            .name("Dialog")
+            .style(STYLE_DIALOG)
+            .title("")
+            .scrim("#66000000")
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding(16.0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(16.0)
+            .font("Roboto-Medium")
+            // blocks the input of the widgets below the dialog
+            .on_mouse_down(|_, _| true)
+            .on_mouse_move(|_, _| true)
+            .on_scroll(|_, _| true)
+            .on_key_down(move |states, event| {
+                if event.key == Key::Escape {
+                    states
+                        .get_mut::<DialogState>(id)
+                        .close(DialogResult::Cancel);
+                }
+                true
+            })
+            .on_dialog_result(move |states, result| {
+                states.get_mut::<DialogState>(id).result = Some(result);
+            })
+            .child(Container::new().background(("scrim", id)).build(ctx));
+
+        // the blocker of a dialog window has no content
+        if let Some(content) = content {
+            let mut stack = Stack::new().orientation("vertical");
+
+            if !in_window {
+                stack = stack.child(
+                    TextBlock::new()
+                        .margin((0.0, 0.0, 0.0, 8.0))
+                        .foreground(id)
+                        .text(("title", id))
+                        .font_size(id)
+                        .font(id)
+                        .build(ctx),
+                );
+            }
+
+            let (h_align, v_align) = if in_window {
+                ("stretch", "stretch")
+            } else {
+                ("center", "center")
+            };
+
+            dialog = dialog.child(
+                Container::new()
+                    .h_align(h_align)
+                    .v_align(v_align)
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .child(stack.child(content).build(ctx))
+                    .build(ctx),
+            );
+        }
+
+        dialog
+    }
+}
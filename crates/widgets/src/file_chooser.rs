@@ -0,0 +1,625 @@
+use std::{
+    collections::HashSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+
+// --- KEYS --
+pub static STYLE_FILE_CHOOSER: &str = "file_chooser";
+static ENTRIES_VIEW: &str = "entries_view";
+// --- KEYS --
+
+/// An entry of a folder displayed by the `FileChooser`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileEntry {
+    pub name: String,
+    pub is_dir: bool,
+}
+
+impl FileEntry {
+    // Folders are displayed with a trailing slash.
+    fn display(&self) -> String {
+        if self.is_dir {
+            format!("{}/", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+// Reads the visible entries of the folder, folders first. Folder dialogs list only folders, the
+// files are filtered by the selected filter.
+fn read_entries(
+    folder: &Path,
+    kind: FileDialogKind,
+    filter: Option<&FileFilter>,
+) -> io::Result<Vec<FileEntry>> {
+    let mut entries: Vec<FileEntry> = fs::read_dir(folder)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let is_dir = entry.path().is_dir();
+
+            if name.starts_with('.')
+                || (!is_dir && kind == FileDialogKind::Folder)
+                || (!is_dir && matches!(filter, Some(filter) if !filter.matches(&name)))
+            {
+                return None;
+            }
+
+            Some(FileEntry { name, is_dir })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    Ok(entries)
+}
+
+// Gets the paths that are picked by the given input. It is empty if the input is not valid.
+fn accepted_paths(
+    kind: FileDialogKind,
+    folder: &Path,
+    file_name: &str,
+    selected: &[&FileEntry],
+) -> Vec<PathBuf> {
+    let file = folder.join(file_name);
+
+    match kind {
+        FileDialogKind::OpenMultiple if !selected.is_empty() => selected
+            .iter()
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| folder.join(&entry.name))
+            .collect(),
+        FileDialogKind::Open | FileDialogKind::OpenMultiple
+            if !file_name.is_empty() && file.is_file() =>
+        {
+            vec![file]
+        }
+        FileDialogKind::Save if !file_name.is_empty() && !file.is_dir() => vec![file],
+        FileDialogKind::Folder if !file_name.is_empty() && file.is_dir() => vec![file],
+        FileDialogKind::Folder if folder.is_dir() => vec![folder.to_path_buf()],
+        _ => vec![],
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum FileChooserAction {
+    Up,
+    Navigate,
+    SelectionChanged,
+    FilterChanged,
+    Accept,
+    Cancel,
+}
+
+/// The `FileChooserState` lists the entries of the current folder and delivers the picked paths.
+#[derive(Default, AsAny)]
+pub struct FileChooserState {
+    action: Option<FileChooserAction>,
+    delivered: bool,
+    entries: Vec<FileEntry>,
+    folder: PathBuf,
+    request: FileDialogRequest,
+    target: Option<Entity>,
+}
+
+impl FileChooserState {
+    fn action(&mut self, action: FileChooserAction) {
+        self.action = Some(action);
+    }
+
+    fn filter(&self, ctx: &mut Context) -> Option<&FileFilter> {
+        let index = *FileChooser::selected_filter_ref(&ctx.widget());
+
+        if index < 0 {
+            return None;
+        }
+
+        self.request.filters.get(index as usize)
+    }
+
+    fn load(&mut self, folder: PathBuf, ctx: &mut Context) {
+        let entries = match read_entries(&folder, self.request.kind, self.filter(ctx)) {
+            Ok(entries) => entries,
+            // keeps the current folder if the new one could not be read
+            Err(_) => {
+                let folder = self.folder.to_string_lossy().into_owned();
+                FileChooser::folder_set(&mut ctx.widget(), folder);
+                return;
+            }
+        };
+
+        self.entries = entries;
+        self.folder = folder;
+
+        let display = self.entries.iter().map(FileEntry::display).collect();
+        FileChooser::folder_set(
+            &mut ctx.widget(),
+            self.folder.to_string_lossy().into_owned(),
+        );
+        FileChooser::entries_set(&mut ctx.widget(), display);
+        FileChooser::entry_count_set(&mut ctx.widget(), self.entries.len());
+
+        let mut entries_view = ctx.child(ENTRIES_VIEW);
+        entries_view.set("selected_indices", SelectedIndices(HashSet::new()));
+        entries_view.set("selected_entities", SelectedEntities(HashSet::new()));
+        entries_view.set("request_update", true);
+    }
+
+    fn selected(&self, ctx: &mut Context) -> Vec<&FileEntry> {
+        let mut indices: Vec<usize> = FileChooser::selected_indices_ref(&ctx.widget())
+            .0
+            .iter()
+            .copied()
+            .collect();
+        indices.sort_unstable();
+
+        indices
+            .iter()
+            .filter_map(|index| self.entries.get(*index))
+            .collect()
+    }
+
+    // A selected folder is opened, a selected file is taken as file name.
+    fn selection_changed(&mut self, ctx: &mut Context) {
+        let selected: Vec<FileEntry> = self.selected(ctx).into_iter().cloned().collect();
+
+        if let Some(folder) = selected.iter().find(|entry| entry.is_dir) {
+            let folder = self.folder.join(&folder.name);
+            self.load(folder, ctx);
+            return;
+        }
+
+        if let [file] = selected.as_slice() {
+            FileChooser::file_name_set(&mut ctx.widget(), file.name.clone());
+        }
+    }
+
+    fn accept(&mut self, ctx: &mut Context) {
+        let file_name = FileChooser::file_name_clone(&ctx.widget());
+
+        // a typed folder is opened
+        if self.request.kind != FileDialogKind::Folder
+            && !file_name.is_empty()
+            && self.folder.join(&file_name).is_dir()
+        {
+            FileChooser::file_name_set(&mut ctx.widget(), String::new());
+            let folder = self.folder.join(&file_name);
+            self.load(folder, ctx);
+            return;
+        }
+
+        let paths = accepted_paths(
+            self.request.kind,
+            &self.folder,
+            &file_name,
+            &self.selected(ctx),
+        );
+
+        if paths.is_empty() {
+            return;
+        }
+
+        self.deliver(
+            FileDialogResult {
+                kind: self.request.kind,
+                paths,
+            },
+            ctx,
+        );
+        Dialog::close(ctx, DialogResult::Ok);
+    }
+
+    fn deliver(&mut self, result: FileDialogResult, ctx: &mut Context) {
+        if self.delivered {
+            return;
+        }
+
+        self.delivered = true;
+
+        if let Some(target) = self.target {
+            ctx.dispatcher().send(target, result);
+        }
+    }
+}
+
+impl State for FileChooserState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let folder = self
+            .request
+            .folder
+            .clone()
+            .filter(|folder| folder.is_dir())
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_else(|| PathBuf::from("/"));
+
+        let filters = self
+            .request
+            .filters
+            .iter()
+            .map(|filter| filter.name.clone())
+            .collect::<Vec<String>>();
+        FileChooser::filter_count_set(&mut ctx.widget(), filters.len());
+        FileChooser::filters_set(&mut ctx.widget(), filters);
+        FileChooser::file_name_set(&mut ctx.widget(), self.request.file_name.clone());
+
+        self.load(folder, ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let action = match self.action.take() {
+            Some(action) => action,
+            None => return,
+        };
+
+        match action {
+            FileChooserAction::Up => {
+                if let Some(parent) = self.folder.parent().map(Path::to_path_buf) {
+                    self.load(parent, ctx);
+                }
+            }
+            FileChooserAction::Navigate => {
+                let folder = PathBuf::from(FileChooser::folder_clone(&ctx.widget()));
+                self.load(folder, ctx);
+            }
+            FileChooserAction::SelectionChanged => self.selection_changed(ctx),
+            FileChooserAction::FilterChanged => {
+                let folder = self.folder.clone();
+                self.load(folder, ctx);
+            }
+            FileChooserAction::Accept => self.accept(ctx),
+            FileChooserAction::Cancel => Dialog::close(ctx, DialogResult::Cancel),
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // the dialog was closed without a pick
+        self.deliver(FileDialogResult::canceled(self.request.kind), ctx);
+    }
+}
+
+widget!(
+    /// The `FileChooser` is the content of a `Dialog` that picks files or a folder. It's rendered
+    /// by OrbTk and is shown by the `FileDialogs` service if there is no native file dialog. A
+    /// click on a folder opens it, `Enter` in the path box opens the typed folder.
+    ///
+    /// **style:** `file_chooser`
+    FileChooser<FileChooserState> {
+        /// Sets or shares the path of the current folder.
+        folder: String,
+
+        /// Sets or shares the file name.
+        file_name: String,
+
+        /// Sets or shares the displayed entries of the current folder.
+        entries: Vec<String>,
+
+        /// Sets or shares the number of entries.
+        entry_count: usize,
+
+        /// Sets or shares the selected entries.
+        selected_indices: SelectedIndices,
+
+        /// Sets or shares the names of the filters.
+        filters: Vec<String>,
+
+        /// Sets or shares the number of filters.
+        filter_count: usize,
+
+        /// Sets or shares the index of the selected filter.
+        selected_filter: i32
+    }
+);
+
+impl FileChooser {
+    // Sets the request that is displayed.
+    fn request(mut self, request: FileDialogRequest) -> Self {
+        self.state_mut().request = request;
+        self
+    }
+
+    // Sets the widget that receives the result.
+    fn target(mut self, target: Entity) -> Self {
+        self.state_mut().target = Some(target);
+        self
+    }
+
+    /// Shows the file chooser in a dialog on the overlay of the current window. After the dialog
+    /// is closed the state of `target` receives a `FileDialogResult` message. It's used as
+    /// fallback of the `FileDialogs` service.
+    pub fn show(ctx: &mut Context, request: FileDialogRequest, target: Entity) {
+        let bctx = &mut ctx.build_context();
+        let title = request.display_title().to_string();
+        let content = FileChooser::new()
+            .request(request)
+            .target(target)
+            .build(bctx);
+        let dialog = Dialog::new().title(title).content(content).build(bctx);
+        let _ = bctx.append_child_to_overlay(dialog);
+    }
+}
+
+impl Template for FileChooser {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let kind = self.state_mut().request.kind;
+        let has_filters = !self.state_mut().request.filters.is_empty();
+
+        let accept_text = match kind {
+            FileDialogKind::Open | FileDialogKind::OpenMultiple => "Open",
+            FileDialogKind::Save => "Save",
+            FileDialogKind::Folder => "Select",
+        };
+
+        let selection_mode = if kind == FileDialogKind::OpenMultiple {
+            SelectionMode::Multiple
+        } else {
+            SelectionMode::Single
+        };
+
+        let visibility = |visible: bool| {
+            if visible {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            }
+        };
+
+        self.name("FileChooser")
+            .style(STYLE_FILE_CHOOSER)
+            .folder("")
+            .file_name("")
+            .entries(vec![])
+            .entry_count(0)
+            .selected_indices(HashSet::new())
+            .filters(vec![])
+            .filter_count(0)
+            .selected_filter(0)
+            .width(480.0)
+            .height(360.0)
+            .child(
+                Grid::new()
+                    .rows(
+                        Rows::create()
+                            .push("auto")
+                            .push("*")
+                            .push("auto")
+                            .push("auto"),
+                    )
+                    .child(
+                        Grid::new()
+                            .columns(Columns::create().push("auto").push(8.0).push("*"))
+                            .attach(Grid::row(0))
+                            .child(
+                                Button::new()
+                                    .style("button_small")
+                                    .attach(Grid::column(0))
+                                    .icon(material_icons_font::MD_ARROW_UPWARD)
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get_mut::<FileChooserState>(id)
+                                            .action(FileChooserAction::Up);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                TextBox::new()
+                                    .attach(Grid::column(2))
+                                    .text(("folder", id))
+                                    .on_activate(move |states, _| {
+                                        states
+                                            .get_mut::<FileChooserState>(id)
+                                            .action(FileChooserAction::Navigate);
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        ListView::new()
+                            .id(ENTRIES_VIEW)
+                            .attach(Grid::row(1))
+                            .margin((0.0, 8.0, 0.0, 8.0))
+                            .selection_mode(selection_mode)
+                            .selected_indices(id)
+                            .count(("entry_count", id))
+                            .items_builder(move |bc, index| {
+                                let entry =
+                                    bc.get_widget(id).get::<Vec<String>>("entries")[index].clone();
+                                let icon = if entry.ends_with('/') {
+                                    material_icons_font::MD_FOLDER
+                                } else {
+                                    material_icons_font::MD_INSERT_DRIVE_FILE
+                                };
+
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .spacing(8.0)
+                                    .child(
+                                        FontIconBlock::new().v_align("center").icon(icon).build(bc),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .v_align("center")
+                                            .text(entry.trim_end_matches('/'))
+                                            .build(bc),
+                                    )
+                                    .build(bc)
+                            })
+                            .on_selection_changed(move |states, _, _| {
+                                states
+                                    .get_mut::<FileChooserState>(id)
+                                    .action(FileChooserAction::SelectionChanged);
+                            })
+                            .build(ctx),
+                    )
+                    .child(
+                        Grid::new()
+                            .columns(Columns::create().push("*").push(8.0).push("auto"))
+                            .attach(Grid::row(2))
+                            .margin((0.0, 0.0, 0.0, 8.0))
+                            .child(
+                                TextBox::new()
+                                    .attach(Grid::column(0))
+                                    .water_mark("File name")
+                                    .text(("file_name", id))
+                                    .visibility(visibility(kind != FileDialogKind::Folder))
+                                    .on_activate(move |states, _| {
+                                        states
+                                            .get_mut::<FileChooserState>(id)
+                                            .action(FileChooserAction::Accept);
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                ComboBox::new()
+                                    .attach(Grid::column(2))
+                                    .min_width(120.0)
+                                    .visibility(visibility(has_filters))
+                                    .count(("filter_count", id))
+                                    .selected_index(("selected_filter", id))
+                                    .items_builder(move |bc, index| {
+                                        let name = bc.get_widget(id).get::<Vec<String>>("filters")
+                                            [index]
+                                            .clone();
+                                        TextBlock::new().v_align("center").text(name).build(bc)
+                                    })
+                                    .on_changed("selected_index", move |states, _| {
+                                        states
+                                            .get_mut::<FileChooserState>(id)
+                                            .action(FileChooserAction::FilterChanged);
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(
+                        Stack::new()
+                            .orientation("horizontal")
+                            .attach(Grid::row(3))
+                            .h_align("end")
+                            .spacing(8.0)
+                            .child(
+                                Button::new()
+                                    .text("Cancel")
+                                    .min_width(80.0)
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get_mut::<FileChooserState>(id)
+                                            .action(FileChooserAction::Cancel);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Button::new()
+                                    .style("button_primary")
+                                    .text(accept_text)
+                                    .min_width(80.0)
+                                    .on_click(move |states, _| {
+                                        states
+                                            .get_mut::<FileChooserState>(id)
+                                            .action(FileChooserAction::Accept);
+                                        true
+                                    })
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_folder(name: &str) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!("orbtk_file_chooser_{}", name));
+        let _ = fs::remove_dir_all(&folder);
+        fs::create_dir_all(folder.join("Pictures")).unwrap();
+        fs::create_dir_all(folder.join("documents")).unwrap();
+        fs::create_dir_all(folder.join(".cache")).unwrap();
+        for file in &["b.png", "A.PNG", "notes.txt", ".hidden.png"] {
+            fs::write(folder.join(file), "").unwrap();
+        }
+        folder
+    }
+
+    fn names(entries: Vec<FileEntry>) -> Vec<String> {
+        entries.iter().map(FileEntry::display).collect()
+    }
+
+    #[test]
+    fn test_read_entries() {
+        let folder = test_folder("read_entries");
+        let images = FileFilter::new("Images").pattern("*.png");
+
+        assert_eq!(
+            names(read_entries(&folder, FileDialogKind::Open, None).unwrap()),
+            vec!["documents/", "Pictures/", "A.PNG", "b.png", "notes.txt"]
+        );
+        assert_eq!(
+            names(read_entries(&folder, FileDialogKind::Open, Some(&images)).unwrap()),
+            vec!["documents/", "Pictures/", "A.PNG", "b.png"]
+        );
+        assert_eq!(
+            names(read_entries(&folder, FileDialogKind::Folder, Some(&images)).unwrap()),
+            vec!["documents/", "Pictures/"]
+        );
+        assert!(read_entries(&folder.join("missing"), FileDialogKind::Open, None).is_err());
+
+        let _ = fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn test_accepted_paths() {
+        let folder = test_folder("accepted_paths");
+        let file = |name: &str| FileEntry {
+            name: name.to_string(),
+            is_dir: false,
+        };
+
+        assert_eq!(
+            accepted_paths(FileDialogKind::Open, &folder, "b.png", &[]),
+            vec![folder.join("b.png")]
+        );
+        assert!(accepted_paths(FileDialogKind::Open, &folder, "missing.png", &[]).is_empty());
+        assert!(accepted_paths(FileDialogKind::Open, &folder, "", &[]).is_empty());
+
+        assert_eq!(
+            accepted_paths(
+                FileDialogKind::OpenMultiple,
+                &folder,
+                "",
+                &[&file("A.PNG"), &file("b.png")]
+            ),
+            vec![folder.join("A.PNG"), folder.join("b.png")]
+        );
+
+        assert_eq!(
+            accepted_paths(FileDialogKind::Save, &folder, "new.txt", &[]),
+            vec![folder.join("new.txt")]
+        );
+        assert!(accepted_paths(FileDialogKind::Save, &folder, "documents", &[]).is_empty());
+
+        assert_eq!(
+            accepted_paths(FileDialogKind::Folder, &folder, "", &[]),
+            vec![folder.clone()]
+        );
+        assert_eq!(
+            accepted_paths(FileDialogKind::Folder, &folder, "documents", &[]),
+            vec![folder.join("documents")]
+        );
+
+        let _ = fs::remove_dir_all(&folder);
+    }
+}
@@ -1,27 +1,171 @@
+use std::time::Duration;
+
 use crate::{api::prelude::*, proc_macros::*, render::prelude::*};
 
+type Frames = Result<Vec<(Image, Duration)>, String>;
+
+/// The `ImageWidgetState` loads the `source` of an `ImageWidget` and plays its frames if the
+/// image is animated.
+#[derive(Default, AsAny)]
+pub struct ImageWidgetState {
+    source: String,
+    // incremented for each load, outdated loads and animations are ignored
+    generation: usize,
+    frames: Vec<(Image, Duration)>,
+    frame: usize,
+}
+
+impl ImageWidgetState {
+    // Decodes the image on a background thread, the placeholder is shown while it is loading.
+    fn load(&mut self, ctx: &mut Context) {
+        self.source = ctx.widget().clone::<String>("source");
+        self.generation += 1;
+        self.frames.clear();
+        self.frame = 0;
+        ctx.widget().set("error", String::new());
+
+        if self.source.is_empty() {
+            return;
+        }
+
+        let placeholder = ctx.widget().clone_or_default::<Image>("placeholder");
+        ctx.widget().set("image", placeholder);
+
+        let generation = self.generation;
+        let path = self.source.clone();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (sender, mut receiver) = async_channel();
+
+            std::thread::spawn(move || {
+                let _ = sender.send(Image::frames_from_path(path));
+            });
+
+            ctx.spawn_local_then(
+                async move { receiver.recv().await },
+                move |state: &mut ImageWidgetState, frames, ctx| {
+                    if let Some(frames) = frames {
+                        state.loaded(generation, frames, ctx);
+                    }
+                },
+            );
+        }
+
+        // the browser decodes the image by itself
+        #[cfg(target_arch = "wasm32")]
+        self.loaded(generation, Image::frames_from_path(path), ctx);
+    }
+
+    fn loaded(&mut self, generation: usize, frames: Frames, ctx: &mut Context) {
+        if generation != self.generation {
+            return;
+        }
+
+        match frames {
+            Ok(frames) if !frames.is_empty() => {
+                self.frames = frames;
+                self.show_frame(0, ctx);
+            }
+            Ok(_) => {}
+            // the placeholder stays visible
+            Err(error) => ctx.widget().set("error", error),
+        }
+    }
+
+    fn show_frame(&mut self, frame: usize, ctx: &mut Context) {
+        self.frame = frame;
+        let (image, frame_delay) = self.frames[frame].clone();
+        ctx.widget().set("image", image);
+
+        if self.frames.len() < 2 {
+            return;
+        }
+
+        let generation = self.generation;
+
+        ctx.spawn_local_then(
+            async move { delay(frame_delay).await },
+            move |state: &mut ImageWidgetState, _, ctx| {
+                if generation == state.generation {
+                    state.show_frame((state.frame + 1) % state.frames.len(), ctx);
+                }
+            },
+        );
+    }
+}
+
+impl State for ImageWidgetState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.load(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if *ctx.widget().get::<String>("source") != self.source {
+            self.load(ctx);
+        }
+    }
+}
+
 widget!(
     /// The `ImageWidget` widget is used to draw an image. It is not interactive.
     ///
+    /// The image is drawn with its natural size, or resized to the size of the widget as
+    /// described by `stretch`. Images with a `nine_slice` keep their borders and stretch their
+    /// center, e.g. for skinnable backgrounds.
+    ///
+    /// Images set by `source` are decoded on a background thread, the `placeholder` is drawn
+    /// while the image is loading. Animated GIF and PNG images are played. If the image could
+    /// not be loaded the placeholder stays and the reason is set to `error`.
+    ///
     /// **style:** `image-widget`
-    ImageWidget {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ImageWidget::new()
+    ///     .source("res/orbtk_space.png")
+    ///     .stretch("uniform")
+    ///     .build(ctx)
+    /// ```
+    ImageWidget<ImageWidgetState> {
         /// Sets or shares the image property.
         ///
         /// Set image property:
         /// * &str: `Image::new().image("path/to/image.png").build(xt)`
         /// * String: `Image::new().image(String::from()).build(xt)`
         /// * (width: u32, height: u32, data: Vec<u32>): `Image::new().image((width, height, vec![0; width * height]));`
-        image: Image
+        image: Image,
+
+        /// Sets or shares the path of an image that is loaded in the background.
+        source: String,
+
+        /// Sets or shares the image that is drawn while the `source` is loading.
+        placeholder: Image,
+
+        /// Sets or shares the error of the last load of the `source`, empty if there is no error.
+        error: String,
+
+        /// Sets or shares the stretch property, that describes how the image is resized to the
+        /// size of the widget.
+        stretch: Stretch,
+
+        /// Sets or shares the nine-slice property. The insets in image pixels split the image
+        /// in nine parts, the corners keep their size while the edges and the center are stretched.
+        nine_slice: Thickness
     }
 );
 
 impl Template for ImageWidget {
     fn template(self, _: Entity, _: &mut BuildContext) -> Self {
-        self.name("ImageWidget").style("image-widget").image("")
+        self.name("ImageWidget")
+            .style("image-widget")
+            .image(Image::default())
+            .error("")
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
-        ImageRenderObject.into()
+        ImageRenderObject::new().into()
     }
 
     fn layout(&self) -> Box<dyn Layout> {
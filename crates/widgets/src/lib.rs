@@ -12,16 +12,22 @@ pub(crate) use orbtk_theme as theme;
 
 pub use self::button::*;
 pub use self::canvas::*;
+pub use self::canvas_2d::*;
 pub use self::check_box::*;
 pub use self::combo_box::*;
 pub use self::container::*;
 pub use self::cursor::*;
+pub use self::data_grid::*;
+pub use self::dialog::*;
+pub use self::file_chooser::*;
 pub use self::font_icon_block::*;
 pub use self::grid::*;
 pub use self::image_widget::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
 pub use self::master_detail::*;
+pub use self::menu::*;
+pub use self::message_box::*;
 pub use self::numeric_box::*;
 pub use self::pager::*;
 pub use self::password_box::*;
@@ -32,26 +38,35 @@ pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
 pub use self::slider::*;
 pub use self::stack::*;
+pub use self::svg_icon::*;
 pub use self::switch::*;
 pub use self::tab_widget::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
 pub use self::toggle_button::*;
+pub use self::tooltip::*;
+pub use self::tree_view::*;
 pub use self::window::*;
 
 pub mod behaviors;
 mod button;
 mod canvas;
+mod canvas_2d;
 mod check_box;
 mod combo_box;
 mod container;
 mod cursor;
+mod data_grid;
+mod dialog;
+mod file_chooser;
 mod font_icon_block;
 mod grid;
 mod image_widget;
 mod items_widget;
 mod list_view;
 mod master_detail;
+mod menu;
+mod message_box;
 mod numeric_box;
 mod pager;
 mod password_box;
@@ -62,9 +77,12 @@ mod scroll_indicator;
 mod scroll_viewer;
 mod slider;
 mod stack;
+mod svg_icon;
 mod switch;
 mod tab_widget;
 mod text_block;
 mod text_box;
 mod toggle_button;
+mod tooltip;
+mod tree_view;
 mod window;
@@ -1,23 +1,101 @@
 use std::{
     cell::Cell,
     collections::{HashMap, HashSet},
+    rc::Rc,
 };
 
 use super::behaviors::MouseBehavior;
-use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::MouseButton, theme::prelude::*,
+};
 
 static ITEMS_PANEL: &str = "items_panel";
 
+// Builds the content of the item on the given index, shared by the list and its items.
+type ItemsBuilder = Rc<dyn Fn(&mut BuildContext, usize) -> Entity + 'static>;
+
+/// The payload of an item that is dragged inside of a reorderable `ListView`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ListViewDrag {
+    /// The list view of the item.
+    pub list: Entity,
+
+    /// The index of the dragged item.
+    pub index: usize,
+}
+
 /// The `ListViewState` generates the list box items and handles the selected indices.
 #[derive(Default, AsAny)]
 pub struct ListViewState {
-    builder: WidgetBuildContext,
+    builder: Option<ItemsBuilder>,
     count: usize,
     selected_entities: RefCell<HashSet<Entity>>,
     items_panel: Entity,
+    items: Vec<Entity>,
+    dropped: Option<(usize, Point)>,
 }
 
 impl ListViewState {
+    fn drop_item(&mut self, index: usize, position: Point) {
+        self.dropped = Some((index, position));
+    }
+
+    // Moves the dropped item to the index of the drop position.
+    fn move_dropped_item(&mut self, ctx: &mut Context) {
+        let (from, position) = match self.dropped.take() {
+            Some(dropped) => dropped,
+            None => return,
+        };
+
+        let vertical = *ctx.widget().get::<Orientation>("orientation") == Orientation::Vertical;
+        let item = match self.items.get(from) {
+            Some(item) => *item,
+            None => return,
+        };
+
+        // the new index is the count of the other items in front of the drop position
+        let to = self
+            .items
+            .iter()
+            .filter(|i| **i != item)
+            .filter(|i| {
+                let widget = ctx.get_widget(**i);
+                let item_position = widget.get::<Point>("position");
+                let bounds = widget.get::<Rectangle>("bounds");
+
+                if vertical {
+                    item_position.y() + bounds.height() / 2.0 < position.y()
+                } else {
+                    item_position.x() + bounds.width() / 2.0 < position.x()
+                }
+            })
+            .count();
+
+        if to == from {
+            return;
+        }
+
+        self.items.remove(from);
+        self.items.insert(to, item);
+        ctx.move_child(item, to);
+
+        // the selected entities keep selected on their new index
+        let selected_entities = ctx.widget().clone::<SelectedEntities>("selected_entities");
+        let selected_indices: HashSet<usize> = selected_entities
+            .0
+            .iter()
+            .filter_map(|entity| ctx.index_as_child(*entity))
+            .collect();
+        ctx.widget()
+            .set("selected_indices", SelectedIndices(selected_indices));
+
+        ctx.push_event_strategy_by_entity(
+            ItemMovedEvent(ctx.entity, from, to),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
     fn generate_items(&mut self, ctx: &mut Context) {
         let count = ctx.widget().clone_or_default::<usize>("count");
         let entity = ctx.entity;
@@ -26,12 +104,15 @@ impl ListViewState {
             ctx.widget().set("request_update", false);
             if let Some(builder) = &self.builder {
                 ctx.clear_children_of(self.items_panel);
+                self.items.clear();
 
                 for i in 0..count {
                     let item = {
                         let build_context = &mut ctx.build_context();
                         let child = builder(build_context, i);
-                        let item = ListViewItem::new().parent(entity.0).build(build_context);
+                        let mut item = ListViewItem::new().parent(entity.0);
+                        item.state_mut().builder = Some(builder.clone());
+                        let item = item.build(build_context);
 
                         let mouse_behavior =
                             MouseBehavior::new().target(item.0).build(build_context);
@@ -58,6 +139,7 @@ impl ListViewState {
                         item
                     };
                     ctx.get_widget(item).update_widget(entity, false, false);
+                    self.items.push(item);
                 }
             }
 
@@ -77,6 +159,7 @@ impl State for ListViewState {
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.generate_items(ctx);
+        self.move_dropped_item(ctx);
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
@@ -116,17 +199,92 @@ impl State for ListViewState {
 /// The `ListViewItemState` handles the interaction and selection of a `ListViewItem`.
 #[derive(Default, AsAny)]
 pub struct ListViewItemState {
+    builder: Option<ItemsBuilder>,
     request_selection_toggle: Cell<bool>,
+    drag_gesture: Cell<DragGesture>,
+    request_drag: bool,
 }
 
 impl ListViewItemState {
     fn toggle_selection(&self) {
         self.request_selection_toggle.set(true);
     }
+
+    fn press(&self, mouse: Mouse) {
+        if mouse.button == MouseButton::Left {
+            let mut drag_gesture = self.drag_gesture.get();
+            drag_gesture.press(mouse.position);
+            self.drag_gesture.set(drag_gesture);
+        }
+    }
+
+    fn release(&self) {
+        self.drag_gesture.set(DragGesture::default());
+    }
+
+    // Returns `true` if the item should be dragged.
+    fn mouse_moved(&self, position: Point) -> bool {
+        let mut drag_gesture = self.drag_gesture.get();
+        let start = drag_gesture.moved(position);
+        self.drag_gesture.set(drag_gesture);
+        start
+    }
+
+    fn start_drag(&mut self, ctx: &mut Context) {
+        self.request_drag = false;
+
+        let parent_entity: Entity = (*ctx.widget().get::<u32>("parent")).into();
+
+        if !*ctx.get_widget(parent_entity).get::<bool>("reorderable") {
+            return;
+        }
+
+        let entity = ctx.entity;
+        let index = match ctx.index_as_child(entity) {
+            Some(index) => index,
+            None => return,
+        };
+
+        // the visual shows the content of the item built again
+        let bounds = ctx.widget().clone::<Rectangle>("bounds");
+        let visual = {
+            let build_context = &mut ctx.build_context();
+            let mut visual = Container::new()
+                .style("drag_visual")
+                .width(bounds.width())
+                .height(bounds.height());
+
+            if let Some(builder) = &self.builder {
+                let child = builder(build_context, index);
+                build_context.register_shared_property::<Brush>("foreground", child, entity);
+                build_context.register_shared_property::<f64>("font_size", child, entity);
+                build_context.register_shared_property::<String>("font", child, entity);
+                visual = visual.child(child);
+            }
+
+            visual.build(build_context)
+        };
+
+        if ctx.start_drag(
+            DragPayload::new(ListViewDrag {
+                list: parent_entity,
+                index,
+            }),
+            Some(visual),
+        ) {
+            // prevents the click after the drop
+            ctx.widget().set("pressed", false);
+            toggle_flag("pressed", &mut ctx.widget());
+        }
+    }
 }
 
 impl State for ListViewItemState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.request_drag {
+            self.start_drag(ctx);
+        }
+
         if !ctx.widget().get::<bool>("enabled") || !self.request_selection_toggle.get() {
             return;
         }
@@ -208,7 +366,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -259,6 +417,24 @@ impl Template for ListViewItem {
                 states.get::<ListViewItemState>(id).toggle_selection();
                 false
             })
+            .on_mouse_down(move |states, m| {
+                states.get::<ListViewItemState>(id).press(m);
+
+                // the drag starts also if the mouse leaves the item
+                if m.button == MouseButton::Left {
+                    states.capture_mouse(id);
+                }
+                false
+            })
+            .on_mouse_move(move |states, p| {
+                if states.get::<ListViewItemState>(id).mouse_moved(p) {
+                    states.get_mut::<ListViewItemState>(id).request_drag = true;
+                }
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                states.get::<ListViewItemState>(id).release();
+            })
             .child(
                 MouseBehavior::new()
                     .pressed(id)
@@ -280,13 +456,30 @@ impl Template for ListViewItem {
 widget!(
     /// The `ListView` is an items drawer widget with selectable items.
     ///
+    /// If `reorderable` is set, the items could be moved by drag and drop. After an item is
+    /// moved an `ItemMovedEvent` is raised, the handler has to move the item of the data in the
+    /// same way.
+    ///
     /// **style:** `items-widget`
-    ListView<ListViewState> : SelectionChangedHandler {
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ListView::new()
+    ///     .count(3)
+    ///     .reorderable(true)
+    ///     .items_builder(|bc, index| TextBlock::new().text(format!("Item {}", index)).build(bc))
+    ///     .on_item_moved(|states, _, from, to| {
+    ///         // move the item of the data from `from` to `to`
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    ListView<ListViewState> : SelectionChangedHandler, DragDropHandler, ItemMovedHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -313,7 +506,10 @@ widget!(
         selected_entities: SelectedEntities,
 
         /// Use this flag to force the redrawing of the items.
-        request_update: bool
+        request_update: bool,
+
+        /// Sets or shares the flag if the items could be reordered by drag and drop.
+        reorderable: bool
     }
 );
 
@@ -323,7 +519,7 @@ impl ListView {
         mut self,
         builder: F,
     ) -> Self {
-        self.state_mut().builder = Some(Box::new(builder));
+        self.state_mut().builder = Some(Rc::new(builder));
         self
     }
 }
@@ -352,6 +548,20 @@ impl Template for ListView {
             .selected_indices(HashSet::new())
             .selected_entities(HashSet::new())
             .orientation("vertical")
+            .reorderable(false)
+            .on_drag_enter(move |_, payload, _| {
+                matches!(payload.get::<ListViewDrag>(), Some(drag) if drag.list == id)
+            })
+            .on_drag_over(move |_, payload, _| {
+                matches!(payload.get::<ListViewDrag>(), Some(drag) if drag.list == id)
+            })
+            .on_drag_drop(move |states, payload, position| match payload.get::<ListViewDrag>() {
+                Some(drag) if drag.list == id => {
+                    states.get_mut::<ListViewState>(id).drop_item(drag.index, position);
+                    true
+                }
+                _ => false,
+            })
             .child(
                 Container::new()
                     .background(id)
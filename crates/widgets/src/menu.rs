@@ -0,0 +1,1052 @@
+use super::behaviors::{CommandBehavior, MouseBehavior};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+pub static STYLE_MENU_BAR: &str = "menu_bar";
+pub static STYLE_MENU: &str = "menu";
+pub static STYLE_MENU_ITEM: &str = "menu_item";
+pub static STYLE_MENU_PANEL: &str = "menu_panel";
+pub static STYLE_MENU_SEPARATOR: &str = "menu_separator";
+static SUBMENU: &str = "submenu";
+// --- KEYS --
+
+// Returns the bounds of the widget in window coordinates.
+fn global_bounds(ctx: &mut Context, entity: Entity) -> Rectangle {
+    let widget = ctx.get_widget(entity);
+    Rectangle::new(
+        widget.clone::<Point>("position"),
+        widget.clone::<Rectangle>("bounds").size(),
+    )
+}
+
+// Returns the menu panel that is opened by the given menu, menu item or context menu.
+fn submenu_of(ctx: &mut Context, entity: Entity) -> Option<Entity> {
+    ctx.get_widget(entity)
+        .try_clone::<u32>(SUBMENU)
+        .map(Entity::from)
+}
+
+// Returns `true` if the widget is a menu item and not e.g. a separator.
+fn is_menu_item(ctx: &mut Context, entity: Entity) -> bool {
+    ctx.get_widget(entity).has::<Shortcut>("shortcut")
+}
+
+// Returns the items of the given menu panel.
+fn panel_items(ctx: &mut Context, panel: Entity) -> Vec<Entity> {
+    let items_panel = ctx
+        .get_widget(panel)
+        .children_mut()
+        .and_then(|children| children.first().copied());
+
+    match items_panel {
+        Some(items_panel) => ctx
+            .get_widget(items_panel)
+            .children_mut()
+            .cloned()
+            .unwrap_or_default(),
+        None => vec![],
+    }
+}
+
+// Returns the chain of currently open menu panels starting with the panel of the given root menu.
+fn open_panels(ctx: &mut Context, root: Entity) -> Vec<Entity> {
+    let mut panels = vec![];
+
+    if !*ctx.get_widget(root).get::<bool>("open") {
+        return panels;
+    }
+
+    let mut current = submenu_of(ctx, root);
+
+    while let Some(panel) = current {
+        panels.push(panel);
+        current = None;
+
+        for item in panel_items(ctx, panel) {
+            if is_menu_item(ctx, item) && *MenuItem::open_ref(&ctx.get_widget(item)) {
+                current = submenu_of(ctx, item);
+                break;
+            }
+        }
+    }
+
+    panels
+}
+
+// Walks from the given panel over the owners up to the root `Menu` or `ContextMenu`.
+fn root_of(ctx: &mut Context, panel: Entity) -> Entity {
+    let mut panel = panel;
+
+    loop {
+        let owner = Entity::from(*MenuPanel::owner_ref(&ctx.get_widget(panel)));
+
+        match ctx.get_widget(owner).try_clone::<u32>("menu_panel") {
+            Some(owner_panel) => panel = owner_panel.into(),
+            None => return owner,
+        }
+    }
+}
+
+// Collects all leaf items with a shortcut below the given menu panel.
+fn collect_shortcut_items(ctx: &mut Context, panel: Entity, items: &mut Vec<Entity>) {
+    for item in panel_items(ctx, panel) {
+        if !is_menu_item(ctx, item) {
+            continue;
+        }
+
+        if let Some(submenu) = submenu_of(ctx, item) {
+            collect_shortcut_items(ctx, submenu, items);
+        } else if !MenuItem::shortcut_ref(&ctx.get_widget(item)).is_empty() {
+            items.push(item);
+        }
+    }
+}
+
+// Toggles the checked state of a checkable item and sends the activate event to the item.
+fn activate_item(ctx: &mut Context, item: Entity) {
+    if !*ctx.get_widget(item).get::<bool>("enabled") {
+        return;
+    }
+
+    if *MenuItem::checkable_ref(&ctx.get_widget(item)) {
+        let checked = !*MenuItem::checked_ref(&ctx.get_widget(item));
+        MenuItem::checked_set(&mut ctx.get_widget(item), checked);
+    }
+
+    ctx.push_event_strategy_by_entity(ActivateEvent(item), item, EventStrategy::Direct);
+}
+
+// Activates the first item below the given root menu that matches the key event. Returns `true`
+// if an item was activated.
+fn handle_accelerator(ctx: &mut Context, root: Entity, key_event: &KeyEvent) -> bool {
+    let keyboard_state = ctx.window().get::<KeyboardState>("keyboard_state").clone();
+
+    let mut items = vec![];
+
+    if let Some(panel) = submenu_of(ctx, root) {
+        collect_shortcut_items(ctx, panel, &mut items);
+    }
+
+    for item in items {
+        if MenuItem::shortcut_ref(&ctx.get_widget(item)).matches(key_event.key, &keyboard_state) {
+            activate_item(ctx, item);
+            return true;
+        }
+    }
+
+    false
+}
+
+// Closes the root menu if the given position is outside of its open panels.
+fn close_on_mouse_up_outside(ctx: &mut Context, root: Entity, p: Point) {
+    if !*ctx.get_widget(root).get::<bool>("open") {
+        return;
+    }
+
+    for panel in open_panels(ctx, root) {
+        if global_bounds(ctx, panel).contains(p) {
+            return;
+        }
+    }
+
+    ctx.get_widget(root).set("open", false);
+}
+
+// Sets or clears the `selected` state of the style selector if it differs from the given value.
+fn set_selected_state(widget: &mut WidgetContainer, selected: bool) {
+    let changed = match widget.try_get_mut::<Selector>("selector") {
+        Some(selector) if selector.has_state("selected") != selected => {
+            if selected {
+                selector.set_state("selected");
+            } else {
+                selector.clear_state();
+            }
+            true
+        }
+        _ => false,
+    };
+
+    if changed {
+        widget.update(false);
+    }
+}
+
+// --- MenuPanel ---
+
+#[derive(Debug, Copy, Clone)]
+enum MenuPanelAction {
+    Hover(Point),
+}
+
+/// The `MenuPanelState` handles the opening and placement of a `MenuPanel` and opens sub menus
+/// on hover.
+#[derive(Default, AsAny)]
+pub struct MenuPanelState {
+    action: Option<MenuPanelAction>,
+    items: Vec<Entity>,
+}
+
+impl MenuPanelState {
+    fn hover(&mut self, ctx: &mut Context, p: Point) {
+        let mut hovered = None;
+
+        for item in &self.items {
+            if is_menu_item(ctx, *item) && global_bounds(ctx, *item).contains(p) {
+                hovered = Some(*item);
+                break;
+            }
+        }
+
+        let hovered = match hovered {
+            Some(hovered) => hovered,
+            None => return,
+        };
+
+        for item in &self.items {
+            if *item != hovered && is_menu_item(ctx, *item) {
+                MenuItem::open_set(&mut ctx.get_widget(*item), false);
+            }
+        }
+
+        if submenu_of(ctx, hovered).is_some() && *ctx.get_widget(hovered).get::<bool>("enabled") {
+            MenuItem::open_set(&mut ctx.get_widget(hovered), true);
+        }
+    }
+}
+
+impl State for MenuPanelState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        ctx.widget().set("visibility", Visibility::Hidden);
+        let entity = ctx.entity;
+        self.items = panel_items(ctx, entity);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let visibility = ctx.widget().clone::<Visibility>("visibility");
+        let open = *MenuPanel::open_ref(&ctx.widget());
+
+        if open && visibility != Visibility::Visible {
+            ctx.widget().set("visibility", Visibility::Visible);
+        } else if !open && visibility == Visibility::Visible {
+            ctx.widget().set("visibility", Visibility::Hidden);
+
+            // closes the sub menus
+            for item in &self.items {
+                if is_menu_item(ctx, *item) {
+                    MenuItem::open_set(&mut ctx.get_widget(*item), false);
+                }
+            }
+        }
+
+        if let Some(action) = self.action.take() {
+            match action {
+                MenuPanelAction::Hover(p) => {
+                    if open {
+                        self.hover(ctx, p);
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if *ctx.widget().get::<Visibility>("visibility") != Visibility::Visible {
+            return;
+        }
+
+        let owner = Entity::from(*MenuPanel::owner_ref(&ctx.widget()));
+        let owner_bounds = global_bounds(ctx, owner);
+        let bounds = ctx.widget().clone::<Rectangle>("bounds");
+
+        let (x, y) = if ctx.get_widget(owner).has::<u32>("menu_panel") {
+            // sub menu right of its item
+            (owner_bounds.x() + owner_bounds.width(), owner_bounds.y())
+        } else if ctx.get_widget(owner).has::<u32>("target") {
+            // context menus are placed by their owner
+            (bounds.x(), bounds.y())
+        } else {
+            (owner_bounds.x(), owner_bounds.y() + owner_bounds.height())
+        };
+
+        // keeps the panel inside of the window
+        let window_bounds = ctx.window().clone::<Rectangle>("bounds");
+        let x = x.min(window_bounds.width() - bounds.width()).max(0.0);
+        let y = y.min(window_bounds.height() - bounds.height()).max(0.0);
+
+        ctx.widget().get_mut::<Rectangle>("bounds").set_x(x);
+        ctx.widget().get_mut::<Rectangle>("bounds").set_y(y);
+    }
+}
+
+widget!(
+    /// The `MenuPanel` is the floating list of items of a `Menu`, a `ContextMenu` or a sub menu.
+    /// It's created by its owner and placed on the overlay.
+    ///
+    /// **style:** `menu_panel`
+    MenuPanel<MenuPanelState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the value if the panel is open and visible.
+        open: bool,
+
+        /// Sets or shares the id of the menu, menu item or context menu that owns the panel.
+        owner: u32
+    }
+);
+
+impl MenuPanel {
+    /// Adds an item to the panel.
+    pub fn item(mut self, item: Entity) -> Self {
+        self.state_mut().items.push(item);
+        self
+    }
+}
+
+impl Template for MenuPanel {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::take(&mut self.state_mut().items);
+        let mut items_panel = Stack::new().orientation("vertical");
+
+        for item in items {
+            if ctx.get_widget(item).has::<u32>("menu_panel") {
+                ctx.get_widget(item).set("menu_panel", id.0);
+            }
+            items_panel = items_panel.child(item);
+        }
+
+        self.name("MenuPanel")
+            .style(STYLE_MENU_PANEL)
+            .open(false)
+            .h_align("start")
+            .v_align("start")
+            .min_width(120.0)
+            .padding((0.0, 4.0, 0.0, 4.0))
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .on_mouse_down(|_, _| true)
+            .on_mouse_move(move |states, p| {
+                states.get_mut::<MenuPanelState>(id).action = Some(MenuPanelAction::Hover(p));
+                false
+            })
+            .child(items_panel.build(ctx))
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PopupLayout::new().into()
+    }
+}
+
+// --- MenuItem ---
+
+#[derive(Debug, Copy, Clone)]
+enum MenuItemAction {
+    Click,
+}
+
+/// The `MenuItemState` handles the click, check and sub menu behavior of a `MenuItem`.
+#[derive(Default, AsAny)]
+pub struct MenuItemState {
+    action: Option<MenuItemAction>,
+    arrow: Entity,
+    icon: Entity,
+    items: Vec<Entity>,
+    shortcut_text: Entity,
+    submenu: Option<Entity>,
+}
+
+impl State for MenuItemState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let icon = if *MenuItem::checkable_ref(&ctx.widget()) {
+            if *MenuItem::checked_ref(&ctx.widget()) {
+                String::from(material_icons_font::MD_CHECK)
+            } else {
+                String::default()
+            }
+        } else {
+            MenuItem::icon_clone(&ctx.widget())
+        };
+        ctx.get_widget(self.icon).set("icon", icon);
+
+        let shortcut = MenuItem::shortcut_ref(&ctx.widget()).to_string();
+        ctx.get_widget(self.shortcut_text).set("text", shortcut);
+
+        if self.submenu.is_some() {
+            ctx.get_widget(self.arrow)
+                .set("icon", String::from(material_icons_font::MD_CHEVRON_RIGHT));
+        }
+
+        let open = *MenuItem::open_ref(&ctx.widget());
+        set_selected_state(&mut ctx.widget(), open);
+
+        if let Some(action) = self.action.take() {
+            match action {
+                MenuItemAction::Click => {
+                    if self.submenu.is_some() {
+                        MenuItem::open_set(&mut ctx.widget(), true);
+                        return;
+                    }
+
+                    let entity = ctx.entity;
+                    activate_item(ctx, entity);
+
+                    let panel = Entity::from(*MenuItem::menu_panel_ref(&ctx.widget()));
+                    let root = root_of(ctx, panel);
+                    ctx.get_widget(root).set("open", false);
+                }
+            }
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(submenu) = self.submenu {
+            let _ = ctx.remove_child_from_overlay(submenu);
+        }
+    }
+}
+
+widget!(
+    /// The `MenuItem` is an entry of a menu. It could display an icon, a check mark and a keyboard
+    /// shortcut. An item with sub items opens a sub menu on hover. A click on an item without sub
+    /// items toggles the checked state of checkable items, raises the activate event, executes its
+    /// command and closes the menu.
+    ///
+    /// **style:** `menu_item`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// MenuItem::new()
+    ///     .text("Save")
+    ///     .shortcut("Ctrl+S")
+    ///     .on_activate(|states, _| { /* save */ })
+    ///     .build(ctx)
+    /// ```
+    MenuItem<MenuItemState>: ActivateHandler, MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon property. The icon is replaced by the check mark on checkable items.
+        icon: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String,
+
+        /// Sets or shares the keyboard shortcut that activates the item e.g. `Ctrl+S`.
+        shortcut: Shortcut,
+
+        /// Sets or shares the value if the item could be checked.
+        checkable: bool,
+
+        /// Sets or shares the checked state of a checkable item.
+        checked: bool,
+
+        /// Sets or shares the value if the sub menu of the item is open.
+        open: bool,
+
+        /// Sets or shares the pressed property.
+        pressed: bool,
+
+        /// Gets the id of the menu panel that contains the item.
+        menu_panel: u32,
+
+        /// Sets or shares the id of the command that is executed on click. The item displays the
+        /// label and the shortcut of the command.
+        command: String
+    }
+);
+
+impl MenuItem {
+    /// Adds a sub item. An item with sub items opens a sub menu.
+    pub fn item(mut self, item: Entity) -> Self {
+        self.state_mut().items.push(item);
+        self
+    }
+}
+
+impl Template for MenuItem {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::take(&mut self.state_mut().items);
+
+        if !items.is_empty() {
+            let mut submenu = MenuPanel::new().owner(id.0).open(id);
+
+            for item in items {
+                submenu = submenu.item(item);
+            }
+
+            let submenu = submenu.build(ctx);
+            ctx.register_property(SUBMENU, id, submenu.0);
+            let _ = ctx.append_child_to_overlay(submenu);
+            self.state_mut().submenu = Some(submenu);
+        }
+
+        let icon = FontIconBlock::new()
+            .v_align("center")
+            .icon_brush(id)
+            .icon_size(id)
+            .icon_font(id)
+            .opacity(id)
+            .build(ctx);
+
+        let shortcut_text = TextBlock::new()
+            .attach(Grid::column(2))
+            .v_align("center")
+            .margin((16.0, 0.0, 0.0, 0.0))
+            .foreground(id)
+            .font_size(id)
+            .font(id)
+            .opacity(id)
+            .build(ctx);
+
+        let arrow = FontIconBlock::new()
+            .attach(Grid::column(3))
+            .v_align("center")
+            .h_align("end")
+            .icon_brush(id)
+            .icon_size(id)
+            .icon_font(id)
+            .opacity(id)
+            .build(ctx);
+
+        self.state_mut().icon = icon;
+        self.state_mut().shortcut_text = shortcut_text;
+        self.state_mut().arrow = arrow;
+
+        self.name("MenuItem")
+            .style(STYLE_MENU_ITEM)
+            .height(28.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((8.0, 0.0, 8.0, 0.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon("")
+            .icon_font("MaterialIcons-Regular")
+            .icon_size(fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .checkable(false)
+            .checked(false)
+            .open(false)
+            .pressed(false)
+            .command("")
+            .on_click(move |states, _| {
+                states.get_mut::<MenuItemState>(id).action = Some(MenuItemAction::Click);
+                false
+            })
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        CommandBehavior::new()
+                            .command(id)
+                            .target(id.0)
+                            .child(
+                                Container::new()
+                                    .background(id)
+                                    .border_radius(id)
+                                    .border_width(id)
+                                    .border_brush(id)
+                                    .padding(id)
+                                    .opacity(id)
+                                    .child(
+                                        Grid::new()
+                                            .columns(
+                                                Columns::create()
+                                                    .push(24.0)
+                                                    .push("*")
+                                                    .push("auto")
+                                                    .push(16.0),
+                                            )
+                                            .child(icon)
+                                            .child(
+                                                TextBlock::new()
+                                                    .attach(Grid::column(1))
+                                                    .v_align("center")
+                                                    .foreground(id)
+                                                    .text(id)
+                                                    .font_size(id)
+                                                    .font(id)
+                                                    .opacity(id)
+                                                    .build(ctx),
+                                            )
+                                            .child(shortcut_text)
+                                            .child(arrow)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- MenuSeparator ---
+
+widget!(
+    /// The `MenuSeparator` draws a line between groups of menu items.
+    ///
+    /// **style:** `menu_separator`
+    MenuSeparator {
+        /// Sets or shares the background property.
+        background: Brush
+    }
+);
+
+impl Template for MenuSeparator {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("MenuSeparator")
+            .style(STYLE_MENU_SEPARATOR)
+            .height(1.0)
+            .margin((0.0, 4.0, 0.0, 4.0))
+            .background(colors::BOMBAY_COLOR)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+}
+
+// --- Menu ---
+
+#[derive(Debug, Clone)]
+enum MenuAction {
+    Click,
+    CheckMouseUpOutside(Point),
+    Key(KeyEvent),
+}
+
+/// The `MenuState` opens and closes the menu panel of a `Menu` and handles the keyboard shortcuts
+/// of its items.
+#[derive(Default, AsAny)]
+pub struct MenuState {
+    action: Option<MenuAction>,
+    items: Vec<Entity>,
+    panel: Entity,
+}
+
+impl State for MenuState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let open = *Menu::open_ref(&ctx.widget());
+        set_selected_state(&mut ctx.widget(), open);
+
+        if let Some(action) = self.action.take() {
+            let entity = ctx.entity;
+
+            match action {
+                MenuAction::Click => Menu::open_set(&mut ctx.widget(), !open),
+                MenuAction::CheckMouseUpOutside(p) => {
+                    // the header toggles the menu by itself
+                    if !global_bounds(ctx, entity).contains(p) {
+                        close_on_mouse_up_outside(ctx, entity, p);
+                    }
+                }
+                MenuAction::Key(key_event) => {
+                    if key_event.key == Key::Escape || handle_accelerator(ctx, entity, &key_event) {
+                        Menu::open_set(&mut ctx.widget(), false);
+                    }
+                }
+            }
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let _ = ctx.remove_child_from_overlay(self.panel);
+    }
+}
+
+widget!(
+    /// The `Menu` is a top level entry of a `MenuBar`. A click on its header opens the panel with
+    /// its items below the header.
+    ///
+    /// **style:** `menu`
+    Menu<MenuState>: KeyDownHandler, MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the value if the menu is open.
+        open: bool,
+
+        /// Sets or shares the pressed property.
+        pressed: bool
+    }
+);
+
+impl Menu {
+    /// Adds an item to the menu.
+    pub fn item(mut self, item: Entity) -> Self {
+        self.state_mut().items.push(item);
+        self
+    }
+}
+
+impl Template for Menu {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::take(&mut self.state_mut().items);
+        let mut panel = MenuPanel::new().owner(id.0).open(id);
+
+        for item in items {
+            panel = panel.item(item);
+        }
+
+        let panel = panel.build(ctx);
+        ctx.register_property(SUBMENU, id, panel.0);
+        let _ = ctx.append_child_to_overlay(panel);
+        self.state_mut().panel = panel;
+
+        self.name("Menu")
+            .style(STYLE_MENU)
+            .height(28.0)
+            .background("transparent")
+            .border_radius(2.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding((8.0, 0.0, 8.0, 0.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .open(false)
+            .pressed(false)
+            .on_click(move |states, _| {
+                states.get_mut::<MenuState>(id).action = Some(MenuAction::Click);
+                false
+            })
+            .on_global_mouse_up(move |states, e| {
+                states.get_mut::<MenuState>(id).action =
+                    Some(MenuAction::CheckMouseUpOutside(e.position));
+            })
+            .on_key_down(move |states, event| -> bool {
+                states.get_mut::<MenuState>(id).action = Some(MenuAction::Key(event));
+                false
+            })
+            .child(
+                MouseBehavior::new()
+                    .pressed(id)
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Container::new()
+                            .background(id)
+                            .border_radius(id)
+                            .border_width(id)
+                            .border_brush(id)
+                            .padding(id)
+                            .opacity(id)
+                            .child(
+                                TextBlock::new()
+                                    .v_align("center")
+                                    .foreground(id)
+                                    .text(id)
+                                    .font_size(id)
+                                    .font(id)
+                                    .opacity(id)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+// --- MenuBar ---
+
+/// The `MenuBarState` switches between the menus of the bar on hover while one of them is open.
+#[derive(Default, AsAny)]
+pub struct MenuBarState {
+    hover: Option<Point>,
+}
+
+impl State for MenuBarState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let p = match self.hover.take() {
+            Some(p) => p,
+            None => return,
+        };
+
+        let menus = ctx.widget().children_mut().cloned().unwrap_or_default();
+
+        let open_menu = menus
+            .iter()
+            .find(|menu| *ctx.get_widget(**menu).get::<bool>("open"))
+            .copied();
+
+        if let Some(open_menu) = open_menu {
+            for menu in menus {
+                if menu != open_menu && global_bounds(ctx, menu).contains(p) {
+                    Menu::open_set(&mut ctx.get_widget(open_menu), false);
+                    Menu::open_set(&mut ctx.get_widget(menu), true);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `MenuBar` arranges its `Menu` children in a row. Moving the mouse over another menu
+    /// while a menu is open switches to that menu.
+    ///
+    /// **style:** `menu_bar`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// MenuBar::new()
+    ///     .child(
+    ///         Menu::new()
+    ///             .text("File")
+    ///             .item(MenuItem::new().text("Open").shortcut("Ctrl+O").build(ctx))
+    ///             .item(MenuSeparator::new().build(ctx))
+    ///             .item(MenuItem::new().text("Quit").shortcut("Ctrl+Q").build(ctx))
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    MenuBar<MenuBarState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Margin between the menus.
+        spacing: f64
+    }
+);
+
+impl Template for MenuBar {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("MenuBar")
+            .style(STYLE_MENU_BAR)
+            .orientation("horizontal")
+            .spacing(0.0)
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .padding(2.0)
+            .on_mouse_move(move |states, p| {
+                states.get_mut::<MenuBarState>(id).hover = Some(p);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        StackLayout::new().into()
+    }
+}
+
+// --- ContextMenu ---
+
+#[derive(Debug, Clone)]
+enum ContextMenuAction {
+    MouseUp(Mouse),
+    Key(KeyEvent),
+}
+
+/// The `ContextMenuState` opens the menu panel on right click on the target.
+#[derive(Default, AsAny)]
+pub struct ContextMenuState {
+    action: Option<ContextMenuAction>,
+    items: Vec<Entity>,
+    panel: Entity,
+}
+
+impl State for ContextMenuState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let action = match self.action.take() {
+            Some(action) => action,
+            None => return,
+        };
+
+        let entity = ctx.entity;
+
+        match action {
+            ContextMenuAction::MouseUp(mouse) => {
+                close_on_mouse_up_outside(ctx, entity, mouse.position);
+
+                if mouse.button != MouseButton::Right {
+                    return;
+                }
+
+                let target = Entity::from(*ContextMenu::target_ref(&ctx.widget()));
+
+                if !global_bounds(ctx, target).contains(mouse.position) {
+                    return;
+                }
+
+                // opens the menu at the mouse position
+                ctx.get_widget(self.panel)
+                    .get_mut::<Rectangle>("bounds")
+                    .set_x(mouse.position.x());
+                ctx.get_widget(self.panel)
+                    .get_mut::<Rectangle>("bounds")
+                    .set_y(mouse.position.y());
+                ContextMenu::open_set(&mut ctx.widget(), true);
+            }
+            ContextMenuAction::Key(key_event) => {
+                if key_event.key == Key::Escape || handle_accelerator(ctx, entity, &key_event) {
+                    ContextMenu::open_set(&mut ctx.widget(), false);
+                }
+            }
+        }
+    }
+
+    fn cleanup(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let _ = ctx.remove_child_from_overlay(self.panel);
+    }
+}
+
+widget!(
+    /// The `ContextMenu` opens a menu at the mouse position if the user clicks with the right
+    /// mouse button on its target widget. The context menu itself is not visible, it could be
+    /// placed anywhere in the tree of the window.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// ContextMenu::new()
+    ///     .target(text_box.0)
+    ///     .item(MenuItem::new().text("Copy").shortcut("Ctrl+C").build(ctx))
+    ///     .item(MenuItem::new().text("Paste").shortcut("Ctrl+V").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    ContextMenu<ContextMenuState>: KeyDownHandler, MouseHandler {
+        /// Sets or shares the id of the widget that opens the context menu on right click.
+        target: u32,
+
+        /// Sets or shares the value if the context menu is open.
+        open: bool
+    }
+);
+
+impl ContextMenu {
+    /// Adds an item to the context menu.
+    pub fn item(mut self, item: Entity) -> Self {
+        self.state_mut().items.push(item);
+        self
+    }
+}
+
+impl Template for ContextMenu {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items = std::mem::take(&mut self.state_mut().items);
+        let mut panel = MenuPanel::new().owner(id.0).open(id);
+
+        for item in items {
+            panel = panel.item(item);
+        }
+
+        let panel = panel.build(ctx);
+        ctx.register_property(SUBMENU, id, panel.0);
+        let _ = ctx.append_child_to_overlay(panel);
+        self.state_mut().panel = panel;
+
+        self.name("ContextMenu")
+            .open(false)
+            .on_global_mouse_up(move |states, e| {
+                states.get_mut::<ContextMenuState>(id).action = Some(ContextMenuAction::MouseUp(e));
+            })
+            .on_key_down(move |states, event| -> bool {
+                states.get_mut::<ContextMenuState>(id).action = Some(ContextMenuAction::Key(event));
+                false
+            })
+    }
+}
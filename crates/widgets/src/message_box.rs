@@ -0,0 +1,299 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+pub static STYLE_MESSAGE_BOX: &str = "message_box";
+static ICON: &str = "icon";
+// --- KEYS --
+
+// Result of the Enter key.
+fn accept_result(buttons: MessageBoxButtons) -> DialogResult {
+    match buttons {
+        MessageBoxButtons::Ok | MessageBoxButtons::OkCancel => DialogResult::Ok,
+        MessageBoxButtons::YesNo | MessageBoxButtons::YesNoCancel => DialogResult::Yes,
+    }
+}
+
+// Result of the Escape key.
+fn cancel_result(buttons: MessageBoxButtons) -> DialogResult {
+    match buttons {
+        MessageBoxButtons::Ok => DialogResult::Ok,
+        MessageBoxButtons::YesNo => DialogResult::No,
+        MessageBoxButtons::OkCancel | MessageBoxButtons::YesNoCancel => DialogResult::Cancel,
+    }
+}
+
+fn icon_of(kind: MessageBoxKind) -> &'static str {
+    match kind {
+        MessageBoxKind::Info => material_icons_font::MD_INFO,
+        MessageBoxKind::Warning => material_icons_font::MD_WARNING,
+        MessageBoxKind::Error => material_icons_font::MD_ERROR,
+        MessageBoxKind::Confirm => material_icons_font::MD_HELP,
+    }
+}
+
+// Returns the results of the buttons displayed for the given buttons value.
+fn results_of(buttons: MessageBoxButtons) -> &'static [DialogResult] {
+    match buttons {
+        MessageBoxButtons::Ok => &[DialogResult::Ok],
+        MessageBoxButtons::OkCancel => &[DialogResult::Ok, DialogResult::Cancel],
+        MessageBoxButtons::YesNo => &[DialogResult::Yes, DialogResult::No],
+        MessageBoxButtons::YesNoCancel => {
+            &[DialogResult::Yes, DialogResult::No, DialogResult::Cancel]
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum MessageBoxAction {
+    Result(DialogResult),
+    Accept,
+    Cancel,
+}
+
+/// The `MessageBoxState` shows the buttons and icon of the message box and closes its dialog.
+#[derive(Default, AsAny)]
+pub struct MessageBoxState {
+    action: Option<MessageBoxAction>,
+    buttons: Vec<(DialogResult, Entity)>,
+    current: Option<(MessageBoxButtons, MessageBoxKind)>,
+}
+
+impl MessageBoxState {
+    fn update_buttons(&mut self, ctx: &mut Context) {
+        let buttons = *MessageBox::buttons_ref(&ctx.widget());
+        let kind = *MessageBox::kind_ref(&ctx.widget());
+
+        if self.current == Some((buttons, kind)) {
+            return;
+        }
+
+        self.current = Some((buttons, kind));
+        let results = results_of(buttons);
+
+        for (result, button) in &self.buttons {
+            let visibility = if results.contains(result) {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            };
+            ctx.get_widget(*button).set("visibility", visibility);
+        }
+
+        ctx.child(ICON).set("icon", String::from(icon_of(kind)));
+    }
+}
+
+impl State for MessageBoxState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_buttons(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.update_buttons(ctx);
+
+        if let Some(action) = self.action.take() {
+            let buttons = *MessageBox::buttons_ref(&ctx.widget());
+
+            let result = match action {
+                MessageBoxAction::Result(result) => result,
+                MessageBoxAction::Accept => accept_result(buttons),
+                MessageBoxAction::Cancel => cancel_result(buttons),
+            };
+
+            Dialog::close(ctx, result);
+        }
+    }
+}
+
+widget!(
+    /// The `MessageBox` is the content of a `Dialog` that displays a message with an icon and a
+    /// set of buttons. A click on a button closes the dialog with the result of the button,
+    /// `Enter` closes it with `Ok` or `Yes` and `Escape` with `Cancel`, `No` or `Ok` if it is the
+    /// only button.
+    ///
+    /// **style:** `message_box`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// MessageBox::show(
+    ///     ctx,
+    ///     "Save changes",
+    ///     MessageBox::new()
+    ///         .kind("confirm")
+    ///         .buttons("yes_no_cancel")
+    ///         .text("Do you want to save the changes?"),
+    ///     |states, result| println!("{:?}", result),
+    /// );
+    /// ```
+    MessageBox<MessageBoxState>: KeyDownHandler {
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or shares the kind of the message. It defines the icon.
+        kind: MessageBoxKind,
+
+        /// Sets or shares the displayed buttons.
+        buttons: MessageBoxButtons,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or shares the icon size property.
+        icon_size: f64,
+
+        /// Sets or shares the icon font property.
+        icon_font: String
+    }
+);
+
+impl MessageBox {
+    /// Shows the message box in a dialog on the overlay of the current window. `handler` is
+    /// called with the result after the dialog is closed.
+    pub fn show<H: Fn(&mut StatesContext, DialogResult) + 'static>(
+        ctx: &mut Context,
+        title: impl Into<String>,
+        message_box: MessageBox,
+        handler: H,
+    ) {
+        let bctx = &mut ctx.build_context();
+        let content = message_box.build(bctx);
+        let dialog = Dialog::new()
+            .title(title.into())
+            .content(content)
+            .on_dialog_result(handler)
+            .build(bctx);
+        let _ = bctx.append_child_to_overlay(dialog);
+    }
+}
+
+impl Template for MessageBox {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let mut button_bar = Stack::new()
+            .orientation("horizontal")
+            .h_align("end")
+            .spacing(8.0);
+
+        for (result, text) in &[
+            (DialogResult::Ok, "OK"),
+            (DialogResult::Yes, "Yes"),
+            (DialogResult::No, "No"),
+            (DialogResult::Cancel, "Cancel"),
+        ] {
+            let result = *result;
+            let button = Button::new()
+                .text(*text)
+                .min_width(80.0)
+                .on_click(move |states, _| {
+                    states.get_mut::<MessageBoxState>(id).action =
+                        Some(MessageBoxAction::Result(result));
+                    true
+                })
+                .build(ctx);
+
+            self.state_mut().buttons.push((result, button));
+            button_bar = button_bar.child(button);
+        }
+
+        self.name("MessageBox")
+            .style(STYLE_MESSAGE_BOX)
+            .text("")
+            .kind(MessageBoxKind::default())
+            .buttons(MessageBoxButtons::default())
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .icon_size(32.0)
+            .icon_font("MaterialIcons-Regular")
+            .on_key_down(move |states, event| -> bool {
+                let action = match event.key {
+                    Key::Enter | Key::NumpadEnter => MessageBoxAction::Accept,
+                    Key::Escape => MessageBoxAction::Cancel,
+                    _ => return false,
+                };
+
+                states.get_mut::<MessageBoxState>(id).action = Some(action);
+                true
+            })
+            .child(
+                Stack::new()
+                    .orientation("vertical")
+                    .spacing(16.0)
+                    .child(
+                        Stack::new()
+                            .orientation("horizontal")
+                            .spacing(12.0)
+                            .child(
+                                FontIconBlock::new()
+                                    .id(ICON)
+                                    .v_align("center")
+                                    .icon_brush(id)
+                                    .icon_size(id)
+                                    .icon_font(id)
+                                    .build(ctx),
+                            )
+                            .child(
+                                TextBlock::new()
+                                    .v_align("center")
+                                    .foreground(id)
+                                    .text(id)
+                                    .font_size(id)
+                                    .font(id)
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .child(button_bar.build(ctx))
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_result() {
+        assert_eq!(accept_result(MessageBoxButtons::Ok), DialogResult::Ok);
+        assert_eq!(accept_result(MessageBoxButtons::OkCancel), DialogResult::Ok);
+        assert_eq!(accept_result(MessageBoxButtons::YesNo), DialogResult::Yes);
+        assert_eq!(
+            accept_result(MessageBoxButtons::YesNoCancel),
+            DialogResult::Yes
+        );
+    }
+
+    #[test]
+    fn test_cancel_result() {
+        assert_eq!(cancel_result(MessageBoxButtons::Ok), DialogResult::Ok);
+        assert_eq!(
+            cancel_result(MessageBoxButtons::OkCancel),
+            DialogResult::Cancel
+        );
+        assert_eq!(cancel_result(MessageBoxButtons::YesNo), DialogResult::No);
+        assert_eq!(
+            cancel_result(MessageBoxButtons::YesNoCancel),
+            DialogResult::Cancel
+        );
+    }
+
+    #[test]
+    fn test_results_of() {
+        assert_eq!(
+            results_of(MessageBoxButtons::YesNoCancel),
+            &[DialogResult::Yes, DialogResult::No, DialogResult::Cancel]
+        );
+        assert_eq!(results_of(MessageBoxButtons::Ok), &[DialogResult::Ok]);
+    }
+}
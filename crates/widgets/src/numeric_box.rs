@@ -175,7 +175,7 @@ widget!(
         border_width: Thickness,
 
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the focused property
         focused: bool,
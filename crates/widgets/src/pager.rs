@@ -125,7 +125,10 @@ widget!(
     ///     })
     ///     .build(ctx);
     /// ```
-    Pager<PagerState> {
+    ///
+    /// On touch screens a swipe to the left navigates to the next and a swipe to the right to the
+    /// previous child.
+    Pager<PagerState>: GestureHandler {
         /// Defines the index of the current shown child.
         current_index: usize,
 
@@ -263,10 +266,19 @@ impl Pager {
 }
 
 impl Template for Pager {
-    fn template(self, _id: Entity, _context: &mut BuildContext) -> Self {
+    fn template(self, id: Entity, _context: &mut BuildContext) -> Self {
         self.name("Pager")
             .on_changed("current_index", |states, id| {
                 states.get_mut::<PagerState>(id).navigate_to_current_index();
             })
+            .on_swipe(move |states, swipe| {
+                match swipe.direction {
+                    SwipeDirection::Left => states.get_mut::<PagerState>(id).next(),
+                    SwipeDirection::Right => states.get_mut::<PagerState>(id).previous(),
+                    _ => return false,
+                }
+
+                true
+            })
     }
 }
@@ -96,7 +96,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -152,6 +152,7 @@ impl Template for PasswordBox {
 
         self.name("PasswordBox")
             .style(STYLE_TEXT_BOX)
+            .cursor_icon(CursorIcon::Text)
             .echo('*')
             .text("")
             .mask("")
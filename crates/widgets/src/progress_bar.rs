@@ -76,7 +76,7 @@ widget!(
         /// Sets or shares the border color property
         border_brush: Brush,
         /// Sets or shares the border radius property
-        border_radius: f64,
+        border_radius: CornerRadius,
         /// Sets or shares the border width property
         border_width: Thickness,
         /// Sets or shares the padding property
@@ -7,15 +7,16 @@ pub static STYLE_SCROLL_BAR: &str = "scroll_bar";
 // --- KEYS --
 
 widget!(
-    /// The `ScrollBar` widget represents a position inside of a scroll container.
+    /// The `ScrollBar` widget represents a position inside of a scroll container. It can be
+    /// dragged with the mouse to scroll the container.
     ///
     /// **style:** `scroll_bar`
-    ScrollBar {
+    ScrollBar: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64
+        border_radius: CornerRadius
     }
 );
 
@@ -1,4 +1,4 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::MouseButton};
 
 // --- KEYS --
 static ID_SCROLL_BAR_HORIZONTAL: &str = "scroll_bar_horizontal";
@@ -10,6 +10,36 @@ static ID_SCROLL_BAR_VERTICAL: &str = "scroll_bar_vertical";
 pub struct ScrollIndicatorState {
     horizontal_scroll_bar: Entity,
     vertical_scroll_bar: Entity,
+    // orientation of the dragged scroll bar and the last mouse position
+    drag: Option<(Orientation, Point)>,
+    drag_delta: Point,
+}
+
+impl ScrollIndicatorState {
+    fn press(&mut self, orientation: Orientation, position: Point) {
+        self.drag = Some((orientation, position));
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    fn drag(&mut self, position: Point) {
+        if let Some((orientation, last_position)) = self.drag {
+            let delta = position - last_position;
+
+            match orientation {
+                Orientation::Horizontal => self.drag_delta.set_x(self.drag_delta.x() + delta.x()),
+                Orientation::Vertical => self.drag_delta.set_y(self.drag_delta.y() + delta.y()),
+            }
+
+            self.drag = Some((orientation, position));
+        }
+    }
+
+    fn release(&mut self) {
+        self.drag = None;
+    }
 }
 
 impl State for ScrollIndicatorState {
@@ -22,6 +52,41 @@ impl State for ScrollIndicatorState {
             .expect("ScrollIndicatorState.init: scroll_bar_vertical child could not be found.");
     }
 
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.drag_delta == Point::default() {
+            return;
+        }
+
+        let delta = std::mem::take(&mut self.drag_delta);
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let content_size = ctx.widget().get::<Rectangle>("content_bounds").size();
+        let view_port_size = ctx.widget().get::<Rectangle>("view_port_bounds").size();
+        let mut scroll_padding = *ctx.widget().get::<Thickness>("scroll_padding");
+
+        // the scroll padding is shared with the scroll viewer, that moves its content
+        if content_size.height() > view_port_size.height() {
+            scroll_padding.set_top(scroll_offset(
+                size.height(),
+                content_size.height(),
+                view_port_size.height(),
+                scroll_padding.top(),
+                delta.y(),
+            ));
+        }
+
+        if content_size.width() > view_port_size.width() {
+            scroll_padding.set_left(scroll_offset(
+                size.width(),
+                content_size.width(),
+                view_port_size.width(),
+                scroll_padding.left(),
+                delta.x(),
+            ));
+        }
+
+        ctx.widget().set("scroll_padding", scroll_padding);
+    }
+
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
         let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
 
@@ -88,8 +153,9 @@ impl State for ScrollIndicatorState {
 }
 
 widget!(
-    /// The `ScrollIndicator` widget contains two scroll bars.
-    ScrollIndicator<ScrollIndicatorState> {
+    /// The `ScrollIndicator` widget contains two scroll bars. The scroll bars capture the mouse
+    /// while they are dragged and scroll the content of the `ScrollViewer`.
+    ScrollIndicator<ScrollIndicatorState>: MouseHandler {
         /// Shares the mode of the `ScrollViewer`.
         mode: ScrollViewerMode,
 
@@ -113,6 +179,17 @@ impl Template for ScrollIndicator {
             .v_align("stretch")
             .h_align("stretch")
             .padding(0.0)
+            .on_mouse_move(move |states, p| {
+                if states.get::<ScrollIndicatorState>(id).is_dragging() {
+                    states.get_mut::<ScrollIndicatorState>(id).drag(p);
+                }
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                if states.get::<ScrollIndicatorState>(id).is_dragging() {
+                    states.get_mut::<ScrollIndicatorState>(id).release();
+                }
+            })
             .child(
                 Grid::new()
                     .child(
@@ -123,6 +200,9 @@ impl Template for ScrollIndicator {
                             .margin((0.0, 0.0, 0.0, 6.0))
                             .h_align("end")
                             .opacity(id)
+                            .on_mouse_down(move |states, m| {
+                                press_scroll_bar(states, id, Orientation::Vertical, m)
+                            })
                             .build(ctx),
                     )
                     .child(
@@ -134,6 +214,9 @@ impl Template for ScrollIndicator {
                             .height(4.0)
                             .v_align("end")
                             .opacity(id)
+                            .on_mouse_down(move |states, m| {
+                                press_scroll_bar(states, id, Orientation::Horizontal, m)
+                            })
                             .build(ctx),
                     )
                     .build(ctx),
@@ -147,6 +230,24 @@ impl Template for ScrollIndicator {
 
 // --- Helpers --
 
+// Starts to drag a scroll bar, the mouse is captured to follow it also outside of the indicator.
+fn press_scroll_bar(
+    states: &mut StatesContext,
+    indicator: Entity,
+    orientation: Orientation,
+    mouse: Mouse,
+) -> bool {
+    if mouse.button != MouseButton::Left {
+        return false;
+    }
+
+    states
+        .get_mut::<ScrollIndicatorState>(indicator)
+        .press(orientation, mouse.position);
+    states.capture_mouse(indicator);
+    true
+}
+
 fn scroll_bar_size(size: f64, content_size: f64, view_port_size: f64, padding: f64) -> f64 {
     (size * view_port_size / content_size) - padding
 }
@@ -155,6 +256,19 @@ fn offset(size: f64, content_size: f64, offset: f64) -> f64 {
     size * offset / content_size
 }
 
+// Returns the offset of the content after its scroll bar is moved by the given delta.
+fn scroll_offset(
+    size: f64,
+    content_size: f64,
+    view_port_size: f64,
+    offset: f64,
+    delta: f64,
+) -> f64 {
+    (offset - delta * content_size / size)
+        .min(0.0)
+        .max(view_port_size - content_size)
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -182,4 +296,41 @@ mod tests {
 
         assert!((offset(size, content_size, offset_in) - 2.).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_scroll_offset() {
+        let size = 50.;
+        let content_size = 200.;
+        let view_port_size = 50.;
+
+        assert!(
+            (scroll_offset(size, content_size, view_port_size, 0., 5.) + 20.).abs() < f64::EPSILON
+        );
+        assert!(
+            (scroll_offset(size, content_size, view_port_size, -20., -10.) - 0.).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (scroll_offset(size, content_size, view_port_size, 0., 100.) + 150.).abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_release_ends_drag() {
+        let mut state = ScrollIndicatorState::default();
+
+        state.press(Orientation::Vertical, Point::new(0., 10.));
+        state.drag(Point::new(50., 30.));
+        assert!(state.is_dragging());
+        assert_eq!(state.drag_delta, Point::new(0., 20.));
+
+        // the captured release arrives also if the mouse is outside of the indicator
+        state.release();
+        assert!(!state.is_dragging());
+
+        // hovering afterwards does not scroll
+        state.drag(Point::new(50., 80.));
+        assert_eq!(state.drag_delta, Point::new(0., 20.));
+    }
 }
@@ -1,64 +1,148 @@
-use crate::{api::prelude::*, proc_macros::*};
+use std::time::Duration;
+
+use crate::{api::prelude::*, proc_macros::*, shell::Instant};
+
+// Part of the velocity of the kinetic scrolling that remains after one second.
+const KINETIC_FRICTION: f64 = 0.05;
+
+// The kinetic scrolling stops below this velocity in pixels per second.
+const KINETIC_MIN_VELOCITY: f64 = 20.0;
+
+// Interval of the updates while kinetic scrolling.
+const KINETIC_FRAME: Duration = Duration::from_millis(16);
 
 /// The `ScrollViewerState` handles the `ScrollViewer` widget.
 #[derive(Default, AsAny)]
 pub struct ScrollViewerState {
     delta: Option<Point>,
+    pan_delta: Point,
+    velocity: Point,
+    last_tick: Option<Instant>,
+    // a wake up for the next frame of the kinetic scrolling is pending
+    waiting: bool,
 }
 
 impl ScrollViewerState {
     fn scroll(&mut self, delta: Point) {
         self.delta = Some(delta);
     }
+
+    fn pan(&mut self, pan: PanEvent) {
+        match pan.phase {
+            GesturePhase::Started | GesturePhase::Changed => {
+                self.stop();
+                self.pan_delta = self.pan_delta + pan.delta;
+            }
+            // continues to scroll with the velocity of the finger
+            GesturePhase::Ended => {
+                self.velocity = pan.velocity;
+                self.last_tick = None;
+            }
+        }
+    }
+
+    fn stop(&mut self) {
+        self.velocity = Point::default();
+        self.last_tick = None;
+    }
+
+    // returns the distance of the kinetic scrolling since the last update.
+    fn kinetic_delta(&mut self, ctx: &mut Context) -> Point {
+        if self.velocity == Point::default() {
+            return Point::default();
+        }
+
+        let now = Instant::now();
+        let mut delta = Point::default();
+
+        if let Some(last_tick) = self.last_tick {
+            let elapsed = now.duration_since(last_tick).as_secs_f64();
+            delta = self.velocity * elapsed;
+            self.velocity = self.velocity * KINETIC_FRICTION.powf(elapsed);
+        }
+
+        if self.velocity.x().abs().max(self.velocity.y().abs()) < KINETIC_MIN_VELOCITY {
+            self.stop();
+        } else {
+            self.last_tick = Some(now);
+
+            if !self.waiting {
+                self.waiting = true;
+                ctx.spawn_local_then(
+                    async move { delay(KINETIC_FRAME).await },
+                    |state: &mut ScrollViewerState, _, _| state.waiting = false,
+                );
+            }
+        }
+
+        delta
+    }
 }
 
 impl State for ScrollViewerState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
-            self.delta = None;
-            let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+        let speed = *ctx.widget().get::<f64>("speed");
+        let delta = self
+            .delta
+            .take()
+            .map(|delta| delta * speed)
+            .unwrap_or_default()
+            + self.pan_delta
+            + self.kinetic_delta(ctx);
+        self.pan_delta = Point::default();
+
+        if delta == Point::default() {
+            return;
+        }
+
+        let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
+
+        if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
+            return;
+        }
+
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let mut padding = *ctx.widget().get::<Thickness>("padding");
 
-            if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
-                return;
+        if let Some(child) = &mut ctx.try_child_from_index(0) {
+            let child_size = child.get::<Rectangle>("bounds").size();
+
+            if mode.vertical == ScrollMode::Auto && child_size.height() > size.height() {
+                padding.set_top(offset(
+                    size.height(),
+                    child_size.height(),
+                    padding.top(),
+                    delta.y(),
+                ));
             }
 
-            let size = ctx.widget().get::<Rectangle>("bounds").size();
-            let speed = *ctx.widget().get::<f64>("speed");
-            let mut padding = *ctx.widget().get::<Thickness>("padding");
-
-            if let Some(child) = &mut ctx.try_child_from_index(0) {
-                let child_size = child.get::<Rectangle>("bounds").size();
-
-                if mode.vertical == ScrollMode::Auto && child_size.height() > size.height() {
-                    padding.set_top(offset(
-                        size.height(),
-                        child_size.height(),
-                        padding.top(),
-                        delta.y() * speed,
-                    ));
-                }
-
-                if mode.horizontal == ScrollMode::Auto && child_size.width() > size.width() {
-                    padding.set_left(offset(
-                        size.width(),
-                        child_size.width(),
-                        padding.left(),
-                        delta.x() * speed,
-                    ));
-                }
-            } else {
-                return;
+            if mode.horizontal == ScrollMode::Auto && child_size.width() > size.width() {
+                padding.set_left(offset(
+                    size.width(),
+                    child_size.width(),
+                    padding.left(),
+                    delta.x(),
+                ));
             }
+        } else {
+            return;
+        }
 
-            ctx.widget().set("padding", padding);
+        // the kinetic scrolling stops at the edges
+        if padding == *ctx.widget().get::<Thickness>("padding") {
+            self.stop();
+            return;
         }
+
+        ctx.widget().set("padding", padding);
     }
 }
 
 widget!(
     /// The `ScrollViewer` is used to scroll its child vertical and or horizontal.
-    /// Only the first child of the scroll viewer can be scrolled.
-    ScrollViewer<ScrollViewerState>: MouseHandler {
+    /// Only the first child of the scroll viewer can be scrolled. On touch screens the child
+    /// follows the finger and continues to scroll kinetic after the finger is lifted.
+    ScrollViewer<ScrollViewerState>: MouseHandler, TouchHandler, GestureHandler {
         /// Sets or shares the scroll mode property.
         mode: ScrollViewerMode,
 
@@ -81,6 +165,14 @@ impl Template for ScrollViewer {
                 states.get_mut::<ScrollViewerState>(id).scroll(p);
                 false
             })
+            .on_touch_down(move |states, _| {
+                states.get_mut::<ScrollViewerState>(id).stop();
+                false
+            })
+            .on_pan(move |states, pan| {
+                states.get_mut::<ScrollViewerState>(id).pan(pan);
+                true
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
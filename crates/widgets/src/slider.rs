@@ -1,4 +1,4 @@
-use crate::{api::prelude::*, prelude::*, proc_macros::*};
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::MouseButton};
 
 // --- KEYS --
 pub static STYLE_SLIDER: &str = "slider";
@@ -168,7 +168,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -212,6 +212,13 @@ impl Template for Slider {
                     )
                     .build(ctx),
             )
+            .on_mouse_down(move |states, m| {
+                // the thumb follows the mouse also outside of the slider
+                if m.button == MouseButton::Left {
+                    states.capture_mouse(id);
+                }
+                false
+            })
             .on_mouse_move(move |states, p| {
                 states
                     .get_mut::<SliderState>(id)
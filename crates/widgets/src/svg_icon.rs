@@ -0,0 +1,44 @@
+use crate::{api::prelude::*, proc_macros::*, render::prelude::*};
+
+widget!(
+    /// The `SvgIcon` widget is used to draw a svg image as icon. The image is rasterized with
+    /// the size of the widget, so it stays sharp at any icon size. It is not interactive.
+    ///
+    /// A solid `icon_brush` replaces the colors of the image, a transparent `icon_brush` keeps them.
+    ///
+    /// **style:** `svg_icon`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// SvgIcon::new().svg("res/icons/home.svg").icon_size(24.0).build(ctx)
+    /// ```
+    SvgIcon {
+        /// Sets or shares the svg image property.
+        ///
+        /// Set svg property:
+        /// * &str: `SvgIcon::new().svg("path/to/icon.svg").build(ctx)`
+        /// * Image: `SvgIcon::new().svg(Image::from_svg_data(data).unwrap()).build(ctx)`
+        svg: Image,
+
+        /// Sets or shares the icon brush property.
+        icon_brush: Brush,
+
+        /// Sets or shares the icon size property, the height of the icon.
+        icon_size: f64
+    }
+);
+
+impl Template for SvgIcon {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("SvgIcon").style("svg_icon").icon_size(16.0)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        SvgIconRenderObject::new().into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        FixedSizeLayout::new().into()
+    }
+}
@@ -1,6 +1,10 @@
+use std::cell::Cell;
+
 use super::behaviors::MouseBehavior;
 
-use crate::{api::prelude::*, prelude::*, proc_macros::*, theme::prelude::*};
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::MouseButton, theme::prelude::*,
+};
 
 // --- KEYS --
 const TAB_HEADER_CONTAINER: &str = "tab_header_container";
@@ -9,6 +13,16 @@ const HEADER_BAR: &str = "header_bar";
 const BODY_CONTAINER: &str = "body_container";
 // --- KEYS --
 
+/// The payload of a tab that is dragged inside of a reorderable `TabWidget`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TabDrag {
+    /// The tab widget of the tab.
+    pub tab_widget: Entity,
+
+    /// The body of the dragged tab.
+    pub body: Entity,
+}
+
 /**
 The `TabHeaderState` is used to store some callbacks that will be applied during template function.
 Once the template function is called, they are no more used.
@@ -23,6 +37,67 @@ pub struct TabHeaderState {
     header_bar: Entity,
 
     tab_header_container: Entity,
+
+    //The payload that is dragged if the tab widget is reorderable
+    tab_drag: Option<TabDrag>,
+
+    drag_gesture: Cell<DragGesture>,
+
+    request_drag: bool,
+}
+
+impl TabHeaderState {
+    fn press(&self, mouse: Mouse) {
+        if mouse.button == MouseButton::Left {
+            let mut drag_gesture = self.drag_gesture.get();
+            drag_gesture.press(mouse.position);
+            self.drag_gesture.set(drag_gesture);
+        }
+    }
+
+    fn release(&self) {
+        self.drag_gesture.set(DragGesture::default());
+    }
+
+    // Returns `true` if the header should be dragged.
+    fn mouse_moved(&self, position: Point) -> bool {
+        let mut drag_gesture = self.drag_gesture.get();
+        let start = drag_gesture.moved(position);
+        self.drag_gesture.set(drag_gesture);
+        start
+    }
+
+    fn start_drag(&mut self, ctx: &mut Context) {
+        self.request_drag = false;
+
+        let tab_drag = match self.tab_drag {
+            Some(tab_drag) if *TabHeader::reorderable_ref(&ctx.widget()) => tab_drag,
+            _ => return,
+        };
+
+        let bounds = ctx.widget().clone::<Rectangle>("bounds");
+        let text = TabHeader::text_clone(&ctx.widget());
+        let visual = {
+            let build_context = &mut ctx.build_context();
+            Container::new()
+                .style("drag_visual")
+                .width(bounds.width())
+                .height(bounds.height())
+                .child(
+                    TextBlock::new()
+                        .text(text)
+                        .v_align("center")
+                        .h_align("center")
+                        .build(build_context),
+                )
+                .build(build_context)
+        };
+
+        if ctx.start_drag(DragPayload::new(tab_drag), Some(visual)) {
+            ctx.widget().set("pressed", false);
+            toggle_flag("pressed", &mut ctx.widget());
+        }
+    }
 }
 
 impl State for TabHeaderState {
@@ -33,6 +108,10 @@ impl State for TabHeaderState {
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if self.request_drag {
+            self.start_drag(ctx);
+        }
+
         // set visibility of selection indicator bar
         // should be refactored after property converter are implemented
         // ```rust
@@ -61,12 +140,12 @@ impl State for TabHeaderState {
 
 widget!(
     /// The `TabHeader` widget is used internally to managed tabs headers. Not meant for other uses.
-    TabHeader<TabHeaderState> {
+    TabHeader<TabHeaderState>: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -111,11 +190,20 @@ widget!(
         spacing: f64,
 
         /// Sets or shares the close button visibility.
-        close_button: Visibility
+        close_button: Visibility,
+
+        /// Sets or shares the flag if the tab could be moved by drag and drop.
+        reorderable: bool
     }
 );
 
 impl TabHeader {
+    ///Set the payload that is dragged if the header is reorderable
+    pub fn tab_drag(mut self, tab_drag: TabDrag) -> Self {
+        self.state.tab_drag = Some(tab_drag);
+        self
+    }
+
     ///Set the callback that is called when user click on the header (generally used to switch tab)
     pub fn on_header_mouse_down<T: 'static + Fn(&mut StatesContext, Mouse) -> bool>(
         mut self,
@@ -147,11 +235,24 @@ impl Template for TabHeader {
         }
         //if self.close_button() == false {button = button.visibility(Visibility::Collapsed);}
 
-        let mut mouse_behavior = MouseBehavior::new().enabled(id).target(id.0).pressed(id);
+        let callback = self.state.on_header_mouse_down_callback.take();
+        let mouse_behavior = MouseBehavior::new()
+            .enabled(id)
+            .target(id.0)
+            .pressed(id)
+            .on_mouse_down(move |states, m| {
+                states.get::<TabHeaderState>(id).press(m);
+
+                // the drag starts also if the mouse leaves the header
+                if m.button == MouseButton::Left {
+                    states.capture_mouse(id);
+                }
 
-        if let Some(callback) = self.state.on_header_mouse_down_callback.take() {
-            mouse_behavior = mouse_behavior.on_mouse_down(callback);
-        }
+                match &callback {
+                    Some(callback) => callback(states, m),
+                    None => false,
+                }
+            });
 
         self.name("TabHeader")
             .style("tab_header")
@@ -173,6 +274,15 @@ impl Template for TabHeader {
             .icon_brush(colors::LINK_WATER_COLOR)
             .spacing(4)
             .close_button(Visibility::Visible)
+            .on_mouse_move(move |states, p| {
+                if states.get::<TabHeaderState>(id).mouse_moved(p) {
+                    states.get_mut::<TabHeaderState>(id).request_drag = true;
+                }
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                states.get::<TabHeaderState>(id).release();
+            })
             .child(mouse_behavior.build(ctx))
             .child(
                 Container::new()
@@ -223,6 +333,7 @@ enum TabWidgetAction {
     Add(String, Entity),
     Remove(Entity),
     SetCloseButtonVisibility(bool),
+    Drop(Entity, Point),
 }
 /**
 Through the TabWidgetState it is possible to control the behaviour of TabWidget.
@@ -277,6 +388,11 @@ impl TabWidgetState {
         }
     }
 
+    //Move the dropped tab to the drop position.
+    fn drop_tab(&mut self, body: Entity, position: Point) {
+        self.actions.push(TabWidgetAction::Drop(body, position));
+    }
+
     ///Set the close button visibility of all the tabs.
     pub fn set_close_button_visibility(&mut self, value: bool) {
         self.actions
@@ -424,6 +540,47 @@ impl TabWidgetState {
         }
     }
 
+    ///Move the tab with the passed body to the index of the drop position. Unlike the public actions, this happen immediatly.
+    fn drop_tab_internal(&mut self, ctx: &mut Context, body: Entity, position: Point) {
+        let from = match self.get_index(body) {
+            Some(index) => index,
+            None => return,
+        };
+
+        //The new index is the count of the other headers in front of the drop position
+        let to = self
+            .tabs
+            .iter()
+            .filter(|(_, b)| *b != body)
+            .filter(|(header, _)| {
+                let widget = ctx.get_widget(*header);
+                widget.get::<Point>("position").x()
+                    + widget.get::<Rectangle>("bounds").width() / 2.0
+                    < position.x()
+            })
+            .count();
+
+        if to == from {
+            return;
+        }
+
+        let selected_body = self.tabs[self.selected].1;
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+        ctx.move_child(tab.0, to);
+
+        //The selected tab keeps selected on its new index
+        if let Some(selected) = self.get_index(selected_body) {
+            self.selected = selected;
+        }
+
+        ctx.push_event_strategy_by_entity(
+            ItemMovedEvent(ctx.entity, from, to),
+            ctx.entity,
+            EventStrategy::Direct,
+        );
+    }
+
     //Create a new TabHeader entity and return it. For internal use.
     fn create_tab_header(&self, ctx: &mut Context, text: String, body: Entity) -> Entity {
         let cloned_entity = ctx.entity;
@@ -434,6 +591,11 @@ impl TabWidgetState {
                 Visibility::Collapsed
             })
             .text(text)
+            .reorderable(cloned_entity)
+            .tab_drag(TabDrag {
+                tab_widget: cloned_entity,
+                body,
+            })
             .on_header_mouse_down(move |states, _| {
                 states
                     .get_mut::<TabWidgetState>(cloned_entity)
@@ -479,6 +641,9 @@ impl State for TabWidgetState {
                 TabWidgetAction::SetCloseButtonVisibility(value) => {
                     self.set_close_button_visibility_internal(ctx, value);
                 }
+                TabWidgetAction::Drop(body, position) => {
+                    self.drop_tab_internal(ctx, body, position);
+                }
             }
         }
     }
@@ -496,8 +661,10 @@ widget!(
     .tab("Tab header 3",TextBlock::new().text("Tab content 3").build(ctx))
     .build(ctx)
      ```
+
+    If `reorderable` is set, the tabs could be moved by dragging their headers. After a tab is moved an `ItemMovedEvent` is raised.
      */
-    TabWidget<TabWidgetState> {
+    TabWidget<TabWidgetState>: DragDropHandler, ItemMovedHandler {
         /// Sets or shares the spacing between tabs.
         spacing: f64,
 
@@ -505,7 +672,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -514,7 +681,10 @@ widget!(
         border_brush: Brush,
 
         /// Sets or shares the padding property.
-        padding: Thickness
+        padding: Thickness,
+
+        /// Sets or shares the flag if the tabs could be reordered by drag and drop.
+        reorderable: bool
     }
 );
 
@@ -537,27 +707,43 @@ impl TabWidget {
 
 impl Template for TabWidget {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
-        self.name("TabWidget").style("tab_widget").child(
-            Grid::new()
-                .rows(Rows::create().push(32).push("*"))
-                .child(
-                    Stack::new()
-                        .id(HEADER_CONTAINER)
-                        .orientation("horizontal")
-                        .spacing(id)
-                        .build(ctx),
-                )
-                .child(
-                    Container::new()
-                        .id(BODY_CONTAINER)
-                        .background(id)
-                        .border_brush(id)
-                        .border_width(id)
-                        .border_radius(id)
-                        .attach(Grid::row(1))
-                        .build(ctx),
-                )
-                .build(ctx),
-        )
+        self.name("TabWidget")
+            .style("tab_widget")
+            .reorderable(false)
+            .on_drag_enter(move |_, payload, _| {
+                matches!(payload.get::<TabDrag>(), Some(drag) if drag.tab_widget == id)
+            })
+            .on_drag_over(move |_, payload, _| {
+                matches!(payload.get::<TabDrag>(), Some(drag) if drag.tab_widget == id)
+            })
+            .on_drag_drop(move |states, payload, position| match payload.get::<TabDrag>() {
+                Some(drag) if drag.tab_widget == id => {
+                    states.get_mut::<TabWidgetState>(id).drop_tab(drag.body, position);
+                    true
+                }
+                _ => false,
+            })
+            .child(
+                Grid::new()
+                    .rows(Rows::create().push(32).push("*"))
+                    .child(
+                        Stack::new()
+                            .id(HEADER_CONTAINER)
+                            .orientation("horizontal")
+                            .spacing(id)
+                            .build(ctx),
+                    )
+                    .child(
+                        Container::new()
+                            .id(BODY_CONTAINER)
+                            .background(id)
+                            .border_brush(id)
+                            .border_width(id)
+                            .border_radius(id)
+                            .attach(Grid::row(1))
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
     }
 }
@@ -35,7 +35,7 @@ widget!(
         background: Brush,
 
         /// Sets or shares the border radius property.
-        border_radius: f64,
+        border_radius: CornerRadius,
 
         /// Sets or shares the border thickness property.
         border_width: Thickness,
@@ -91,6 +91,7 @@ impl Template for TextBox {
 
         self.name("TextBox")
             .style(STYLE_TEXT_BOX)
+            .cursor_icon(CursorIcon::Text)
             .text("")
             .foreground(colors::LINK_WATER_COLOR)
             .font_size(fonts::FONT_SIZE_12)
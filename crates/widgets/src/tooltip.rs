@@ -0,0 +1,359 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::Instant, theme::prelude::*};
+
+// --- KEYS --
+pub static STYLE_TOOLTIP: &str = "tooltip";
+// --- KEYS --
+
+// Distance between the mouse cursor and the tooltip.
+const CURSOR_OFFSET: (f64, f64) = (12.0, 20.0);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum TooltipAction {
+    Move(Point),
+    Hide,
+}
+
+// Searches the top most visible widget with a tooltip below the given position.
+fn tooltip_target(
+    ctx: &mut Context,
+    parent: Entity,
+    skip: Entity,
+    p: Point,
+    target: &mut Option<Entity>,
+) {
+    let children = ctx
+        .get_widget(parent)
+        .children_mut()
+        .cloned()
+        .unwrap_or_default();
+
+    for child in children {
+        if child == skip {
+            continue;
+        }
+
+        let widget = ctx.get_widget(child);
+
+        if *widget.get::<Visibility>("visibility") != Visibility::Visible {
+            continue;
+        }
+
+        let bounds = Rectangle::new(
+            widget.clone::<Point>("position"),
+            widget.clone::<Rectangle>("bounds").size(),
+        );
+
+        if !bounds.contains(p) {
+            continue;
+        }
+
+        let has_text = widget
+            .try_clone::<String>("tooltip")
+            .map_or(false, |text| !text.is_empty());
+
+        if has_text || widget.has::<u32>("tooltip_content") {
+            *target = Some(child);
+        }
+
+        tooltip_target(ctx, child, skip, p, target);
+    }
+}
+
+/// The `TooltipState` waits until the mouse rests over a widget with an attached tooltip and
+/// shows the tooltip near the cursor.
+#[derive(Default, AsAny)]
+pub struct TooltipState {
+    actions: VecDeque<TooltipAction>,
+    content_panel: Entity,
+    contents: Vec<Entity>,
+    hover_start: Option<Instant>,
+    mouse_position: Point,
+    position: Point,
+    shown: bool,
+    target: Option<Entity>,
+    text_block: Entity,
+    // a wake up is pending, the deadline is checked again after it
+    waiting: bool,
+}
+
+impl TooltipState {
+    fn action(&mut self, action: TooltipAction) {
+        self.actions.push_back(action);
+    }
+
+    fn hide(&mut self, ctx: &mut Context) {
+        self.shown = false;
+        ctx.widget().set("visibility", Visibility::Hidden);
+    }
+
+    fn show(&mut self, ctx: &mut Context, target: Entity) {
+        let text = ctx
+            .get_widget(target)
+            .try_clone::<String>("tooltip")
+            .unwrap_or_default();
+        let content = ctx
+            .get_widget(target)
+            .try_clone::<u32>("tooltip_content")
+            .map(Entity::from);
+
+        if let Some(content) = content {
+            if !self.contents.contains(&content) {
+                ctx.append_child_entity_to(content, self.content_panel);
+                self.contents.push(content);
+            }
+        }
+
+        for c in &self.contents {
+            let visibility = if Some(*c) == content {
+                Visibility::Visible
+            } else {
+                Visibility::Collapsed
+            };
+            ctx.get_widget(*c).set("visibility", visibility);
+        }
+
+        if content.is_some() {
+            ctx.get_widget(self.text_block)
+                .set("visibility", Visibility::Collapsed);
+        } else {
+            ctx.get_widget(self.text_block)
+                .set("visibility", Visibility::Visible);
+            Tooltip::text_set(&mut ctx.widget(), text);
+        }
+
+        self.shown = true;
+        self.position = self.mouse_position;
+        ctx.widget().set("visibility", Visibility::Visible);
+    }
+
+    fn delay(&self, ctx: &mut Context, target: Entity) -> u32 {
+        match ctx.get_widget(target).try_clone::<u32>("tooltip_delay") {
+            Some(delay) => delay,
+            None => *Tooltip::delay_ref(&ctx.widget()),
+        }
+    }
+
+    fn handle_move(&mut self, ctx: &mut Context, p: Point) {
+        self.mouse_position = p;
+
+        let mut target = None;
+
+        let root = ctx.window().entity();
+        let entity = ctx.entity;
+        tooltip_target(ctx, root, entity, p, &mut target);
+
+        if let Some(overlay) = ctx.entity_of_parent() {
+            tooltip_target(ctx, overlay, entity, p, &mut target);
+        }
+
+        if target != self.target {
+            self.hide(ctx);
+            self.target = target;
+        }
+
+        // the timer restarts on each move until the tooltip is shown
+        if !self.shown {
+            self.hover_start = self.target.map(|_| Instant::now());
+        }
+    }
+
+    // updates the tooltip again at the given time. Moves only delay the deadline, so one pending
+    // wake up is enough.
+    fn update_at(&mut self, ctx: &mut Context, instant: Instant) {
+        if self.waiting {
+            return;
+        }
+
+        self.waiting = true;
+
+        ctx.spawn_local_then(
+            async move { delay_until(instant).await },
+            |state: &mut TooltipState, _, _| state.waiting = false,
+        );
+    }
+}
+
+impl State for TooltipState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        ctx.widget().set("visibility", Visibility::Hidden);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        while let Some(action) = self.actions.pop_front() {
+            match action {
+                TooltipAction::Move(p) => self.handle_move(ctx, p),
+                TooltipAction::Hide => {
+                    self.hide(ctx);
+                    self.hover_start = None;
+                }
+            }
+        }
+
+        if self.shown {
+            return;
+        }
+
+        if let (Some(target), Some(hover_start)) = (self.target, self.hover_start) {
+            let show_at = hover_start + Duration::from_millis(u64::from(self.delay(ctx, target)));
+
+            if Instant::now() >= show_at {
+                self.hover_start = None;
+                self.show(ctx, target);
+            } else {
+                self.update_at(ctx, show_at);
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if !self.shown {
+            return;
+        }
+
+        let bounds = ctx.widget().clone::<Rectangle>("bounds");
+        let window_bounds = ctx.window().clone::<Rectangle>("bounds");
+
+        let mut x = self.position.x() + CURSOR_OFFSET.0;
+        let mut y = self.position.y() + CURSOR_OFFSET.1;
+
+        // keeps the tooltip inside of the window, flips it above the cursor if there is no space
+        // below.
+        if x + bounds.width() > window_bounds.width() {
+            x = window_bounds.width() - bounds.width();
+        }
+
+        if y + bounds.height() > window_bounds.height() {
+            y = self.position.y() - bounds.height() - 4.0;
+        }
+
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_x(x.max(0.0));
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_y(y.max(0.0));
+    }
+}
+
+widget!(
+    /// The `Tooltip` displays a short description of the widget below the mouse cursor after the
+    /// cursor rests on it for the given delay. Each window places one tooltip on its overlay. A
+    /// tooltip is attached to a widget by the `tooltip` attached property with a plain text or by
+    /// `tooltip_content` with the id of a widget that is displayed as content. The tooltip is
+    /// hidden if the mouse leaves the widget, on mouse down, scroll or key press.
+    ///
+    /// **style:** `tooltip`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// Button::new()
+    ///     .text("Save")
+    ///     .attach(Tooltip::tooltip("Saves the current file"))
+    ///     .attach(Tooltip::tooltip_delay(1000))
+    ///     .build(ctx)
+    /// ```
+    Tooltip<TooltipState> {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the box shadow property.
+        box_shadow: BoxShadow,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the default delay in milliseconds before the tooltip is shown.
+        delay: u32
+
+        attached_properties: {
+            /// Attach a tooltip text to a widget.
+            tooltip: String,
+
+            /// Attach the id of a widget that is displayed as tooltip content.
+            tooltip_content: u32,
+
+            /// Attach the delay in milliseconds before the tooltip of the widget is shown.
+            tooltip_delay: u32
+        }
+    }
+);
+
+impl Tooltip {
+    /// Handles the mouse move of the window.
+    pub(crate) fn mouse_move(states: &mut StatesContext, tooltip: Entity, p: Point) {
+        states
+            .get_mut::<TooltipState>(tooltip)
+            .action(TooltipAction::Move(p));
+    }
+
+    /// Hides the tooltip e.g. on mouse down, scroll or key press.
+    pub(crate) fn hide(states: &mut StatesContext, tooltip: Entity) {
+        states
+            .get_mut::<TooltipState>(tooltip)
+            .action(TooltipAction::Hide);
+    }
+}
+
+impl Template for Tooltip {
+    fn template(mut self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let text_block = TextBlock::new()
+            .foreground(id)
+            .text(id)
+            .font_size(id)
+            .font(id)
+            .build(ctx);
+
+        let content_panel = Grid::new().child(text_block).build(ctx);
+
+        self.state_mut().text_block = text_block;
+        self.state_mut().content_panel = content_panel;
+
+        self.name("Tooltip")
+            .style(STYLE_TOOLTIP)
+            .h_align("start")
+            .v_align("start")
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .padding((6.0, 4.0, 6.0, 4.0))
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .delay(500)
+            .child(content_panel)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PopupLayout::new().into()
+    }
+}
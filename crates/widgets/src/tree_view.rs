@@ -0,0 +1,668 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::behaviors::SelectionBehavior;
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+pub static STYLE_TREE_VIEW: &str = "tree_view";
+pub static STYLE_TREE_VIEW_ITEM: &str = "tree_view_item";
+pub static STYLE_TREE_VIEW_EXPANDER: &str = "tree_view_expander";
+static ID_ITEMS_PANEL: &str = "tree_view_items_panel";
+// --- KEYS --
+
+type TreeItemsBuilder = Option<Box<dyn Fn(&mut BuildContext, &[usize]) -> Entity + 'static>>;
+
+type TreeChildrenCount = Option<Box<dyn Fn(&[usize]) -> usize + 'static>>;
+
+// Describes a visible node of the tree.
+#[derive(Clone, Debug, PartialEq)]
+struct TreeNode {
+    path: TreePath,
+    child_count: usize,
+}
+
+// Collects the visible nodes below parent in display order. Children are only visited if their
+// parent is expanded.
+fn flatten_nodes(
+    children_count: &dyn Fn(&[usize]) -> usize,
+    expanded: &HashSet<TreePath>,
+    parent: &TreePath,
+    nodes: &mut Vec<TreeNode>,
+) {
+    for i in 0..children_count(parent.as_slice()) {
+        let path = parent.child(i);
+        let child_count = children_count(path.as_slice());
+        let is_expanded = child_count > 0 && expanded.contains(&path);
+
+        nodes.push(TreeNode {
+            path: path.clone(),
+            child_count,
+        });
+
+        if is_expanded {
+            flatten_nodes(children_count, expanded, &path, nodes);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TreeViewAction {
+    Expand(TreePath),
+    Collapse(TreePath),
+    Toggle(TreePath),
+    Key(KeyEvent),
+    Focus,
+}
+
+/// The `TreeViewState` generates the visible tree items, handles expanding, collapsing, keyboard
+/// navigation and the selected paths.
+#[derive(Default, AsAny)]
+pub struct TreeViewState {
+    actions: VecDeque<TreeViewAction>,
+    builder: TreeItemsBuilder,
+    children_count: TreeChildrenCount,
+    expanded: HashSet<TreePath>,
+    items: Vec<(TreePath, Entity)>,
+    items_panel: Entity,
+    nodes: Vec<TreeNode>,
+    request_items_update: bool,
+}
+
+impl TreeViewState {
+    fn action(&mut self, action: TreeViewAction) {
+        self.actions.push_back(action);
+    }
+
+    /// Expands the item with the given path.
+    pub fn expand(&mut self, path: impl Into<TreePath>) {
+        self.action(TreeViewAction::Expand(path.into()));
+    }
+
+    /// Collapses the item with the given path.
+    pub fn collapse(&mut self, path: impl Into<TreePath>) {
+        self.action(TreeViewAction::Collapse(path.into()));
+    }
+
+    /// Expands the item with the given path if it is collapsed otherwise it will be collapsed.
+    pub fn toggle(&mut self, path: impl Into<TreePath>) {
+        self.action(TreeViewAction::Toggle(path.into()));
+    }
+
+    /// Returns `true` if the item with the given path is expanded.
+    pub fn is_expanded(&self, path: &TreePath) -> bool {
+        self.expanded.contains(path)
+    }
+
+    fn request_focus(&self, ctx: &mut Context) {
+        if !ctx.widget().get::<bool>("focused") {
+            ctx.widget().set::<bool>("focused", true);
+            ctx.push_event_by_window(FocusEvent::RequestFocus(ctx.entity));
+        }
+    }
+
+    fn update_nodes(&mut self) {
+        self.nodes.clear();
+
+        if let Some(children_count) = &self.children_count {
+            flatten_nodes(
+                children_count,
+                &self.expanded,
+                &TreePath::default(),
+                &mut self.nodes,
+            );
+        }
+
+        self.request_items_update = true;
+    }
+
+    fn generate_items(&mut self, ctx: &mut Context) {
+        self.request_items_update = false;
+        ctx.clear_children_of(self.items_panel);
+        self.items.clear();
+
+        let builder = match &self.builder {
+            Some(builder) => builder,
+            None => return,
+        };
+
+        let entity = ctx.entity;
+        let indent = *TreeView::indent_ref(&ctx.widget());
+        let selected_paths = TreeView::selected_paths_clone(&ctx.widget()).0;
+
+        for node in &self.nodes {
+            let selected = selected_paths.contains(&node.path);
+            let expanded = self.expanded.contains(&node.path);
+
+            let item = {
+                let build_context = &mut ctx.build_context();
+                let item = TreeViewItem::new()
+                    .path(node.path.clone())
+                    .parent(entity.0)
+                    .selected(selected)
+                    .build(build_context);
+
+                let selection_behavior = SelectionBehavior::new()
+                    .selected(item)
+                    .enabled(item)
+                    .target(item.0)
+                    .parent(entity.0)
+                    .build(build_context);
+
+                let row = Stack::new().orientation("horizontal").build(build_context);
+
+                // indentation guides
+                for _ in 0..node.path.depth() {
+                    let guide = Container::new()
+                        .width(indent)
+                        .child(
+                            Container::new()
+                                .width(1.0)
+                                .h_align("center")
+                                .background(("guide_brush", entity))
+                                .build(build_context),
+                        )
+                        .build(build_context);
+                    build_context.append_child(row, guide);
+                }
+
+                let path = node.path.clone();
+                let expander = Button::new()
+                    .style(STYLE_TREE_VIEW_EXPANDER)
+                    .v_align("center")
+                    .icon(if expanded {
+                        material_icons_font::MD_EXPAND_MORE
+                    } else {
+                        material_icons_font::MD_CHEVRON_RIGHT
+                    })
+                    .visibility(if node.child_count > 0 {
+                        "visible"
+                    } else {
+                        "hidden"
+                    })
+                    .on_click(move |states, _| {
+                        states.get_mut::<TreeViewState>(entity).toggle(path.clone());
+                        true
+                    })
+                    .build(build_context);
+                build_context.append_child(row, expander);
+
+                let child = builder(build_context, node.path.as_slice());
+                build_context.register_shared_property::<Brush>("foreground", child, item);
+                build_context.register_shared_property::<f32>("opacity", item, entity);
+                build_context.register_shared_property::<f32>("opacity", child, entity);
+                build_context.register_shared_property::<f64>("font_size", child, item);
+                build_context.register_shared_property::<String>("font", child, item);
+                build_context.append_child(row, child);
+
+                build_context.append_child(selection_behavior, row);
+                build_context.append_child(item, selection_behavior);
+                build_context.append_child(self.items_panel, item);
+
+                item
+            };
+
+            ctx.get_widget(item).update(false);
+            self.items.push((node.path.clone(), item));
+        }
+    }
+
+    fn select(&mut self, ctx: &mut Context, path: TreePath) {
+        if *TreeView::selection_mode_ref(&ctx.widget()) == SelectionMode::None {
+            return;
+        }
+
+        {
+            let mut widget = ctx.widget();
+            let selected_paths = &mut widget.get_mut::<SelectedPaths>("selected_paths").0;
+            selected_paths.clear();
+            selected_paths.insert(path.clone());
+        }
+
+        let entity = ctx.entity;
+        ctx.push_event_strategy_by_entity(
+            SelectionChangedEvent(entity, path.0),
+            entity,
+            EventStrategy::Direct,
+        );
+    }
+
+    // Returns the index of the visible node that is selected last.
+    fn current_index(&self, ctx: &mut Context) -> Option<usize> {
+        let selected_paths = TreeView::selected_paths_clone(&ctx.widget()).0;
+        self.nodes
+            .iter()
+            .rposition(|node| selected_paths.contains(&node.path))
+    }
+
+    fn handle_key(&mut self, ctx: &mut Context, key_event: KeyEvent) {
+        if self.nodes.is_empty() {
+            return;
+        }
+
+        let current = match self.current_index(ctx) {
+            Some(current) => current,
+            None => {
+                if key_event.key == Key::Up || key_event.key == Key::Down {
+                    let path = self.nodes[0].path.clone();
+                    self.select(ctx, path);
+                }
+                return;
+            }
+        };
+
+        let node = self.nodes[current].clone();
+
+        match key_event.key {
+            Key::Up => {
+                if current > 0 {
+                    let path = self.nodes[current - 1].path.clone();
+                    self.select(ctx, path);
+                }
+            }
+            Key::Down => {
+                if current + 1 < self.nodes.len() {
+                    let path = self.nodes[current + 1].path.clone();
+                    self.select(ctx, path);
+                }
+            }
+            Key::Right => {
+                if node.child_count == 0 {
+                    return;
+                }
+
+                if self.expanded.contains(&node.path) {
+                    self.select(ctx, node.path.child(0));
+                } else {
+                    self.handle_action(ctx, TreeViewAction::Expand(node.path));
+                }
+            }
+            Key::Left => {
+                if self.expanded.contains(&node.path) {
+                    self.handle_action(ctx, TreeViewAction::Collapse(node.path));
+                } else if let Some(parent) = node.path.parent() {
+                    if !parent.0.is_empty() {
+                        self.select(ctx, parent);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_action(&mut self, ctx: &mut Context, action: TreeViewAction) {
+        match action {
+            TreeViewAction::Expand(path) => {
+                if self.expanded.insert(path) {
+                    self.update_nodes();
+                }
+            }
+            TreeViewAction::Collapse(path) => {
+                if self.expanded.remove(&path) {
+                    self.update_nodes();
+                }
+            }
+            TreeViewAction::Toggle(path) => {
+                if !self.expanded.remove(&path) {
+                    self.expanded.insert(path);
+                }
+                self.update_nodes();
+            }
+            TreeViewAction::Key(key_event) => self.handle_key(ctx, key_event),
+            TreeViewAction::Focus => self.request_focus(ctx),
+        }
+    }
+}
+
+impl State for TreeViewState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.items_panel = ctx
+            .entity_of_child(ID_ITEMS_PANEL)
+            .expect("TreeViewState.init: items panel child could not be found.");
+
+        self.update_nodes();
+        self.generate_items(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if *TreeView::request_update_ref(&ctx.widget()) {
+            TreeView::request_update_set(&mut ctx.widget(), false);
+            self.update_nodes();
+        }
+
+        while let Some(action) = self.actions.pop_front() {
+            self.handle_action(ctx, action);
+        }
+
+        if self.request_items_update {
+            self.generate_items(ctx);
+        }
+    }
+
+    fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let selected_paths = TreeView::selected_paths_clone(&ctx.widget()).0;
+
+        for (path, item) in &self.items {
+            let mut widget = ctx.get_widget(*item);
+            let selected = selected_paths.contains(path);
+
+            if *widget.get::<bool>("selected") == selected {
+                continue;
+            }
+
+            widget.set("selected", selected);
+            toggle_flag("selected", &mut widget);
+            widget.update(false);
+        }
+    }
+}
+
+/// The `TreeViewItemState` writes the selection of a `TreeViewItem` back to its `TreeView`.
+#[derive(Default, AsAny)]
+pub struct TreeViewItemState {
+    selected: bool,
+}
+
+impl State for TreeViewItemState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.selected = *TreeViewItem::selected_ref(&ctx.widget());
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let selected = *TreeViewItem::selected_ref(&ctx.widget());
+
+        if selected == self.selected {
+            return;
+        }
+
+        let path = TreeViewItem::path_clone(&ctx.widget());
+        let parent_entity: Entity = (*TreeViewItem::parent_ref(&ctx.widget())).into();
+        let selection_mode = *ctx
+            .get_widget(parent_entity)
+            .get::<SelectionMode>("selection_mode");
+
+        if selected && selection_mode == SelectionMode::None {
+            ctx.widget().set("selected", false);
+            return;
+        }
+
+        self.selected = selected;
+
+        let changed = {
+            let mut parent = ctx.get_widget(parent_entity);
+            let selected_paths = &mut parent.get_mut::<SelectedPaths>("selected_paths").0;
+
+            if selected {
+                if selection_mode == SelectionMode::Single
+                    && !(selected_paths.len() == 1 && selected_paths.contains(&path))
+                {
+                    selected_paths.clear();
+                }
+
+                selected_paths.insert(path.clone())
+            } else {
+                selected_paths.remove(&path)
+            }
+        };
+
+        if changed {
+            ctx.push_event_strategy_by_entity(
+                SelectionChangedEvent(parent_entity, path.0),
+                parent_entity,
+                EventStrategy::Direct,
+            );
+        }
+    }
+}
+
+widget!(
+    /// The `TreeViewItem` describes an item inside of a `TreeView`.
+    ///
+    /// **style:** `tree_view_item`
+    TreeViewItem<TreeViewItemState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the selected property.
+        selected: bool,
+
+        /// Sets or shares the path of the item inside of the tree.
+        path: TreePath,
+
+        /// Sets or shares the tree view id.
+        parent: u32
+    }
+);
+
+impl Template for TreeViewItem {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("TreeViewItem")
+            .style(STYLE_TREE_VIEW_ITEM)
+            .min_width(64.0)
+            .height(24.0)
+            .selected(false)
+            .padding(0.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .border_width(0.0)
+            .border_brush("transparent")
+            .foreground(colors::LINK_WATER_COLOR)
+            .font_size(fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PaddingLayout::new().into()
+    }
+}
+
+widget!(
+    /// The `TreeView` displays hierarchical items that can be expanded and collapsed. The items
+    /// of a collapsed node are not generated until it is expanded. An item is described by its
+    /// `TreePath`, the list of indices from the top level down to the item.
+    ///
+    /// Items are expanded and collapsed by their expander, the `Right` and `Left` keys. `Up`
+    /// and `Down` move the selection. The `SelectionChangedEvent` contains the path of the item
+    /// whose selection has changed.
+    ///
+    /// **style:** `tree_view`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// TreeView::new()
+    ///     .children_count(move |path| match path.len() {
+    ///         0 => folders.len(),
+    ///         1 => folders[path[0]].files.len(),
+    ///         _ => 0,
+    ///     })
+    ///     .items_builder(move |bc, path| TextBlock::new().text(name_of(path)).build(bc))
+    ///     .build(ctx)
+    /// ```
+    TreeView<TreeViewState>: KeyDownHandler, MouseHandler, SelectionChangedHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: CornerRadius,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the brush of the indentation guides.
+        guide_brush: Brush,
+
+        /// Sets or shares the indentation width of a tree level.
+        indent: f64,
+
+        /// Sets or shares the selection mode property.
+        selection_mode: SelectionMode,
+
+        /// Sets or shares the selected paths.
+        selected_paths: SelectedPaths,
+
+        /// Sets or shares the focused property.
+        focused: bool,
+
+        /// Use this flag to force the redrawing of the items.
+        request_update: bool
+    }
+);
+
+impl TreeView {
+    /// Define the template build function for the content of the items. The builder gets the
+    /// path of the item.
+    pub fn items_builder<F: Fn(&mut BuildContext, &[usize]) -> Entity + 'static>(
+        mut self,
+        builder: F,
+    ) -> Self {
+        self.state_mut().builder = Some(Box::new(builder));
+        self
+    }
+
+    /// Defines the function that returns the number of children of the item with the given
+    /// path. The empty path describes the top level.
+    pub fn children_count<F: Fn(&[usize]) -> usize + 'static>(mut self, children_count: F) -> Self {
+        self.state_mut().children_count = Some(Box::new(children_count));
+        self
+    }
+}
+
+impl Template for TreeView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let items_panel = Stack::new()
+            .id(ID_ITEMS_PANEL)
+            .v_align("start")
+            .orientation("vertical")
+            .build(ctx);
+
+        let scroll_viewer = ScrollViewer::new()
+            .mode(("auto", "auto"))
+            .child(items_panel)
+            .build(ctx);
+
+        self.name("TreeView")
+            .style(STYLE_TREE_VIEW)
+            .background(colors::LYNCH_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_brush(colors::BOMBAY_COLOR)
+            .guide_brush(colors::BOMBAY_COLOR)
+            .indent(16.0)
+            .padding(2.0)
+            .selection_mode("single")
+            .selected_paths(HashSet::new())
+            .focused(false)
+            .on_mouse_down(move |states, _| {
+                states
+                    .get_mut::<TreeViewState>(id)
+                    .action(TreeViewAction::Focus);
+                false
+            })
+            .on_key_down(move |states, event| -> bool {
+                states
+                    .get_mut::<TreeViewState>(id)
+                    .action(TreeViewAction::Key(event));
+                false
+            })
+            .child(
+                Container::new()
+                    .background(id)
+                    .border_radius(id)
+                    .border_width(id)
+                    .border_brush(id)
+                    .padding(id)
+                    .opacity(id)
+                    .child(scroll_viewer)
+                    .child(
+                        ScrollIndicator::new()
+                            .padding(2.0)
+                            .content_bounds(("bounds", items_panel))
+                            .view_port_bounds(("bounds", scroll_viewer))
+                            .scroll_padding(("padding", scroll_viewer))
+                            .mode(scroll_viewer)
+                            .opacity(id)
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(nodes: &[TreeNode]) -> Vec<Vec<usize>> {
+        nodes.iter().map(|node| node.path.0.clone()).collect()
+    }
+
+    #[test]
+    fn test_flatten_nodes() {
+        // 0
+        // 1
+        // ├ 1.0
+        // │ └ 1.0.0
+        // └ 1.1
+        let children_count = |path: &[usize]| match path {
+            [] => 2,
+            [1] => 2,
+            [1, 0] => 1,
+            _ => 0,
+        };
+
+        let mut nodes = vec![];
+        let mut expanded = HashSet::new();
+        flatten_nodes(&children_count, &expanded, &TreePath::default(), &mut nodes);
+        assert_eq!(paths(&nodes), vec![vec![0], vec![1]]);
+        assert_eq!(nodes[1].child_count, 2);
+
+        nodes.clear();
+        expanded.insert(TreePath(vec![1, 0]));
+        flatten_nodes(&children_count, &expanded, &TreePath::default(), &mut nodes);
+        assert_eq!(paths(&nodes), vec![vec![0], vec![1]]);
+
+        nodes.clear();
+        expanded.insert(TreePath(vec![1]));
+        flatten_nodes(&children_count, &expanded, &TreePath::default(), &mut nodes);
+        assert_eq!(
+            paths(&nodes),
+            vec![vec![0], vec![1], vec![1, 0], vec![1, 0, 0], vec![1, 1]]
+        );
+
+        nodes.clear();
+        expanded.insert(TreePath(vec![0]));
+        flatten_nodes(&children_count, &expanded, &TreePath::default(), &mut nodes);
+        assert_eq!(nodes.len(), 5);
+    }
+}
@@ -1,6 +1,9 @@
 use std::{collections::VecDeque, rc::Rc};
 
-use crate::{api::prelude::*, proc_macros::*, shell::prelude::WindowRequest, theme::prelude::*};
+use crate::{
+    api::prelude::*, proc_macros::*, shell::prelude::WindowRequest, theme::prelude::*, FileChooser,
+    Tooltip,
+};
 
 // --- KEYS --
 pub static STYLE_WINDOW: &str = "window";
@@ -20,6 +23,7 @@ enum Action {
 struct WindowState {
     actions: VecDeque<Action>,
     background: Brush,
+    commands: Vec<Command>,
     title: String,
 }
 
@@ -33,6 +37,10 @@ impl WindowState {
         Window::constraint_mut(&mut ctx.window()).set_size(width, height);
     }
 
+    fn scale_factor_changed(&self, scale_factor: f64, ctx: &mut Context) {
+        Window::scale_factor_set(&mut ctx.widget(), scale_factor);
+    }
+
     fn active_changed(&self, active: bool, ctx: &mut Context) {
         Window::active_set(&mut ctx.widget(), active);
 
@@ -70,9 +78,19 @@ impl WindowState {
 }
 
 impl State for WindowState {
-    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
         self.set_background(ctx);
         self.title = ctx.widget().clone("title");
+
+        registry
+            .get_mut::<FileDialogs>("file_dialog")
+            .set_fallback(FileChooser::show);
+
+        for command in self.commands.drain(..) {
+            if let Err(error) = ctx.commands().register(command) {
+                panic!("Window.command(): {}.", error);
+            }
+        }
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
@@ -96,6 +114,9 @@ impl State for WindowState {
                     WindowEvent::ActiveChanged(active) => {
                         self.active_changed(active, ctx);
                     }
+                    WindowEvent::ScaleFactorChanged(scale_factor) => {
+                        self.scale_factor_changed(scale_factor, ctx);
+                    }
                     _ => {}
                 },
                 Action::FocusEvent(focus_event) => match focus_event {
@@ -114,9 +135,10 @@ impl State for WindowState {
 widget!(
     /// The `Window` widget provides access to the properties of an application window.
     /// It also contains global properties like keyboard modifier and focused widget.
+    /// Each window places a `Tooltip` on its overlay that displays the attached tooltips of its widgets.
     ///
     /// **style:** `window`
-    Window<WindowState> {
+    Window<WindowState>: MouseHandler, KeyDownHandler, CommandHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -135,6 +157,10 @@ widget!(
         /// Sets or shares a value that describes if the current window is active.
         active: bool,
 
+        /// Access the number of physical pixels per logical unit of the monitor the window is
+        /// displayed on. The layout uses logical units.
+        scale_factor: f64,
+
         /// Access the current keyboard state e.g. to check modifiers.
         keyboard_state: KeyboardState,
 
@@ -150,6 +176,18 @@ widget!(
 );
 
 impl Window {
+    /// Registers a command of the window. It could be executed by its shortcut while the window
+    /// is active and hides the application command with the same id or shortcut.
+    ///
+    /// # Panics
+    ///
+    /// Panics on initialization if the id or the shortcut of the command is already used by
+    /// another command of the window.
+    pub fn command(mut self, command: Command) -> Self {
+        self.state_mut().commands.push(command);
+        self
+    }
+
     fn on_window_event<H: Fn(&mut StatesContext, WindowEvent) -> bool + 'static>(
         self,
         handler: H,
@@ -170,7 +208,10 @@ impl Window {
 }
 
 impl Template for Window {
-    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let tooltip = Tooltip::new().build(ctx);
+        let _ = ctx.append_child_to_overlay(tooltip);
+
         self.name("Window")
             .background(colors::BRIGHT_GRAY_COLOR)
             .size(100.0, 100.0)
@@ -178,6 +219,7 @@ impl Template for Window {
             .title("Window")
             .resizeable(false)
             .always_on_top(false)
+            .scale_factor(1.0)
             .on_window_event(move |ctx, event| {
                 ctx.get_mut::<WindowState>(id)
                     .push_action(Action::WindowEvent(event));
@@ -188,6 +230,22 @@ impl Template for Window {
                     .push_action(Action::FocusEvent(event));
                 true
             })
+            .on_mouse_move(move |states, p| {
+                Tooltip::mouse_move(states, tooltip, p);
+                false
+            })
+            .on_mouse_down(move |states, _| {
+                Tooltip::hide(states, tooltip);
+                false
+            })
+            .on_scroll(move |states, _| {
+                Tooltip::hide(states, tooltip);
+                false
+            })
+            .on_key_down(move |states, _| {
+                Tooltip::hide(states, tooltip);
+                false
+            })
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
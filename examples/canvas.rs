@@ -202,6 +202,33 @@ impl State for MainViewState {
     }
 }
 
+// Draws a bar chart, each bar is a named shape.
+fn bar_chart() -> Drawing {
+    let mut drawing = Drawing::new();
+    let values = [0.4, 0.7, 0.55, 0.9, 0.3];
+
+    drawing.set_stroke_style(Brush::from("#dfebf5"));
+    drawing.begin_path();
+    drawing.move_to(10.0, 10.0);
+    drawing.line_to(10.0, 130.0);
+    drawing.line_to(330.0, 130.0);
+    drawing.stroke();
+
+    for (index, value) in values.iter().enumerate() {
+        let x = 30.0 + index as f64 * 60.0;
+        let height = 110.0 * value;
+
+        drawing.begin_shape(format!("bar {}", index + 1));
+        drawing.set_fill_style(Brush::from("linear-gradient(#5b0f22, #e33f2f)"));
+        drawing.begin_path();
+        drawing.rect(x, 130.0 - height, 40.0, height);
+        drawing.fill();
+        drawing.end_shape();
+    }
+
+    drawing
+}
+
 widget!(
     MainView<MainViewState> {
          render_pipeline: DefaultRenderPipeline
@@ -214,7 +241,15 @@ impl Template for MainView {
             .render_pipeline(DefaultRenderPipeline(Box::new(CubePipeline::default())))
             .child(
                 Grid::new()
-                    .rows(Rows::create().push("auto").push("*").push("auto").push("*"))
+                    .rows(
+                        Rows::create()
+                            .push("auto")
+                            .push("*")
+                            .push("auto")
+                            .push("*")
+                            .push("auto")
+                            .push("*"),
+                    )
                     .child(
                         TextBlock::new()
                             .attach(Grid::row(0))
@@ -259,6 +294,25 @@ impl Template for MainView {
                             )))
                             .build(ctx),
                     )
+                    .child(
+                        TextBlock::new()
+                            .attach(Grid::row(4))
+                            .text("Canvas2D (retained drawing, click a bar)")
+                            .style("text-block")
+                            .style("text_block_header")
+                            .margin(4.0)
+                            .build(ctx),
+                    )
+                    .child(
+                        Canvas2D::new()
+                            .attach(Grid::row(5))
+                            .drawing(bar_chart())
+                            .on_shape_click(|_, shape| {
+                                println!("{} clicked", shape);
+                                true
+                            })
+                            .build(ctx),
+                    )
                     .build(ctx),
             )
     }
@@ -273,7 +327,7 @@ fn main() {
             Window::new()
                 .title("OrbTk - canvas example")
                 .position((100.0, 100.0))
-                .size(420.0, 730.0)
+                .size(420.0, 900.0)
                 .resizeable(true)
                 .child(MainView::new().build(ctx))
                 .build(ctx)
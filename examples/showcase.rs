@@ -309,9 +309,40 @@ widget!(ImageView {});
 impl Template for ImageView {
     fn template(self, _id: Entity, ctx: &mut BuildContext) -> Self {
         self.child(
-            ImageWidget::new()
+            Stack::new()
                 .margin(16)
-                .image("res/showcase/orbtk_logo.png")
+                .spacing(16)
+                .child(
+                    ImageWidget::new()
+                        .image("res/showcase/orbtk_logo.png")
+                        .build(ctx),
+                )
+                .child(
+                    ImageWidget::new()
+                        .source("res/orbtk_space.png")
+                        .stretch("uniform")
+                        .height(64)
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .spacing(8)
+                        .child(SvgIcon::new().svg("res/showcase/star.svg").build(ctx))
+                        .child(
+                            SvgIcon::new()
+                                .svg("res/showcase/star.svg")
+                                .icon_size(32.0)
+                                .build(ctx),
+                        )
+                        .child(
+                            SvgIcon::new()
+                                .svg("res/showcase/star.svg")
+                                .icon_size(64.0)
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
                 .build(ctx),
         )
     }